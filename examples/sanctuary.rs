@@ -4,9 +4,23 @@ use uuid::Uuid;
 
 use ecoinfra_governance::nanopolygon::*;
 use ecoinfra_governance::aln::*;
+use ecoinfra_governance::audit::{context::AuditContext, sink::InMemoryAuditSink};
+use ecoinfra_governance::policy::{
+    ethics::EthicsProfile,
+    governance_metadata::GovernanceMetadata,
+    nist_ai_rmf::{AIRmfFunction, AIRmfProfile},
+    nist_80053::{Sp80053Family, Sp80053Impact, Sp80053Profile},
+};
 
 fn main() {
-    let geometry = Geometry::new(geojson::Value::Polygon(vec![]));
+    // Counter-clockwise exterior ring around a downtown block (RFC 7946).
+    let geometry = Geometry::new(geojson::Value::Polygon(vec![vec![
+        vec![-112.0740, 33.4480],
+        vec![-112.0720, 33.4480],
+        vec![-112.0720, 33.4495],
+        vec![-112.0740, 33.4495],
+        vec![-112.0740, 33.4480],
+    ]]));
 
     let geo = GeoIntelligence {
         location_band: "urban-core".to_string(),
@@ -44,10 +58,32 @@ fn main() {
     };
 
     let rights = RightsMetadata {
+        version: 1,
         species_rights,
         neurorights,
     };
 
+    let metadata = GovernanceMetadata {
+        jurisdiction_code: "US-FED".to_string(),
+        ai_rmf_profile: AIRmfProfile {
+            functions: AIRmfFunction::GOVERN | AIRmfFunction::MANAGE,
+            requires_human_in_loop: true,
+            requires_appeal_path: true,
+        },
+        sp80053_profile: Sp80053Profile {
+            families: Sp80053Family::AC | Sp80053Family::AU | Sp80053Family::SC,
+            impact: Sp80053Impact::High,
+        },
+        ethics_profile: EthicsProfile {
+            human_primacy: true,
+            equal_power_thresholds: true,
+            appeal_paths_available: true,
+        },
+        data_owner_did: "did:example:eco-council".to_string(),
+        created_by_agent_id: Uuid::new_v4(),
+        last_modified_by_agent_id: Uuid::new_v4(),
+    };
+
     let np = NanopolygonSafetyObject {
         polygon_id: Uuid::new_v4(),
         geometry,
@@ -56,6 +92,7 @@ fn main() {
         learning,
         intelligence,
         rights,
+        metadata,
         timestamp_utc: Utc::now(),
     };
 
@@ -66,12 +103,18 @@ fn main() {
         segment_label: "eco-infra-routing".to_string(),
     };
 
-    let shard = AlnShard { header, nanopolygon: np };
+    let ctx = AuditContext::new(Uuid::new_v4()).with_actor_did("did:example:operator");
+    let mut audit_sink = InMemoryAuditSink::new();
+    let nanopolygon = validate(np, &ctx, &mut audit_sink).expect("valid nanopolygon");
+
+    let shard = AlnShard::new(header, nanopolygon);
     let sanctuary_shard = SanctuaryRoutingShard::from_nanopolygon(shard);
 
-    let enforce = is_sanctuary_enforced(
+    let enforce = enforce_sanctuary(
         &sanctuary_shard,
         sanctuary_shard.base.nanopolygon.intelligence.quantified_safety_index,
+        &ctx,
+        &mut audit_sink,
     );
 
     println!(
@@ -1,17 +1,24 @@
 mod device_class;
-mod shard;
 mod routing;
 
-pub use device_class::*;
-pub use shard::*;
-pub use routing::*;
-
 pub mod shard;
 pub mod sanctuary;
+pub mod route_sanctuary;
 
-pub use shard::{AlnDeviceClass, AlnShardHeader, AlnShard};
+pub use device_class::*;
+pub use routing::*;
+
+pub use shard::{AlnShardHeader, AlnShard};
+pub use crate::web5_integration::pqc::SignatureBlock;
 pub use sanctuary::{
     SanctuaryShardKind,
     SanctuaryRoutingShard,
+    enforce_sanctuary,
     is_sanctuary_enforced,
 };
+pub use route_sanctuary::{
+    RouteSanctuaryReport,
+    SanctuaryCrossing,
+    analyze_route,
+    analyze_line_string,
+};
@@ -0,0 +1,288 @@
+use geojson::{Geometry, Value};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::nanopolygon::{
+    AppealPathType,
+    ConsentRequirement,
+    NanopolygonRegistry,
+    NanopolygonSafetyObject,
+    TerritoryProtectionLevel,
+};
+use super::sanctuary::SanctuaryShardKind;
+
+/// One sanctuary‑relevant polygon an ALN route passes through.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SanctuaryCrossing {
+    pub polygon_id: Uuid,
+    pub kind: SanctuaryShardKind,
+    pub territory_protection: TerritoryProtectionLevel,
+    pub neurorights_sanctuary: bool,
+    pub consent_requirement: ConsentRequirement,
+    pub appeal_path: AppealPathType,
+}
+
+impl SanctuaryCrossing {
+    /// `None` if the polygon carries no sanctuary, neurorights or consent
+    /// protection.
+    pub fn from_nanopolygon(np: &NanopolygonSafetyObject) -> Option<Self> {
+        let neurorights = &np.rights.neurorights;
+        let territory = np.rights.species_rights.territory_protection;
+        let kind = SanctuaryShardKind::classify(np);
+
+        let relevant = kind != SanctuaryShardKind::None
+            || neurorights.neurorights_sanctuary
+            || matches!(
+                territory,
+                TerritoryProtectionLevel::Sanctuary | TerritoryProtectionLevel::SanctuaryCorridor
+            )
+            || neurorights.consent_requirement != ConsentRequirement::StandardGovernance;
+
+        relevant.then_some(SanctuaryCrossing {
+            polygon_id: np.polygon_id,
+            kind,
+            territory_protection: territory,
+            neurorights_sanctuary: neurorights.neurorights_sanctuary,
+            consent_requirement: neurorights.consent_requirement,
+            appeal_path: neurorights.appeal_path,
+        })
+    }
+}
+
+/// Everything a route crosses that constrains it, combined.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RouteSanctuaryReport {
+    /// In the order the route first reaches each polygon.
+    pub crossings: Vec<SanctuaryCrossing>,
+    /// Strictest consent over every crossing (`StandardGovernance` if none).
+    pub consent_requirement: ConsentRequirement,
+    /// Where an appeal against the route goes: the highest body on the
+    /// escalation ladder among the crossings (`None` if none).
+    pub appeal_path: Option<AppealPathType>,
+    /// Distinct appeal bodies of the crossed polygons, in route order.
+    /// Supporting detail only; `appeal_path` is the one to use.
+    pub appeal_paths: Vec<AppealPathType>,
+}
+
+impl RouteSanctuaryReport {
+    pub fn is_clear(&self) -> bool {
+        self.crossings.is_empty()
+    }
+
+    pub fn crosses_neurorights_sanctuary(&self) -> bool {
+        self.crossings.iter().any(|c| c.neurorights_sanctuary)
+    }
+
+    pub fn corridors(&self) -> impl Iterator<Item = &SanctuaryCrossing> {
+        self.crossings
+            .iter()
+            .filter(|c| c.kind == SanctuaryShardKind::BiophysicalSanctuaryCorridor)
+    }
+
+    pub fn consent_domains(&self) -> impl Iterator<Item = &SanctuaryCrossing> {
+        self.crossings
+            .iter()
+            .filter(|c| c.consent_requirement != ConsentRequirement::StandardGovernance)
+    }
+}
+
+/// Analyses a route given as `[lon, lat]` positions against every polygon
+/// in `registry`.
+pub fn analyze_route(route: &[[f64; 2]], registry: &NanopolygonRegistry) -> RouteSanctuaryReport {
+    let crossings: Vec<SanctuaryCrossing> = registry
+        .intersecting_route(route)
+        .into_iter()
+        .filter_map(|np| SanctuaryCrossing::from_nanopolygon(np))
+        .collect();
+
+    let consent_requirement = crossings
+        .iter()
+        .fold(ConsentRequirement::StandardGovernance, |acc, c| {
+            acc.strictest(c.consent_requirement)
+        });
+
+    let appeal_path = crossings
+        .iter()
+        .map(|c| c.appeal_path)
+        .reduce(AppealPathType::strictest);
+
+    let mut appeal_paths = Vec::new();
+    for crossing in &crossings {
+        if !appeal_paths.contains(&crossing.appeal_path) {
+            appeal_paths.push(crossing.appeal_path);
+        }
+    }
+
+    RouteSanctuaryReport {
+        crossings,
+        consent_requirement,
+        appeal_path,
+        appeal_paths,
+    }
+}
+
+/// Like `analyze_route` for a GeoJSON LineString; `None` for any other
+/// geometry type or a position without longitude and latitude.
+pub fn analyze_line_string(
+    geometry: &Geometry,
+    registry: &NanopolygonRegistry,
+) -> Option<RouteSanctuaryReport> {
+    match &geometry.value {
+        Value::LineString(positions) => {
+            let route = positions
+                .iter()
+                .map(|p| (p.len() >= 2).then(|| [p[0], p[1]]))
+                .collect::<Option<Vec<_>>>()?;
+            Some(analyze_route(&route, registry))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use geojson::Geometry;
+
+    use super::*;
+    use crate::nanopolygon::fixtures::nanopolygon;
+    use crate::nanopolygon::ValidatedNanopolygon;
+
+    const ROUTE: [[f64; 2]; 2] = [[-112.080, 33.4487], [-112.040, 33.4487]];
+
+    /// Fixture polygon shifted `dx` degrees east with the given appeal body.
+    fn polygon(id: u128, dx: f64, appeal_path: AppealPathType) -> ValidatedNanopolygon {
+        shifted(id, dx, |np| np.rights.neurorights.appeal_path = appeal_path)
+    }
+
+    /// Fixture polygon shifted `dx` degrees east, then adjusted by `edit`.
+    fn shifted(
+        id: u128,
+        dx: f64,
+        edit: impl FnOnce(&mut NanopolygonSafetyObject),
+    ) -> ValidatedNanopolygon {
+        let mut np = nanopolygon();
+        np.polygon_id = Uuid::from_u128(id);
+        if let Value::Polygon(rings) = &np.geometry.value {
+            let shifted = rings
+                .iter()
+                .map(|ring| ring.iter().map(|p| vec![p[0] + dx, p[1]]).collect())
+                .collect();
+            np.geometry = Geometry::new(Value::Polygon(shifted));
+        }
+        edit(&mut np);
+        ValidatedNanopolygon::try_from(np).unwrap()
+    }
+
+    /// Fixture polygon shifted `dx` degrees east with no protection but the
+    /// given consent requirement.
+    fn consent_domain(id: u128, dx: f64, consent: ConsentRequirement) -> ValidatedNanopolygon {
+        shifted(id, dx, |np| {
+            np.rights.species_rights.territory_protection = TerritoryProtectionLevel::None;
+            np.rights.neurorights.neurorights_sanctuary = false;
+            np.rights.neurorights.consent_requirement = consent;
+        })
+    }
+
+    fn ids<'a>(crossings: impl Iterator<Item = &'a SanctuaryCrossing>) -> Vec<u128> {
+        crossings.map(|c| c.polygon_id.as_u128()).collect()
+    }
+
+    #[test]
+    fn strictest_appeal_path_climbs_the_ladder() {
+        use AppealPathType::*;
+        assert_eq!(CommunityCouncil.strictest(EcoInfraCouncil), EcoInfraCouncil);
+        assert_eq!(EthicsPanel.strictest(CommunityCouncil), EthicsPanel);
+        assert_eq!(EcoInfraCouncil.strictest(EcoInfraCouncil), EcoInfraCouncil);
+    }
+
+    #[test]
+    fn report_names_one_appeal_path_for_the_route() {
+        let registry = NanopolygonRegistry::from_polygons([
+            polygon(1, 0.0, AppealPathType::EcoInfraCouncil),
+            polygon(2, 0.01, AppealPathType::EthicsPanel),
+            polygon(3, 0.02, AppealPathType::CommunityCouncil),
+        ]);
+        let report = analyze_route(&ROUTE, &registry);
+
+        assert_eq!(report.crossings.len(), 3);
+        assert_eq!(report.appeal_path, Some(AppealPathType::EthicsPanel));
+        assert_eq!(
+            report.appeal_paths,
+            [AppealPathType::EcoInfraCouncil, AppealPathType::EthicsPanel, AppealPathType::CommunityCouncil]
+        );
+
+        let clear = analyze_route(&[[0.0, 0.0], [0.001, 0.001]], &registry);
+        assert!(clear.is_clear());
+        assert_eq!(clear.appeal_path, None);
+        assert_eq!(clear.consent_requirement, ConsentRequirement::StandardGovernance);
+    }
+
+    #[test]
+    fn community_and_individual_crossings_combine() {
+        let registry = NanopolygonRegistry::from_polygons([
+            consent_domain(1, 0.0, ConsentRequirement::CommunityMultisigRequired),
+            consent_domain(2, 0.01, ConsentRequirement::IndividualConsentRequired),
+            consent_domain(3, 0.02, ConsentRequirement::StandardGovernance),
+        ]);
+        let report = analyze_route(&ROUTE, &registry);
+
+        // The unprotected polygon is not a crossing at all.
+        assert_eq!(ids(report.crossings.iter()), [1, 2]);
+        assert_eq!(report.consent_requirement, ConsentRequirement::CommunityAndIndividual);
+        assert_eq!(ids(report.consent_domains()), [1, 2]);
+        assert!(report
+            .crossings
+            .iter()
+            .all(|c| c.kind == SanctuaryShardKind::ConsentRequiredCyberneticDomain));
+        assert_eq!(report.corridors().count(), 0);
+        assert!(!report.crosses_neurorights_sanctuary());
+    }
+
+    #[test]
+    fn report_views_pick_out_corridors_consent_and_neurorights() {
+        let registry = NanopolygonRegistry::from_polygons([
+            // Fixture: corridor, neurorights sanctuary, community and individual.
+            shifted(1, 0.0, |_| {}),
+            shifted(2, 0.01, |np| {
+                np.rights.species_rights.territory_protection = TerritoryProtectionLevel::Sanctuary;
+                np.rights.neurorights.neurorights_sanctuary = false;
+                np.rights.neurorights.consent_requirement = ConsentRequirement::StandardGovernance;
+            }),
+            shifted(3, 0.02, |np| {
+                np.rights.species_rights.territory_protection =
+                    TerritoryProtectionLevel::RegulatedExperimentZone;
+                np.rights.neurorights.consent_requirement = ConsentRequirement::StandardGovernance;
+            }),
+        ]);
+        let report = analyze_route(&ROUTE, &registry);
+
+        assert_eq!(ids(report.crossings.iter()), [1, 2, 3]);
+        assert_eq!(ids(report.corridors()), [1]);
+        assert_eq!(ids(report.consent_domains()), [1]);
+        assert!(report.crosses_neurorights_sanctuary());
+        assert_eq!(report.crossings[1].kind, SanctuaryShardKind::None);
+
+        let no_neurorights = NanopolygonRegistry::from_polygons([shifted(2, 0.01, |np| {
+            np.rights.neurorights.neurorights_sanctuary = false;
+        })]);
+        assert!(!analyze_route(&ROUTE, &no_neurorights).crosses_neurorights_sanctuary());
+    }
+
+    #[test]
+    fn line_strings_are_analysed_like_routes() {
+        let registry = NanopolygonRegistry::from_polygons([
+            polygon(1, 0.0, AppealPathType::EcoInfraCouncil),
+            polygon(2, 0.01, AppealPathType::EthicsPanel),
+        ]);
+        let line = Geometry::new(Value::LineString(ROUTE.iter().map(|p| p.to_vec()).collect()));
+        assert_eq!(
+            analyze_line_string(&line, &registry),
+            Some(analyze_route(&ROUTE, &registry))
+        );
+
+        let point = Geometry::new(Value::Point(ROUTE[0].to_vec()));
+        assert_eq!(analyze_line_string(&point, &registry), None);
+        let short = Geometry::new(Value::LineString(vec![ROUTE[0].to_vec(), vec![-112.040]]));
+        assert_eq!(analyze_line_string(&short, &registry), None);
+    }
+}
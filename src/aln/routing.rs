@@ -1,39 +1,691 @@
-use crate::audit::{event::AuditEvent, sink::AuditSink};
+use std::{fs, path::Path};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::audit::{
+    context::AuditContext,
+    event::AuditEvent,
+    sink::AuditSink,
+    trace::{DecisionTrace, TraceStep},
+};
+use crate::nanopolygon::{FreshnessPolicy, Staleness};
+use crate::policy::governance_metadata::HasGovernanceMetadata;
+use crate::policy::nist_80053::{Sp80053Family, Sp80053Impact};
 use super::{AlnShard, device_class::AlnDeviceClass};
 
-#[derive(Debug)]
+/// Built-in policy reproducing the original hardcoded segmentation:
+/// sensors may feed datacenter nodes and SCADA gateways may feed
+/// supercomputer nodes, everything else is denied.
+/// Telemetry freshness uses the `FreshnessPolicy` defaults.
+pub const BASELINE_ROUTING_POLICY: &str = r#"
+policy_id = "aln-baseline"
+version = "1"
+
+[freshness]
+low_max_age_secs = 86400
+moderate_max_age_secs = 14400
+high_max_age_secs = 1800
+extreme_max_age_secs = 300
+clock_skew_secs = 120
+
+[[rules]]
+id = "sensor_to_dc"
+effect = "allow"
+source = "Sensor"
+target = "DatacenterNode"
+
+[[rules]]
+id = "scada_to_supercomputer"
+effect = "allow"
+source = "ScadaGateway"
+target = "SupercomputerNode"
+"#;
+
+/// Denial codes the evaluator itself records. A rule with one of these ids
+/// would make its denials indistinguishable from the built-in ones in the
+/// audit trail.
+pub const RESERVED_RULE_IDS: &[&str] = &[
+    "cybernetic_device",
+    "default_deny",
+    "stale_telemetry",
+    "future_timestamp",
+    "telemetry_freshness",
+];
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RuleEffect {
+    Allow,
+    Deny,
+}
+
+/// One routing rule as written in a policy file. Omitted match fields are
+/// wildcards.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RoutingRuleSpec {
+    pub id: String,
+    pub effect: RuleEffect,
+    /// Higher priorities are evaluated first.
+    #[serde(default)]
+    pub priority: i32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<AlnDeviceClass>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target: Option<AlnDeviceClass>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub segment_label: Option<String>,
+    /// Matches only shards whose SP 800‑53 impact is at or below this level.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_impact: Option<Sp80053Impact>,
+    /// Matches only shards whose profile includes all of these families,
+    /// written as e.g. `"AC | SC"`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub required_families: Option<Sp80053Family>,
+}
+
+/// Serialized form of a routing policy (TOML or JSON).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RoutingPolicySpec {
+    pub policy_id: String,
+    pub version: String,
+    /// Shards whose telemetry is stale or future-dated are denied before
+    /// any rule is considered. There is no way to turn this off; an omitted
+    /// table means `FreshnessPolicy::default()`.
+    #[serde(default)]
+    pub freshness: FreshnessPolicy,
+    #[serde(default)]
+    pub rules: Vec<RoutingRuleSpec>,
+}
+
+#[derive(Debug, Error)]
+pub enum RoutingPolicyError {
+    #[error("invalid TOML routing policy: {0}")]
+    Toml(#[from] toml::de::Error),
+    #[error("invalid JSON routing policy: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("cannot read routing policy: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("unsupported routing policy file extension: {0}")]
+    UnsupportedFormat(String),
+    #[error("routing policy is missing a policy_id or version")]
+    MissingIdentity,
+    #[error("routing rule #{0} has an empty id")]
+    EmptyRuleId(usize),
+    #[error("duplicate routing rule id `{0}`")]
+    DuplicateRuleId(String),
+    #[error("routing rule id `{0}` is reserved for built-in decisions")]
+    ReservedRuleId(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum RoutingDenial {
+    #[error("cybernetic devices are prohibited")]
+    CyberneticDevice,
+    #[error("denied by routing rule `{0}`")]
+    DeniedByRule(String),
+    #[error("no routing rule allows this path under zero-trust ALN")]
+    NoMatchingRule,
+    #[error("{0}")]
+    TelemetryNotFresh(Staleness),
+}
+
+impl RoutingDenial {
+    /// Reason recorded on the `RoutingDenied` audit event.
+    pub fn code(&self) -> &str {
+        match self {
+            RoutingDenial::CyberneticDevice => "cybernetic_device",
+            RoutingDenial::DeniedByRule(rule_id) => rule_id,
+            RoutingDenial::NoMatchingRule => "default_deny",
+            RoutingDenial::TelemetryNotFresh(staleness) => staleness.code(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum RoutingDecision {
-    Allowed,
-    Denied(&'static str),
+    Allowed { rule_id: String },
+    Denied(RoutingDenial),
 }
 
-pub struct RoutingPolicy;
+/// Compiled routing policy. Rules are held in evaluation order: priority
+/// descending, deny before allow at equal priority, then declaration order.
+/// The first matching rule decides; no match is a denial.
+#[derive(Debug, Clone)]
+pub struct RoutingPolicy {
+    policy_id: String,
+    version: String,
+    freshness: FreshnessPolicy,
+    rules: Vec<RoutingRuleSpec>,
+}
 
 impl RoutingPolicy {
-    pub fn evaluate<P: AuditSink>(shard: &AlnShard, audit_sink: &mut P) -> RoutingDecision {
+    pub fn compile(spec: RoutingPolicySpec) -> Result<Self, RoutingPolicyError> {
+        if spec.policy_id.trim().is_empty() || spec.version.trim().is_empty() {
+            return Err(RoutingPolicyError::MissingIdentity);
+        }
+
+        let mut seen = std::collections::HashSet::new();
+        for (index, rule) in spec.rules.iter().enumerate() {
+            if rule.id.trim().is_empty() {
+                return Err(RoutingPolicyError::EmptyRuleId(index));
+            }
+            if RESERVED_RULE_IDS.contains(&rule.id.as_str()) {
+                return Err(RoutingPolicyError::ReservedRuleId(rule.id.clone()));
+            }
+            if !seen.insert(rule.id.as_str()) {
+                return Err(RoutingPolicyError::DuplicateRuleId(rule.id.clone()));
+            }
+        }
+
+        let mut rules = spec.rules;
+        // Stable sort keeps declaration order as the final tie-breaker.
+        rules.sort_by_key(|rule| {
+            (std::cmp::Reverse(rule.priority), rule.effect != RuleEffect::Deny)
+        });
+
+        Ok(RoutingPolicy {
+            policy_id: spec.policy_id,
+            version: spec.version,
+            freshness: spec.freshness,
+            rules,
+        })
+    }
+
+    pub fn from_toml_str(s: &str) -> Result<Self, RoutingPolicyError> {
+        Self::compile(toml::from_str(s)?)
+    }
+
+    pub fn from_json_str(s: &str) -> Result<Self, RoutingPolicyError> {
+        Self::compile(serde_json::from_str(s)?)
+    }
+
+    /// Loads a `.toml` or `.json` policy file.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, RoutingPolicyError> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path)?;
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => Self::from_toml_str(&text),
+            Some("json") => Self::from_json_str(&text),
+            _ => Err(RoutingPolicyError::UnsupportedFormat(path.display().to_string())),
+        }
+    }
+
+    pub fn policy_id(&self) -> &str {
+        &self.policy_id
+    }
+
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    pub fn freshness(&self) -> &FreshnessPolicy {
+        &self.freshness
+    }
+
+    /// Rules in evaluation order.
+    pub fn rules(&self) -> &[RoutingRuleSpec] {
+        &self.rules
+    }
+
+    pub fn evaluate<P: AuditSink>(
+        &self,
+        shard: &AlnShard,
+        ctx: &AuditContext,
+        audit_sink: &mut P,
+    ) -> RoutingDecision {
+        let (decision, trace) = self.explain(shard);
+
+        if let RoutingDecision::Denied(RoutingDenial::TelemetryNotFresh(staleness)) = &decision {
+            audit_sink.record(
+                AuditEvent::telemetry_not_fresh(ctx, shard.nanopolygon.timestamp_utc, staleness)
+                    .with_subject(shard.audit_subject()),
+            );
+        }
+
+        let event = match &decision {
+            RoutingDecision::Allowed { rule_id } => AuditEvent::routing_allowed(ctx, rule_id),
+            RoutingDecision::Denied(denial) => AuditEvent::routing_denied(ctx, denial.code()),
+        };
+        audit_sink.record(event.with_subject(shard.audit_subject()).with_trace(trace));
+        decision
+    }
+
+    /// Evaluates without recording anything, returning the decision and a
+    /// trace of every rule considered up to the deciding one.
+    pub fn explain(&self, shard: &AlnShard) -> (RoutingDecision, DecisionTrace) {
+        self.explain_at(shard, Utc::now())
+    }
+
+    /// As `explain`, judging telemetry freshness as of `now`.
+    pub fn explain_at(&self, shard: &AlnShard, now: DateTime<Utc>) -> (RoutingDecision, DecisionTrace) {
         let src = shard.header.source_device_class;
         let dst = shard.header.target_device_class;
+        let mut trace = DecisionTrace::new(format!("aln_routing:{}@{}", self.policy_id, self.version));
 
-        // No cybernetic / BCI / neuromorphic classes exist here by construction. [file:3][file:5]
-        if src.is_cybernetic() || dst.is_cybernetic() {
-            audit_sink.record(AuditEvent::routing_denied("cybernetic_device"));
-            return RoutingDecision::Denied("cybernetic devices are prohibited");
+        // No cybernetic / BCI / neuromorphic classes exist here by construction,
+        // and no policy file can override this. [file:3][file:5]
+        let cybernetic = src.is_cybernetic() || dst.is_cybernetic();
+        trace.push(
+            TraceStep::new(
+                "cybernetic_device",
+                cybernetic,
+                if cybernetic {
+                    "cybernetic device classes are prohibited"
+                } else {
+                    "no cybernetic device class on either end"
+                },
+            )
+            .with_input("source_device_class", src)
+            .with_input("target_device_class", dst),
+        );
+        if cybernetic {
+            let denial = RoutingDenial::CyberneticDevice;
+            let trace = trace.finish(denial.code());
+            return (RoutingDecision::Denied(denial), trace);
         }
 
-        // Minimal example: disallow direct OT‑>IT lateral movement, enforce ALN segmentation. [file:1][file:2]
-        match (src, dst) {
-            (AlnDeviceClass::Sensor, AlnDeviceClass::DatacenterNode) => {
-                audit_sink.record(AuditEvent::routing_allowed("sensor_to_dc"));
-                RoutingDecision::Allowed
-            }
-            (AlnDeviceClass::ScadaGateway, AlnDeviceClass::SupercomputerNode) => {
-                audit_sink.record(AuditEvent::routing_allowed("scada_to_supercomputer"));
-                RoutingDecision::Allowed
-            }
-            _ => {
-                audit_sink.record(AuditEvent::routing_denied("disallowed_path"));
-                RoutingDecision::Denied("disallowed routing path under zero-trust ALN")
+        let freshness = &self.freshness;
+        let np = &shard.nanopolygon;
+        let result = freshness.check(np, now);
+        trace.push(
+            TraceStep::new(
+                "telemetry_freshness",
+                result.is_err(),
+                match &result {
+                    Err(staleness) => staleness.to_string(),
+                    Ok(()) => "telemetry within freshness window".to_string(),
+                },
+            )
+            .with_input("timestamp_utc", np.timestamp_utc)
+            .with_input("hazard_level", np.geo.hazard_level)
+            .with_input("max_age_secs", freshness.max_age(np.geo.hazard_level).num_seconds())
+            .with_input("clock_skew_secs", freshness.clock_skew_secs),
+        );
+        if let Err(staleness) = result {
+            let denial = RoutingDenial::TelemetryNotFresh(staleness);
+            let trace = trace.finish(denial.code());
+            return (RoutingDecision::Denied(denial), trace);
+        }
+
+        for rule in &self.rules {
+            let step = rule_step(rule, shard);
+            let matched = step.matched;
+            trace.push(step);
+            if matched {
+                let decision = match rule.effect {
+                    RuleEffect::Allow => RoutingDecision::Allowed {
+                        rule_id: rule.id.clone(),
+                    },
+                    RuleEffect::Deny => {
+                        RoutingDecision::Denied(RoutingDenial::DeniedByRule(rule.id.clone()))
+                    }
+                };
+                let trace = trace.finish(&rule.id);
+                return (decision, trace);
             }
         }
+
+        let denial = RoutingDenial::NoMatchingRule;
+        let trace = trace.finish(denial.code());
+        (RoutingDecision::Denied(denial), trace)
+    }
+}
+
+impl Default for RoutingPolicy {
+    fn default() -> Self {
+        Self::from_toml_str(BASELINE_ROUTING_POLICY).expect("baseline routing policy is valid")
+    }
+}
+
+fn rule_step(rule: &RoutingRuleSpec, shard: &AlnShard) -> TraceStep {
+    let header = &shard.header;
+    let profile = &shard.governance_metadata().sp80053_profile;
+
+    let mismatch = if rule.source.is_some_and(|c| c != header.source_device_class) {
+        Some("source device class differs")
+    } else if rule.target.is_some_and(|c| c != header.target_device_class) {
+        Some("target device class differs")
+    } else if rule
+        .segment_label
+        .as_deref()
+        .is_some_and(|label| label != header.segment_label)
+    {
+        Some("segment label differs")
+    } else if rule.max_impact.is_some_and(|max| profile.impact > max) {
+        Some("impact above rule ceiling")
+    } else if rule
+        .required_families
+        .is_some_and(|families| !profile.families.contains(families))
+    {
+        Some("required SP 800-53 families missing")
+    } else {
+        None
+    };
+
+    let reason = match (mismatch, rule.effect) {
+        (Some(why), _) => why,
+        (None, RuleEffect::Allow) => "all conditions met; path allowed",
+        (None, RuleEffect::Deny) => "all conditions met; path denied",
+    };
+
+    let mut step = TraceStep::new(rule.id.as_str(), mismatch.is_none(), reason)
+        .with_input("source_device_class", header.source_device_class)
+        .with_input("target_device_class", header.target_device_class)
+        .with_input("segment_label", &header.segment_label);
+    if rule.max_impact.is_some() {
+        step = step.with_input("impact", profile.impact);
+    }
+    if rule.required_families.is_some() {
+        step = step.with_input("families", profile.families);
+    }
+    step
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RULE: &str = r#"
+policy_id = "p"
+version = "1"
+
+[[rules]]
+id = "RULE_ID"
+effect = "deny"
+"#;
+
+    fn with_rule_id(id: &str) -> Result<RoutingPolicy, RoutingPolicyError> {
+        RoutingPolicy::from_toml_str(&RULE.replace("RULE_ID", id))
+    }
+
+    #[test]
+    fn baseline_policy_compiles() {
+        let policy = RoutingPolicy::default();
+        assert_eq!(policy.policy_id(), "aln-baseline");
+        assert_eq!(policy.rules().len(), 2);
+        assert_eq!(policy.freshness(), &FreshnessPolicy::default());
+    }
+
+    #[test]
+    fn freshness_is_enforced_even_when_omitted() {
+        use crate::nanopolygon::fixtures::shard;
+
+        let policy = with_rule_id("deny_nothing").unwrap();
+        assert_eq!(policy.freshness(), &FreshnessPolicy::default());
+
+        let shard = shard();
+        let (_, trace) = RoutingPolicy::default().explain(&shard);
+        assert_eq!(trace.outcome, "scada_to_supercomputer");
+
+        let later = shard.nanopolygon.timestamp_utc + chrono::Duration::days(2);
+        let (decision, trace) = RoutingPolicy::default().explain_at(&shard, later);
+        assert!(matches!(
+            decision,
+            RoutingDecision::Denied(RoutingDenial::TelemetryNotFresh(Staleness::Stale { .. }))
+        ));
+        assert_eq!(trace.outcome, "stale_telemetry");
+
+        let earlier = shard.nanopolygon.timestamp_utc - chrono::Duration::hours(1);
+        let (decision, _) = policy.explain_at(&shard, earlier);
+        assert!(matches!(
+            decision,
+            RoutingDecision::Denied(RoutingDenial::TelemetryNotFresh(Staleness::FromFuture { .. }))
+        ));
+    }
+
+    #[test]
+    fn reserved_rule_ids_are_rejected() {
+        for id in RESERVED_RULE_IDS {
+            assert!(
+                matches!(with_rule_id(id), Err(RoutingPolicyError::ReservedRuleId(r)) if r == *id),
+                "{id} must be reserved"
+            );
+        }
+        let json = r#"{"policy_id": "p", "version": "1",
+                       "rules": [{"id": "default_deny", "effect": "allow"}]}"#;
+        assert!(matches!(
+            RoutingPolicy::from_json_str(json),
+            Err(RoutingPolicyError::ReservedRuleId(_))
+        ));
+        with_rule_id("deny_sensor_uplink").unwrap();
+    }
+
+    #[test]
+    fn rule_ids_must_be_present_and_unique() {
+        assert!(matches!(with_rule_id(" "), Err(RoutingPolicyError::EmptyRuleId(0))));
+        let rule = RULE.replace("RULE_ID", "a");
+        let duplicate = format!("{rule}\n[[rules]]\nid = \"a\"\neffect = \"allow\"\n");
+        assert!(matches!(
+            RoutingPolicy::from_toml_str(&duplicate),
+            Err(RoutingPolicyError::DuplicateRuleId(id)) if id == "a"
+        ));
+        assert!(matches!(
+            RoutingPolicy::from_toml_str("policy_id = \"\"\nversion = \"1\""),
+            Err(RoutingPolicyError::MissingIdentity)
+        ));
+    }
+
+    #[test]
+    fn toml_parse_errors_are_reported() {
+        for text in [
+            "policy_id = ",
+            "version = \"1\"",
+            &RULE.replace("effect = \"deny\"", "effect = \"maybe\""),
+            &RULE.replace("effect = \"deny\"", "effect = \"deny\"\nsorce = \"Sensor\""),
+            &RULE.replace("effect = \"deny\"", "effect = \"deny\"\nsource = \"NeuralLace\""),
+        ] {
+            assert!(
+                matches!(RoutingPolicy::from_toml_str(text), Err(RoutingPolicyError::Toml(_))),
+                "{text:?} must not parse"
+            );
+        }
+    }
+
+    #[test]
+    fn json_parse_errors_are_reported() {
+        for text in [
+            "{",
+            r#"{"policy_id": "p"}"#,
+            r#"{"policy_id": "p", "version": "1", "extra": true}"#,
+            r#"{"policy_id": "p", "version": "1", "rules": [{"id": "a"}]}"#,
+            r#"{"policy_id": "p", "version": "1", "rules": [{"id": "a", "effect": "allow", "priority": "high"}]}"#,
+        ] {
+            assert!(
+                matches!(RoutingPolicy::from_json_str(text), Err(RoutingPolicyError::Json(_))),
+                "{text:?} must not parse"
+            );
+        }
+    }
+
+    #[test]
+    fn files_are_loaded_by_extension() {
+        let dir = std::env::temp_dir().join(format!("ecoinfra-routing-{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+        let toml_path = dir.join("policy.toml");
+        fs::write(&toml_path, BASELINE_ROUTING_POLICY).unwrap();
+        let yaml_path = dir.join("policy.yaml");
+        fs::write(&yaml_path, BASELINE_ROUTING_POLICY).unwrap();
+
+        let loaded = RoutingPolicy::from_path(&toml_path);
+        let unsupported = RoutingPolicy::from_path(&yaml_path);
+        let missing = RoutingPolicy::from_path(dir.join("absent.json"));
+        let _ = fs::remove_dir_all(&dir);
+
+        assert_eq!(loaded.unwrap().policy_id(), "aln-baseline");
+        assert!(matches!(unsupported, Err(RoutingPolicyError::UnsupportedFormat(_))));
+        assert!(matches!(missing, Err(RoutingPolicyError::Io(_))));
+    }
+
+    fn evaluate(rules: &str) -> (RoutingDecision, DecisionTrace) {
+        let policy = RoutingPolicy::from_toml_str(&format!("policy_id = \"p\"\nversion = \"1\"\n{rules}")).unwrap();
+        policy.explain(&crate::nanopolygon::fixtures::shard())
+    }
+
+    fn allowed(rule_id: &str) -> RoutingDecision {
+        RoutingDecision::Allowed {
+            rule_id: rule_id.to_string(),
+        }
+    }
+
+    fn denied(rule_id: &str) -> RoutingDecision {
+        RoutingDecision::Denied(RoutingDenial::DeniedByRule(rule_id.to_string()))
+    }
+
+    #[test]
+    fn higher_priority_rules_win() {
+        let (decision, trace) = evaluate(
+            r#"
+[[rules]]
+id = "allow_all"
+effect = "allow"
+
+[[rules]]
+id = "lockdown"
+effect = "deny"
+priority = 10
+"#,
+        );
+        assert_eq!(decision, denied("lockdown"));
+        assert_eq!(trace.steps.last().unwrap().rule, "lockdown");
+
+        let (decision, _) = evaluate(
+            r#"
+[[rules]]
+id = "lockdown"
+effect = "deny"
+priority = -1
+
+[[rules]]
+id = "allow_all"
+effect = "allow"
+"#,
+        );
+        assert_eq!(decision, allowed("allow_all"));
+    }
+
+    #[test]
+    fn deny_beats_allow_at_equal_priority() {
+        let (decision, _) = evaluate(
+            r#"
+[[rules]]
+id = "allow_all"
+effect = "allow"
+priority = 5
+
+[[rules]]
+id = "deny_all"
+effect = "deny"
+priority = 5
+"#,
+        );
+        assert_eq!(decision, denied("deny_all"));
+    }
+
+    #[test]
+    fn declaration_order_breaks_remaining_ties() {
+        let (decision, trace) = evaluate(
+            r#"
+[[rules]]
+id = "first"
+effect = "allow"
+
+[[rules]]
+id = "second"
+effect = "allow"
+"#,
+        );
+        assert_eq!(decision, allowed("first"));
+        assert_eq!(trace.outcome, "first");
+    }
+
+    #[test]
+    fn omitted_fields_are_wildcards() {
+        let (decision, _) = evaluate(
+            r#"
+[[rules]]
+id = "to_supercomputer"
+effect = "allow"
+target = "SupercomputerNode"
+"#,
+        );
+        assert_eq!(decision, allowed("to_supercomputer"));
+
+        let (decision, _) = evaluate(
+            r#"
+[[rules]]
+id = "from_sensor"
+effect = "allow"
+source = "Sensor"
+"#,
+        );
+        assert_eq!(decision, RoutingDecision::Denied(RoutingDenial::NoMatchingRule));
+    }
+
+    #[test]
+    fn label_impact_and_families_must_match() {
+        // The fixture shard is labelled "eco-infra-routing", High impact,
+        // with AC | AU | SC.
+        let (decision, trace) = evaluate(
+            r#"
+[[rules]]
+id = "other_label"
+effect = "deny"
+priority = 3
+segment_label = "billing"
+
+[[rules]]
+id = "moderate_only"
+effect = "deny"
+priority = 2
+max_impact = "Moderate"
+
+[[rules]]
+id = "needs_risk_assessment"
+effect = "deny"
+priority = 1
+required_families = "AC | RA"
+
+[[rules]]
+id = "eco_infra"
+effect = "allow"
+segment_label = "eco-infra-routing"
+max_impact = "High"
+required_families = "AC | SC"
+"#,
+        );
+        assert_eq!(decision, allowed("eco_infra"));
+        let reasons: Vec<&str> = trace.steps[2..].iter().map(|s| s.reason.as_str()).collect();
+        assert_eq!(
+            reasons,
+            [
+                "segment label differs",
+                "impact above rule ceiling",
+                "required SP 800-53 families missing",
+                "all conditions met; path allowed",
+            ]
+        );
+    }
+
+    #[test]
+    fn unmatched_paths_fall_through_to_default_deny() {
+        let (decision, trace) = evaluate(
+            r#"
+[[rules]]
+id = "sensor_to_dc"
+effect = "allow"
+source = "Sensor"
+target = "DatacenterNode"
+"#,
+        );
+        assert_eq!(decision, RoutingDecision::Denied(RoutingDenial::NoMatchingRule));
+        assert_eq!(trace.outcome, "default_deny");
+        assert!(trace.steps.iter().all(|s| !s.matched));
+
+        let (decision, _) = evaluate("");
+        assert_eq!(decision, RoutingDecision::Denied(RoutingDenial::NoMatchingRule));
     }
 }
@@ -1,10 +1,12 @@
 use serde::{Deserialize, Serialize};
 
+use crate::audit::{context::AuditContext, event::AuditEvent, sink::AuditSink};
 use crate::nanopolygon::{
     NanopolygonSafetyObject,
     TerritoryProtectionLevel,
     ConsentRequirement,
 };
+use crate::policy::governance_metadata::HasGovernanceMetadata;
 
 /// Classification of sanctuary‑relevant shards.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -17,17 +19,16 @@ pub enum SanctuaryShardKind {
     None,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Built only by `from_nanopolygon`, so `kind` always matches the shard.
+#[derive(Debug, Clone, Serialize)]
 pub struct SanctuaryRoutingShard {
     pub base: super::AlnShard,
     pub kind: SanctuaryShardKind,
 }
 
-impl SanctuaryRoutingShard {
-    pub fn from_nanopolygon(base: super::AlnShard) -> Self {
-        let np = &base.nanopolygon;
-
-        let kind = match np.rights.species_rights.territory_protection {
+impl SanctuaryShardKind {
+    pub fn classify(np: &NanopolygonSafetyObject) -> Self {
+        match np.rights.species_rights.territory_protection {
             TerritoryProtectionLevel::SanctuaryCorridor => {
                 SanctuaryShardKind::BiophysicalSanctuaryCorridor
             }
@@ -41,12 +42,18 @@ impl SanctuaryRoutingShard {
                     ConsentRequirement::StandardGovernance => SanctuaryShardKind::None,
                 }
             }
-        };
+        }
+    }
+}
 
+impl SanctuaryRoutingShard {
+    pub fn from_nanopolygon(base: super::AlnShard) -> Self {
+        let kind = SanctuaryShardKind::classify(&base.nanopolygon);
         SanctuaryRoutingShard { base, kind }
     }
 
     /// Helper: does this shard require explicit consent before actuation?
+    /// Whether it has been given is answered by `ConsentLedger::check`.
     pub fn requires_explicit_consent(&self) -> bool {
         matches!(
             self.kind,
@@ -70,3 +77,63 @@ pub fn is_sanctuary_enforced(
     // Else, enforce if HITL threshold is crossed.
     neurorights.requires_hitl(safety_index)
 }
+
+/// `is_sanctuary_enforced`, recording `SanctuaryEnforced` when it holds.
+/// The action names the trigger: `neurorights_sanctuary` or
+/// `hitl_threshold_crossed`.
+pub fn enforce_sanctuary<P: AuditSink>(
+    shard: &SanctuaryRoutingShard,
+    safety_index: f64,
+    ctx: &AuditContext,
+    audit_sink: &mut P,
+) -> bool {
+    if !is_sanctuary_enforced(shard, safety_index) {
+        return false;
+    }
+    let action = if shard.base.nanopolygon.rights.neurorights.neurorights_sanctuary {
+        "neurorights_sanctuary"
+    } else {
+        "hitl_threshold_crossed"
+    };
+    audit_sink.record(
+        AuditEvent::sanctuary_enforced(ctx, shard.kind.clone(), action)
+            .with_subject(shard.base.audit_subject()),
+    );
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::audit::{event::AuditKind, sink::InMemoryAuditSink};
+    use crate::nanopolygon::fixtures::shard;
+
+    #[test]
+    fn enforcement_is_audited_with_its_trigger() {
+        let ctx = AuditContext::new(Uuid::nil());
+        let mut sink = InMemoryAuditSink::new();
+        let sanctuary = SanctuaryRoutingShard::from_nanopolygon(shard());
+        assert!(enforce_sanctuary(&sanctuary, 0.5, &ctx, &mut sink));
+        assert!(matches!(
+            &sink.events[0].kind,
+            AuditKind::SanctuaryEnforced { kind: SanctuaryShardKind::BiophysicalSanctuaryCorridor, action }
+                if action == "neurorights_sanctuary"
+        ));
+
+        let mut np = shard().nanopolygon.into_inner();
+        np.rights.neurorights.neurorights_sanctuary = false;
+        np.rights.neurorights.hitl_trigger_threshold = 0.0;
+        let mut base = shard();
+        base.nanopolygon = np.try_into().unwrap();
+        let sanctuary = SanctuaryRoutingShard::from_nanopolygon(base);
+        assert!(!enforce_sanctuary(&sanctuary, 0.5, &ctx, &mut sink));
+        assert_eq!(sink.events.len(), 1);
+        assert!(enforce_sanctuary(&sanctuary, -0.5, &ctx, &mut sink));
+        assert!(matches!(
+            &sink.events[1].kind,
+            AuditKind::SanctuaryEnforced { action, .. } if action == "hitl_threshold_crossed"
+        ));
+    }
+}
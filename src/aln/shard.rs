@@ -1,8 +1,15 @@
-use serde::{Deserialize, Serialize};
+use serde::{de::{DeserializeSeed, Error as _}, Deserialize, Deserializer, Serialize};
 use uuid::Uuid;
 
-use crate::nanopolygon::ValidatedNanopolygon;
-use super::deviceclass::AlnDeviceClass;
+use crate::audit::event::AuditSubject;
+use crate::canonical::{to_canonical_bytes, CanonicalError};
+use crate::nanopolygon::{
+    DecodeError, NanopolygonSafetyObject, ValidatedNanopolygon, ValidationConfig, ValidationError,
+    ValidationSeed,
+};
+use crate::policy::governance_metadata::{GovernanceMetadata, HasGovernanceMetadata};
+use crate::web5_integration::pqc::SignatureBlock;
+use super::device_class::AlnDeviceClass;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AlnShardHeader {
@@ -13,8 +20,92 @@ pub struct AlnShardHeader {
     pub segment_label: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// The one shard envelope used by routing, sanctuary classification, HITL
+/// hooks and DWN audit. The signature block covers `signing_payload`;
+/// unsigned shards carry `signature: None`. See `crate::aln_shard` for
+/// signing and verification. Decode with `from_json_slice[_with]` or a
+/// `ValidationSeed`, which re-validate the nanopolygon.
+#[derive(Debug, Clone, Serialize)]
 pub struct AlnShard {
     pub header: AlnShardHeader,
     pub nanopolygon: ValidatedNanopolygon,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<SignatureBlock>,
+}
+
+/// Wire shape of `AlnShard` before the nanopolygon is validated.
+#[derive(Deserialize)]
+struct RawShard {
+    header: AlnShardHeader,
+    nanopolygon: NanopolygonSafetyObject,
+    #[serde(default)]
+    signature: Option<SignatureBlock>,
+}
+
+impl RawShard {
+    fn validate(self, config: &ValidationConfig) -> Result<AlnShard, ValidationError> {
+        Ok(AlnShard {
+            header: self.header,
+            nanopolygon: ValidatedNanopolygon::try_from_with(self.nanopolygon, config)?,
+            signature: self.signature,
+        })
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for ValidationSeed<'_, AlnShard> {
+    type Value = AlnShard;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        RawShard::deserialize(deserializer)?
+            .validate(self.config())
+            .map_err(D::Error::custom)
+    }
+}
+
+#[derive(Serialize)]
+struct SignedContent<'a> {
+    header: &'a AlnShardHeader,
+    nanopolygon: &'a ValidatedNanopolygon,
+}
+
+impl AlnShard {
+    pub fn new(header: AlnShardHeader, nanopolygon: ValidatedNanopolygon) -> Self {
+        Self {
+            header,
+            nanopolygon,
+            signature: None,
+        }
+    }
+
+    /// Decodes a shard, re-validating its nanopolygon under the default
+    /// `ValidationConfig`.
+    pub fn from_json_slice(bytes: &[u8]) -> Result<Self, DecodeError> {
+        Self::from_json_slice_with(bytes, &ValidationConfig::default())
+    }
+
+    /// As `from_json_slice`, under `config`.
+    pub fn from_json_slice_with(bytes: &[u8], config: &ValidationConfig) -> Result<Self, DecodeError> {
+        let raw: RawShard = serde_json::from_slice(bytes)?;
+        Ok(raw.validate(config)?)
+    }
+
+    /// RFC 8785 canonical bytes of `{"header", "nanopolygon"}`: what the
+    /// signature block covers. The header is included so a signed shard
+    /// cannot be re-routed.
+    pub fn signing_payload(&self) -> Result<Vec<u8>, CanonicalError> {
+        to_canonical_bytes(&SignedContent {
+            header: &self.header,
+            nanopolygon: &self.nanopolygon,
+        })
+    }
+}
+
+impl HasGovernanceMetadata for AlnShard {
+    fn governance_metadata(&self) -> &GovernanceMetadata {
+        &self.nanopolygon.metadata
+    }
+
+    fn audit_subject(&self) -> AuditSubject {
+        AuditSubject::shard(self.header.shard_id, self.nanopolygon.polygon_id)
+    }
 }
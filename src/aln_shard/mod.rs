@@ -0,0 +1,4 @@
+pub mod signing;
+
+pub use crate::aln::{AlnShard, SignatureBlock};
+pub use signing::{sign_shard, verify_shard};
@@ -1,16 +1,83 @@
-use super::AlnShard;
-use crate::nanopolygon::NanopolygonSafetyObject;
-use crate::web5_integration::did::DidKeyPair;
-
-// ML-DSA keypair abstraction; impl uses your chosen PQC lib.
-pub fn sign_shard(payload: NanopolygonSafetyObject, keypair: &DidKeyPair) -> AlnShard {
-    let did = keypair.did.clone();
-    let bytes = bincode::serialize(&payload).expect("serialize payload");
-    let signature = keypair.sign_ml_dsa(&bytes);
-    AlnShard { payload, did, signature }
+use super::{AlnShard, SignatureBlock};
+use crate::audit::{context::AuditContext, event::AuditEvent, sink::AuditSink};
+use crate::policy::governance_metadata::HasGovernanceMetadata;
+use crate::web5_integration::pqc::{SignatureError, Signer, Verifier};
+
+// Signs the RFC 8785 canonical encoding of header + nanopolygon (see
+// `AlnShard::signing_payload`). Any `Signer` works here: in-process
+// `DidKeyPair`, HSM or remote KMS. An existing signature is replaced.
+pub fn sign_shard<S: Signer>(mut shard: AlnShard, signer: &S) -> Result<AlnShard, SignatureError> {
+    let bytes = shard.signing_payload()?;
+    shard.signature = Some(SignatureBlock::sign(signer, &bytes)?);
+    Ok(shard)
 }
 
-pub fn verify_shard(shard: &AlnShard) -> bool {
-    let bytes = bincode::serialize(&shard.payload).ok()?;
-    crate::web5_integration::did::verify_ml_dsa(&shard.did, &bytes, &shard.signature)
+/// Checks the shard's signature block and records `SignatureVerified` or
+/// `SignatureVerificationFailed` (SI‑7).
+pub fn verify_shard<V: Verifier, P: AuditSink>(
+    shard: &AlnShard,
+    verifier: &V,
+    ctx: &AuditContext,
+    audit_sink: &mut P,
+) -> Result<(), SignatureError> {
+    let subject = shard.audit_subject();
+    let Some(block) = &shard.signature else {
+        let e = SignatureError::Unsigned;
+        audit_sink.record(
+            AuditEvent::signature_verification_failed(ctx, "", &e.to_string()).with_subject(subject),
+        );
+        return Err(e);
+    };
+
+    let result = shard
+        .signing_payload()
+        .map_err(SignatureError::from)
+        .and_then(|bytes| block.verify(verifier, &bytes));
+    let event = match &result {
+        Ok(()) => AuditEvent::signature_verified(ctx, &block.signer_did, block.algorithm.name()),
+        Err(e) => AuditEvent::signature_verification_failed(ctx, &block.signer_did, &e.to_string()),
+    };
+    audit_sink.record(event.with_subject(subject));
+    result
+}
+
+#[cfg(all(test, feature = "ml-dsa"))]
+mod tests {
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::audit::{event::AuditKind, sink::InMemoryAuditSink};
+    use crate::nanopolygon::fixtures::shard;
+    use crate::web5_integration::did::{DidKeyPair, DidKeyVerifier};
+
+    fn verify(shard: &AlnShard) -> (Result<(), SignatureError>, AuditKind) {
+        let mut sink = InMemoryAuditSink::new();
+        let result = verify_shard(shard, &DidKeyVerifier, &AuditContext::new(Uuid::nil()), &mut sink);
+        assert_eq!(sink.events.len(), 1);
+        assert_eq!(sink.events[0].subject, shard.audit_subject());
+        (result, sink.events.remove(0).kind)
+    }
+
+    #[test]
+    fn signed_shard_verifies_and_is_audited() {
+        let key = DidKeyPair::from_seed(&[7; 32]);
+        let signed = sign_shard(shard(), &key).unwrap();
+        let (result, kind) = verify(&signed);
+        assert!(result.is_ok());
+        assert!(matches!(kind, AuditKind::SignatureVerified { signer_did, .. } if signer_did == key.did));
+    }
+
+    #[test]
+    fn rerouted_or_unsigned_shard_fails_and_is_audited() {
+        let key = DidKeyPair::from_seed(&[7; 32]);
+        let mut signed = sign_shard(shard(), &key).unwrap();
+        signed.header.segment_label = "elsewhere".to_string();
+        let (result, kind) = verify(&signed);
+        assert!(matches!(result, Err(SignatureError::InvalidSignature)));
+        assert!(matches!(kind, AuditKind::SignatureVerificationFailed { .. }));
+
+        let (result, kind) = verify(&shard());
+        assert!(matches!(result, Err(SignatureError::Unsigned)));
+        assert!(matches!(kind, AuditKind::SignatureVerificationFailed { signer_did, .. } if signer_did.is_empty()));
+    }
 }
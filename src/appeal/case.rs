@@ -0,0 +1,193 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::aln::RoutingDenial;
+use crate::audit::event::AuditSubject;
+use crate::governance_hooks::{AppealDecision, HitlTicket};
+use crate::nanopolygon::AppealPathType;
+use crate::web5_integration::vc::OperatorRole;
+
+/// The decision being appealed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AppealTarget {
+    /// Outcome of a human review.
+    HitlTicket(HitlTicket),
+    /// An ALN routing denial; `reason` is the denial's reason code.
+    RoutingDenial { shard_id: Uuid, reason: String },
+}
+
+impl AppealTarget {
+    pub fn routing_denial(shard_id: Uuid, denial: &RoutingDenial) -> Self {
+        AppealTarget::RoutingDenial {
+            shard_id,
+            reason: denial.code().to_string(),
+        }
+    }
+}
+
+/// How long each body has to decide before the appeal is escalated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AppealDeadlines {
+    pub community_council_secs: u64,
+    pub eco_infra_council_secs: u64,
+    pub ethics_panel_secs: u64,
+}
+
+impl Default for AppealDeadlines {
+    fn default() -> Self {
+        Self {
+            community_council_secs: 7 * 86400,
+            eco_infra_council_secs: 14 * 86400,
+            ethics_panel_secs: 30 * 86400,
+        }
+    }
+}
+
+impl AppealDeadlines {
+    pub fn for_path(&self, path: AppealPathType) -> Duration {
+        let secs = match path {
+            AppealPathType::CommunityCouncil => self.community_council_secs,
+            AppealPathType::EcoInfraCouncil => self.eco_infra_council_secs,
+            AppealPathType::EthicsPanel => self.ethics_panel_secs,
+        };
+        Duration::try_seconds(i64::try_from(secs).unwrap_or(i64::MAX)).unwrap_or(Duration::MAX)
+    }
+}
+
+/// Operator credential role a resolver must hold to decide for `path`.
+pub fn deciding_role(path: AppealPathType) -> OperatorRole {
+    match path {
+        AppealPathType::EcoInfraCouncil => OperatorRole::EcoCouncil,
+        AppealPathType::EthicsPanel => OperatorRole::EthicsPanel,
+        AppealPathType::CommunityCouncil => OperatorRole::CommunityCouncil,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Escalation {
+    pub from: AppealPathType,
+    pub to: AppealPathType,
+    pub at: DateTime<Utc>,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AppealResolution {
+    pub resolver_did: String,
+    pub decision: AppealDecision,
+    pub reason: String,
+    pub resolved_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "state", rename_all = "snake_case")]
+pub enum AppealState {
+    /// Waiting for `path` to decide by `deadline`.
+    Open,
+    Resolved(AppealResolution),
+}
+
+/// One appeal and its history. Cases only change through `AppealDesk`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppealCase {
+    pub appeal_id: Uuid,
+    pub target: AppealTarget,
+    pub polygon_id: Uuid,
+    pub appellant_did: String,
+    pub grounds: String,
+    pub filed_at: DateTime<Utc>,
+    /// Body currently responsible; starts at the polygon's
+    /// `NeurorightsProfile.appeal_path`.
+    pub path: AppealPathType,
+    pub deadline: DateTime<Utc>,
+    #[serde(default)]
+    pub escalations: Vec<Escalation>,
+    #[serde(flatten)]
+    pub state: AppealState,
+}
+
+impl AppealCase {
+    pub fn is_open(&self) -> bool {
+        self.state == AppealState::Open
+    }
+
+    pub fn is_overdue(&self, now: DateTime<Utc>) -> bool {
+        self.is_open() && now >= self.deadline
+    }
+
+    pub fn resolution(&self) -> Option<&AppealResolution> {
+        match &self.state {
+            AppealState::Resolved(resolution) => Some(resolution),
+            AppealState::Open => None,
+        }
+    }
+
+    pub fn audit_subject(&self) -> AuditSubject {
+        match &self.target {
+            AppealTarget::RoutingDenial { shard_id, .. } => {
+                AuditSubject::shard(*shard_id, self.polygon_id)
+            }
+            AppealTarget::HitlTicket(_) => AuditSubject::polygon(self.polygon_id),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use serde_json::json;
+
+    use super::*;
+
+    fn case(state: AppealState) -> AppealCase {
+        let filed_at = Utc.with_ymd_and_hms(2026, 6, 1, 0, 0, 0).unwrap();
+        AppealCase {
+            appeal_id: Uuid::from_u128(1),
+            target: AppealTarget::RoutingDenial {
+                shard_id: Uuid::from_u128(2),
+                reason: "default_deny".to_string(),
+            },
+            polygon_id: Uuid::from_u128(3),
+            appellant_did: "did:web:resident.example".to_string(),
+            grounds: "route was needed".to_string(),
+            filed_at,
+            path: AppealPathType::EcoInfraCouncil,
+            deadline: filed_at + Duration::days(14),
+            escalations: Vec::new(),
+            state,
+        }
+    }
+
+    fn round_trip(case: &AppealCase) -> serde_json::Value {
+        let value = serde_json::to_value(case).unwrap();
+        let back: AppealCase = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(back.state, case.state);
+        assert_eq!(serde_json::to_value(&back).unwrap(), value);
+        value
+    }
+
+    #[test]
+    fn open_state_flattens_to_a_tag() {
+        let value = round_trip(&case(AppealState::Open));
+        assert_eq!(value["state"], "open");
+        assert!(value.get("resolver_did").is_none());
+    }
+
+    #[test]
+    fn resolved_state_flattens_its_fields_next_to_the_tag() {
+        let resolution = AppealResolution {
+            resolver_did: "did:web:council-member.example".to_string(),
+            decision: AppealDecision::Modified,
+            reason: "partial restore".to_string(),
+            resolved_at: Utc.with_ymd_and_hms(2026, 6, 3, 0, 0, 0).unwrap(),
+        };
+        let value = round_trip(&case(AppealState::Resolved(resolution)));
+        assert_eq!(value["state"], "resolved");
+        assert_eq!(value["resolver_did"], "did:web:council-member.example");
+        assert_eq!(value["decision"], json!("Modified"));
+        assert_eq!(value["target"]["type"], "routing_denial");
+    }
+}
@@ -0,0 +1,529 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Duration, Utc};
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::audit::{
+    context::AuditContext,
+    event::{AuditEvent, AuditKind},
+    sink::AuditSink,
+};
+use crate::governance_hooks::{AppealDecision, GovernanceHooks};
+use crate::nanopolygon::{AppealPathType, NanopolygonSafetyObject};
+use crate::web5_integration::vc::{OperatorCredential, OperatorRole};
+use super::case::{
+    deciding_role,
+    AppealCase,
+    AppealDeadlines,
+    AppealResolution,
+    AppealState,
+    AppealTarget,
+    Escalation,
+};
+
+#[derive(Debug, Error)]
+pub enum AppealError {
+    #[error("appeals are not available for polygon {0}")]
+    AppealsUnavailable(Uuid),
+    #[error("`{0}` is not a DID")]
+    InvalidDid(String),
+    #[error("appeal grounds must not be empty")]
+    MissingGrounds,
+    #[error("resolution reason must not be empty")]
+    MissingReason,
+    #[error("an open appeal already exists for this decision: {0}")]
+    AlreadyOpen(Uuid),
+    #[error("no appeal {0}")]
+    UnknownAppeal(Uuid),
+    #[error("appeal {0} is already resolved")]
+    AlreadyResolved(Uuid),
+    #[error("{0:?} is the final appeal body")]
+    FinalBody(AppealPathType),
+    #[error("resolver lacks the {required} role for {path:?}")]
+    NotAuthorized {
+        path: AppealPathType,
+        required: OperatorRole,
+    },
+    #[error("resolver credential for {0} is not valid at the time of the ruling")]
+    CredentialNotValid(String),
+    #[error("{0} filed this appeal and cannot resolve it")]
+    ResolverIsAppellant(String),
+    #[error("could not record the appeal outcome: {0}")]
+    HookFailed(String),
+}
+
+impl AppealError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            AppealError::AppealsUnavailable(_) => "appeals_unavailable",
+            AppealError::InvalidDid(_) => "invalid_did",
+            AppealError::MissingGrounds => "missing_grounds",
+            AppealError::MissingReason => "missing_reason",
+            AppealError::AlreadyOpen(_) => "appeal_already_open",
+            AppealError::UnknownAppeal(_) => "unknown_appeal",
+            AppealError::AlreadyResolved(_) => "appeal_already_resolved",
+            AppealError::FinalBody(_) => "final_appeal_body",
+            AppealError::NotAuthorized { .. } => "appeal_resolver_not_authorized",
+            AppealError::CredentialNotValid(_) => "resolver_credential_not_valid",
+            AppealError::ResolverIsAppellant(_) => "resolver_is_appellant",
+            AppealError::HookFailed(_) => "appeal_hook_failed",
+        }
+    }
+}
+
+/// A request to reopen a decision, as submitted to `AppealDesk::file`.
+#[derive(Debug, Clone)]
+pub struct AppealFiling {
+    pub target: AppealTarget,
+    pub appellant_did: String,
+    pub grounds: String,
+}
+
+/// A human decision on an appeal, as submitted to `AppealDesk::resolve`.
+#[derive(Debug, Clone)]
+pub struct AppealRuling {
+    /// Already verified (see `verify_operator_vc`).
+    pub resolver: OperatorCredential,
+    pub decision: AppealDecision,
+    pub reason: String,
+}
+
+/// Files, escalates and resolves appeals, recording an audit event at each
+/// transition: `Open` → (`AppealEscalated`)* → `Resolved`.
+#[derive(Debug, Default)]
+pub struct AppealDesk {
+    deadlines: AppealDeadlines,
+    cases: HashMap<Uuid, AppealCase>,
+}
+
+impl AppealDesk {
+    pub fn new(deadlines: AppealDeadlines) -> Self {
+        Self {
+            deadlines,
+            cases: HashMap::new(),
+        }
+    }
+
+    pub fn deadlines(&self) -> &AppealDeadlines {
+        &self.deadlines
+    }
+
+    pub fn get(&self, appeal_id: Uuid) -> Option<&AppealCase> {
+        self.cases.get(&appeal_id)
+    }
+
+    pub fn cases(&self) -> impl Iterator<Item = &AppealCase> {
+        self.cases.values()
+    }
+
+    /// Open appeals past their deadline, including those already at the
+    /// final body.
+    pub fn overdue(&self, now: DateTime<Utc>) -> impl Iterator<Item = &AppealCase> {
+        self.cases.values().filter(move |c| c.is_overdue(now))
+    }
+
+    /// Opens an appeal filed at `now`, routed to `np`'s appeal path. Emits
+    /// `AppealFiled`.
+    pub fn file<P: AuditSink>(
+        &mut self,
+        filing: AppealFiling,
+        np: &NanopolygonSafetyObject,
+        now: DateTime<Utc>,
+        ctx: &AuditContext,
+        audit_sink: &mut P,
+    ) -> Result<Uuid, AppealError> {
+        let AppealFiling {
+            target,
+            appellant_did,
+            grounds,
+        } = filing;
+        if !np.metadata.ethics_profile.appeal_paths_available {
+            return Err(AppealError::AppealsUnavailable(np.polygon_id));
+        }
+        if !appellant_did.starts_with("did:") {
+            return Err(AppealError::InvalidDid(appellant_did));
+        }
+        if grounds.trim().is_empty() {
+            return Err(AppealError::MissingGrounds);
+        }
+        if let Some(open) = self.cases.values().find(|c| c.is_open() && c.target == target) {
+            return Err(AppealError::AlreadyOpen(open.appeal_id));
+        }
+
+        let path = np.rights.neurorights.appeal_path;
+        let case = AppealCase {
+            appeal_id: Uuid::new_v4(),
+            target,
+            polygon_id: np.polygon_id,
+            appellant_did,
+            grounds,
+            filed_at: now,
+            path,
+            deadline: after(now, self.deadlines.for_path(path)),
+            escalations: Vec::new(),
+            state: AppealState::Open,
+        };
+
+        audit_sink.record(
+            AuditEvent::new(ctx, AuditKind::AppealFiled {
+                appeal_id: case.appeal_id,
+                appellant_did: case.appellant_did.clone(),
+                path,
+            })
+            .with_subject(case.audit_subject()),
+        );
+        let appeal_id = case.appeal_id;
+        self.cases.insert(appeal_id, case);
+        Ok(appeal_id)
+    }
+
+    /// Moves an open appeal to the next body at `now`, with a fresh
+    /// deadline. Emits `AppealEscalated`.
+    pub fn escalate<P: AuditSink>(
+        &mut self,
+        appeal_id: Uuid,
+        reason: &str,
+        now: DateTime<Utc>,
+        ctx: &AuditContext,
+        audit_sink: &mut P,
+    ) -> Result<AppealPathType, AppealError> {
+        let deadlines = self.deadlines;
+        let case = self.open_case(appeal_id)?;
+        let to = case.path.escalates_to().ok_or(AppealError::FinalBody(case.path))?;
+
+        audit_sink.record(
+            AuditEvent::new(ctx, AuditKind::AppealEscalated {
+                appeal_id,
+                from: case.path,
+                to,
+                reason: reason.to_string(),
+            })
+            .with_subject(case.audit_subject()),
+        );
+        case.escalations.push(Escalation {
+            from: case.path,
+            to,
+            at: now,
+            reason: reason.to_string(),
+        });
+        case.path = to;
+        case.deadline = after(now, deadlines.for_path(to));
+        Ok(to)
+    }
+
+    /// Escalates every overdue appeal that has a higher body; returns the
+    /// escalated ids. Overdue appeals at the final body stay where they are
+    /// and remain visible through `overdue`.
+    pub fn escalate_overdue<P: AuditSink>(
+        &mut self,
+        now: DateTime<Utc>,
+        ctx: &AuditContext,
+        audit_sink: &mut P,
+    ) -> Vec<Uuid> {
+        let due: Vec<Uuid> = self
+            .overdue(now)
+            .filter(|c| c.path.escalates_to().is_some())
+            .map(|c| c.appeal_id)
+            .collect();
+        due.into_iter()
+            .filter(|id| self.escalate(*id, "deadline_missed", now, ctx, audit_sink).is_ok())
+            .collect()
+    }
+
+    /// Records the responsible body's ruling at `now`. The resolver's
+    /// credential must be valid at `now` and hold the role for the current
+    /// path (see `deciding_role`), and the resolver may not be the
+    /// appellant. HITL-ticket appeals are also forwarded to
+    /// `hooks.record_appeal`; if that fails the appeal stays open. Emits
+    /// `AppealResolved`.
+    pub fn resolve<H: GovernanceHooks, P: AuditSink>(
+        &mut self,
+        appeal_id: Uuid,
+        ruling: AppealRuling,
+        hooks: &H,
+        now: DateTime<Utc>,
+        ctx: &AuditContext,
+        audit_sink: &mut P,
+    ) -> Result<&AppealCase, AppealError> {
+        if ruling.reason.trim().is_empty() {
+            return Err(AppealError::MissingReason);
+        }
+        let case = self.open_case(appeal_id)?;
+        let resolver = &ruling.resolver;
+        if !resolver.is_valid_at(now) {
+            return Err(AppealError::CredentialNotValid(resolver.did.clone()));
+        }
+        let required = deciding_role(case.path);
+        if !resolver.has_role(&required) {
+            return Err(AppealError::NotAuthorized {
+                path: case.path,
+                required,
+            });
+        }
+        if resolver.did == case.appellant_did {
+            return Err(AppealError::ResolverIsAppellant(resolver.did.clone()));
+        }
+
+        let resolution = AppealResolution {
+            resolver_did: ruling.resolver.did,
+            decision: ruling.decision,
+            reason: ruling.reason,
+            resolved_at: now,
+        };
+        if let AppealTarget::HitlTicket(ticket) = &case.target {
+            hooks
+                .record_appeal(
+                    ticket,
+                    &resolution.resolver_did,
+                    resolution.decision,
+                    &resolution.reason,
+                )
+                .map_err(|e| AppealError::HookFailed(e.to_string()))?;
+        }
+        audit_sink.record(
+            AuditEvent::new(ctx, AuditKind::AppealResolved {
+                appeal_id,
+                resolver_did: resolution.resolver_did.clone(),
+                decision: resolution.decision,
+                reason: resolution.reason.clone(),
+            })
+            .with_subject(case.audit_subject()),
+        );
+        case.state = AppealState::Resolved(resolution);
+        Ok(case)
+    }
+
+    fn open_case(&mut self, appeal_id: Uuid) -> Result<&mut AppealCase, AppealError> {
+        let case = self
+            .cases
+            .get_mut(&appeal_id)
+            .ok_or(AppealError::UnknownAppeal(appeal_id))?;
+        if !case.is_open() {
+            return Err(AppealError::AlreadyResolved(appeal_id));
+        }
+        Ok(case)
+    }
+}
+
+fn after(t: DateTime<Utc>, d: Duration) -> DateTime<Utc> {
+    t.checked_add_signed(d).unwrap_or(DateTime::<Utc>::MAX_UTC)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aln::AlnShard;
+    use crate::audit::sink::InMemoryAuditSink;
+    use crate::audit::trace::DecisionTrace;
+    use crate::governance_hooks::HitlTicket;
+    use crate::hitl::{HitlQueue, InMemoryHitlQueue};
+    use crate::nanopolygon::fixtures::{nanopolygon, shard};
+
+    const APPELLANT: &str = "did:web:resident.example";
+    const COUNCIL: &str = "did:web:council-member.example";
+
+    /// Hooks whose backing store is unreachable.
+    struct Offline;
+
+    impl GovernanceHooks for Offline {
+        type Error = std::io::Error;
+
+        fn enqueue_hitl(&self, _: AlnShard, _: f64, _: DecisionTrace) -> Result<HitlTicket, Self::Error> {
+            Err(std::io::Error::other("queue offline"))
+        }
+
+        fn record_appeal(&self, _: &HitlTicket, _: &str, _: AppealDecision, _: &str) -> Result<(), Self::Error> {
+            Err(std::io::Error::other("queue offline"))
+        }
+    }
+
+    fn filing(target: AppealTarget) -> AppealFiling {
+        AppealFiling {
+            target,
+            appellant_did: APPELLANT.to_string(),
+            grounds: "route was needed".to_string(),
+        }
+    }
+
+    fn routing_denial() -> AppealTarget {
+        AppealTarget::RoutingDenial {
+            shard_id: Uuid::from_u128(0x5a),
+            reason: "default_deny".to_string(),
+        }
+    }
+
+    fn file(desk: &mut AppealDesk, sink: &mut InMemoryAuditSink) -> Uuid {
+        let ctx = AuditContext::new(Uuid::nil());
+        desk.file(filing(routing_denial()), &nanopolygon(), Utc::now(), &ctx, sink)
+            .unwrap()
+    }
+
+    fn ruling(did: &str, role: OperatorRole, valid_until: Option<DateTime<Utc>>) -> AppealRuling {
+        AppealRuling {
+            resolver: OperatorCredential {
+                did: did.to_string(),
+                issuer: "did:web:issuer.example".to_string(),
+                roles: vec![role],
+                valid_from: None,
+                valid_until,
+            },
+            decision: AppealDecision::Approved,
+            reason: "route restored".to_string(),
+        }
+    }
+
+    #[test]
+    fn missed_deadlines_escalate_until_the_final_body() {
+        let mut desk = AppealDesk::default();
+        let mut sink = InMemoryAuditSink::new();
+        let ctx = AuditContext::new(Uuid::nil());
+        let id = file(&mut desk, &mut sink);
+        let filed = desk.get(id).unwrap().clone();
+        assert_eq!(filed.path, AppealPathType::EcoInfraCouncil);
+
+        assert!(desk.escalate_overdue(filed.deadline - Duration::seconds(1), &ctx, &mut sink).is_empty());
+
+        let first = filed.deadline;
+        assert_eq!(desk.escalate_overdue(first, &ctx, &mut sink), vec![id]);
+        let case = desk.get(id).unwrap();
+        assert_eq!(case.path, AppealPathType::EthicsPanel);
+        assert_eq!(case.deadline, first + desk.deadlines().for_path(AppealPathType::EthicsPanel));
+        assert_eq!(case.escalations[0].reason, "deadline_missed");
+        assert!(matches!(
+            sink.events.last().unwrap().kind,
+            AuditKind::AppealEscalated { from: AppealPathType::EcoInfraCouncil, to: AppealPathType::EthicsPanel, .. }
+        ));
+
+        let second = case.deadline;
+        assert!(desk.escalate_overdue(second, &ctx, &mut sink).is_empty());
+        assert_eq!(desk.overdue(second).map(|c| c.appeal_id).collect::<Vec<_>>(), vec![id]);
+        assert!(matches!(
+            desk.escalate(id, "manual", second, &ctx, &mut sink),
+            Err(AppealError::FinalBody(AppealPathType::EthicsPanel))
+        ));
+    }
+
+    #[test]
+    fn resolution_checks_the_resolver_at_the_ruling_time() {
+        let mut desk = AppealDesk::default();
+        let mut sink = InMemoryAuditSink::new();
+        let ctx = AuditContext::new(Uuid::nil());
+        let hooks = InMemoryHitlQueue::default();
+        let id = file(&mut desk, &mut sink);
+        let now = Utc::now() + Duration::days(1);
+        let council = COUNCIL;
+
+        let expired = ruling(council, OperatorRole::EcoCouncil, Some(now));
+        let err = desk.resolve(id, expired, &hooks, now, &ctx, &mut sink).unwrap_err();
+        assert_eq!(err.code(), "resolver_credential_not_valid");
+
+        let wrong_role = ruling(council, OperatorRole::EthicsPanel, None);
+        let err = desk.resolve(id, wrong_role, &hooks, now, &ctx, &mut sink).unwrap_err();
+        assert_eq!(err.code(), "appeal_resolver_not_authorized");
+
+        let own_appeal = ruling(APPELLANT, OperatorRole::EcoCouncil, None);
+        let err = desk.resolve(id, own_appeal, &hooks, now, &ctx, &mut sink).unwrap_err();
+        assert!(matches!(err, AppealError::ResolverIsAppellant(ref did) if did == APPELLANT));
+
+        let valid = ruling(council, OperatorRole::EcoCouncil, Some(now + Duration::days(1)));
+        let case = desk.resolve(id, valid, &hooks, now, &ctx, &mut sink).unwrap();
+        let resolution = case.resolution().unwrap();
+        assert_eq!(resolution.resolver_did, council);
+        assert_eq!(resolution.resolved_at, now);
+        assert!(matches!(sink.events.last().unwrap().kind, AuditKind::AppealResolved { .. }));
+    }
+
+    #[test]
+    fn filing_uses_the_callers_clock_and_refuses_duplicates() {
+        let mut desk = AppealDesk::default();
+        let mut sink = InMemoryAuditSink::new();
+        let ctx = AuditContext::new(Uuid::nil());
+        let filed_at = Utc::now() - Duration::days(3);
+
+        let id = desk
+            .file(filing(routing_denial()), &nanopolygon(), filed_at, &ctx, &mut sink)
+            .unwrap();
+        let case = desk.get(id).unwrap();
+        assert_eq!(case.filed_at, filed_at);
+        assert_eq!(case.deadline, filed_at + desk.deadlines().for_path(case.path));
+
+        let err = desk
+            .file(filing(routing_denial()), &nanopolygon(), Utc::now(), &ctx, &mut sink)
+            .unwrap_err();
+        assert!(matches!(err, AppealError::AlreadyOpen(open) if open == id));
+        assert_eq!(err.code(), "appeal_already_open");
+        assert_eq!(desk.cases().count(), 1);
+        assert_eq!(sink.events.len(), 1);
+    }
+
+    #[test]
+    fn polygons_without_appeal_paths_refuse_filings() {
+        let mut desk = AppealDesk::default();
+        let mut sink = InMemoryAuditSink::new();
+        let ctx = AuditContext::new(Uuid::nil());
+        let mut np = nanopolygon();
+        np.metadata.ethics_profile.appeal_paths_available = false;
+
+        let err = desk
+            .file(filing(routing_denial()), &np, Utc::now(), &ctx, &mut sink)
+            .unwrap_err();
+        assert!(matches!(err, AppealError::AppealsUnavailable(id) if id == np.polygon_id));
+        assert_eq!(err.code(), "appeals_unavailable");
+        assert_eq!(desk.cases().count(), 0);
+        assert!(sink.events.is_empty());
+    }
+
+    #[test]
+    fn ticket_appeals_are_forwarded_to_the_hooks() {
+        let queue = InMemoryHitlQueue::default();
+        let ticket = queue
+            .enqueue(shard(), 0.5, DecisionTrace::new("test"), Utc::now())
+            .unwrap();
+        let mut desk = AppealDesk::default();
+        let mut sink = InMemoryAuditSink::new();
+        let ctx = AuditContext::new(Uuid::nil());
+        let now = Utc::now();
+        let id = desk
+            .file(filing(AppealTarget::HitlTicket(ticket.clone())), &nanopolygon(), now, &ctx, &mut sink)
+            .unwrap();
+
+        let valid = ruling(COUNCIL, OperatorRole::EcoCouncil, None);
+        desk.resolve(id, valid, &queue, now, &ctx, &mut sink).unwrap();
+
+        let item = queue.get(&ticket.ticket_id).unwrap().unwrap();
+        let [appeal] = item.appeals.as_slice() else {
+            panic!("expected one appeal, got {:?}", item.appeals);
+        };
+        assert_eq!(appeal.human_did, COUNCIL);
+        assert_eq!(appeal.decision, AppealDecision::Approved);
+        assert_eq!(appeal.reason, "route restored");
+    }
+
+    #[test]
+    fn ticket_appeals_stay_open_when_the_hook_fails() {
+        let ticket = HitlTicket {
+            ticket_id: "ticket-1".to_string(),
+            shard_did: "did:web:shard.example".to_string(),
+        };
+        let mut desk = AppealDesk::default();
+        let mut sink = InMemoryAuditSink::new();
+        let ctx = AuditContext::new(Uuid::nil());
+        let now = Utc::now();
+        let id = desk
+            .file(filing(AppealTarget::HitlTicket(ticket)), &nanopolygon(), now, &ctx, &mut sink)
+            .unwrap();
+
+        let valid = ruling(COUNCIL, OperatorRole::EcoCouncil, None);
+        let err = desk.resolve(id, valid, &Offline, now, &ctx, &mut sink).unwrap_err();
+        assert!(matches!(err, AppealError::HookFailed(ref reason) if reason == "queue offline"));
+        assert_eq!(err.code(), "appeal_hook_failed");
+        assert!(desk.get(id).unwrap().is_open());
+        assert!(!sink.events.iter().any(|e| matches!(e.kind, AuditKind::AppealResolved { .. })));
+
+        // Routing-denial appeals never touch the hooks.
+        let other = file(&mut desk, &mut sink);
+        let valid = ruling(COUNCIL, OperatorRole::EcoCouncil, None);
+        desk.resolve(other, valid, &Offline, now, &ctx, &mut sink).unwrap();
+    }
+}
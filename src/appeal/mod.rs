@@ -0,0 +1,13 @@
+pub mod case;
+pub mod desk;
+
+pub use case::{
+    deciding_role,
+    AppealCase,
+    AppealDeadlines,
+    AppealResolution,
+    AppealState,
+    AppealTarget,
+    Escalation,
+};
+pub use desk::{AppealDesk, AppealError, AppealFiling, AppealRuling};
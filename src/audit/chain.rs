@@ -0,0 +1,229 @@
+use thiserror::Error;
+
+use super::event::AuditEvent;
+use crate::canonical::{canonical_sha256, CanonicalError};
+use super::sink::AuditSink;
+
+/// Link value carried by the first event of a chain.
+pub const GENESIS_HASH: &str =
+    "0000000000000000000000000000000000000000000000000000000000000000";
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ChainError {
+    #[error("event {index} carries no chain link")]
+    MissingLink { index: usize },
+    #[error("event {index} links to {found}, expected {expected}")]
+    BrokenLink {
+        index: usize,
+        expected: String,
+        found: String,
+    },
+    #[error("chain head {found} does not match expected head {expected}")]
+    HeadMismatch { expected: String, found: String },
+    #[error("event {index} has no canonical encoding: {reason}")]
+    Unencodable { index: usize, reason: String },
+}
+
+/// SHA‑256 over the canonical encoding of the event, including its
+/// `prev_hash` link.
+pub fn event_hash(event: &AuditEvent) -> Result<String, CanonicalError> {
+    canonical_sha256(event)
+}
+
+/// Wraps any sink so that each recorded event carries the hash of its
+/// predecessor (AU‑9, AU‑10).
+pub struct HashChainedAuditSink<S: AuditSink> {
+    inner: S,
+    head: String,
+    recorded: usize,
+    last_error: Option<ChainError>,
+}
+
+impl<S: AuditSink> HashChainedAuditSink<S> {
+    pub fn new(inner: S) -> Self {
+        Self::resume(inner, GENESIS_HASH.to_string())
+    }
+
+    /// Continue an existing chain, e.g. after a process restart.
+    pub fn resume(inner: S, head: String) -> Self {
+        Self {
+            inner,
+            head,
+            recorded: 0,
+            last_error: None,
+        }
+    }
+
+    /// Hash of the last recorded event; store it out of band to detect
+    /// truncation.
+    pub fn head(&self) -> &str {
+        &self.head
+    }
+
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+
+    /// First event since the last call that could not be hashed. Such an
+    /// event is still passed to the inner sink, without advancing the head,
+    /// so `verify_chain` reports it instead of it going missing.
+    pub fn take_error(&mut self) -> Option<ChainError> {
+        self.last_error.take()
+    }
+}
+
+impl<S: AuditSink> AuditSink for HashChainedAuditSink<S> {
+    fn record(&mut self, mut event: AuditEvent) {
+        event.prev_hash = Some(self.head.clone());
+        match event_hash(&event) {
+            Ok(hash) => self.head = hash,
+            Err(e) => {
+                self.last_error.get_or_insert(ChainError::Unencodable {
+                    index: self.recorded,
+                    reason: e.to_string(),
+                });
+            }
+        }
+        self.recorded += 1;
+        self.inner.record(event);
+    }
+}
+
+/// Verify that `events` form an unbroken chain from `start` (the
+/// `prev_hash` of the first event: `GENESIS_HASH` for a new chain, the
+/// previous segment's head for a rotated or resumed one) to
+/// `expected_head`. Detects deletion, reordering and mutation of any entry,
+/// including truncation of the tail.
+pub fn verify_chain(events: &[AuditEvent], start: &str, expected_head: &str) -> Result<(), ChainError> {
+    let mut head = start.to_string();
+
+    for (index, event) in events.iter().enumerate() {
+        let link = event
+            .prev_hash
+            .as_ref()
+            .ok_or(ChainError::MissingLink { index })?;
+        if *link != head {
+            return Err(ChainError::BrokenLink {
+                index,
+                expected: head,
+                found: link.clone(),
+            });
+        }
+        head = event_hash(event).map_err(|e| ChainError::Unencodable {
+            index,
+            reason: e.to_string(),
+        })?;
+    }
+
+    if head != expected_head {
+        return Err(ChainError::HeadMismatch {
+            expected: expected_head.to_string(),
+            found: head,
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::audit::{context::AuditContext, sink::InMemoryAuditSink};
+
+    fn chain(n: usize) -> (Vec<AuditEvent>, String) {
+        let ctx = AuditContext::new(Uuid::nil());
+        let mut sink = HashChainedAuditSink::new(InMemoryAuditSink::new());
+        for i in 0..n {
+            sink.record(AuditEvent::routing_allowed(&ctx, &format!("segment-{i}")));
+        }
+        let head = sink.head().to_string();
+        (sink.into_inner().events, head)
+    }
+
+    #[test]
+    fn intact_chain_verifies() {
+        let (events, head) = chain(3);
+        assert_eq!(verify_chain(&events, GENESIS_HASH, &head), Ok(()));
+        assert_eq!(verify_chain(&[], GENESIS_HASH, GENESIS_HASH), Ok(()));
+    }
+
+    #[test]
+    fn modified_field_is_detected() {
+        let (mut events, head) = chain(3);
+        events[1].actor_did = Some("did:ex:mallory".to_string());
+        assert!(matches!(
+            verify_chain(&events, GENESIS_HASH, &head),
+            Err(ChainError::BrokenLink { index: 2, .. })
+        ));
+
+        // Tampering with the last event only shows up against the head.
+        let (mut events, head) = chain(3);
+        events[2].timestamp_utc += chrono::Duration::seconds(1);
+        assert!(matches!(
+            verify_chain(&events, GENESIS_HASH, &head),
+            Err(ChainError::HeadMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn reordered_event_is_detected() {
+        let (mut events, head) = chain(3);
+        events.swap(0, 1);
+        assert!(matches!(
+            verify_chain(&events, GENESIS_HASH, &head),
+            Err(ChainError::BrokenLink { index: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn deleted_event_is_detected() {
+        let (mut events, head) = chain(3);
+        events.remove(1);
+        assert!(matches!(
+            verify_chain(&events, GENESIS_HASH, &head),
+            Err(ChainError::BrokenLink { index: 1, .. })
+        ));
+
+        let (mut events, head) = chain(3);
+        events.pop();
+        assert!(matches!(
+            verify_chain(&events, GENESIS_HASH, &head),
+            Err(ChainError::HeadMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn wrong_head_or_missing_link_is_detected() {
+        let (mut events, _) = chain(2);
+        assert!(matches!(
+            verify_chain(&events, GENESIS_HASH, GENESIS_HASH),
+            Err(ChainError::HeadMismatch { .. })
+        ));
+        events[0].prev_hash = None;
+        assert_eq!(
+            verify_chain(&events, GENESIS_HASH, GENESIS_HASH),
+            Err(ChainError::MissingLink { index: 0 })
+        );
+    }
+
+    #[test]
+    fn resumed_segment_verifies_from_previous_head() {
+        let (first, first_head) = chain(2);
+        let ctx = AuditContext::new(Uuid::nil());
+        let mut sink = HashChainedAuditSink::resume(InMemoryAuditSink::new(), first_head.clone());
+        sink.record(AuditEvent::routing_denied(&ctx, "default_deny"));
+        let head = sink.head().to_string();
+        let second = sink.into_inner().events;
+
+        assert_eq!(verify_chain(&second, &first_head, &head), Ok(()));
+        assert!(verify_chain(&second, GENESIS_HASH, &head).is_err());
+        let all: Vec<_> = first.into_iter().chain(second).collect();
+        assert_eq!(verify_chain(&all, GENESIS_HASH, &head), Ok(()));
+    }
+}
@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Caller identity and correlation data stamped on every `AuditEvent`
+/// produced while serving one request (AU‑3).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AuditContext {
+    /// Caller's authenticated session.
+    pub session_id: Uuid,
+    /// Web5 DID of the human or service acting in this session.
+    pub actor_did: Option<String>,
+    /// Agent performing the work on the actor's behalf.
+    pub agent_id: Option<Uuid>,
+    /// Trace id shared by all events of one request.
+    pub correlation_id: Uuid,
+}
+
+impl AuditContext {
+    pub fn new(session_id: Uuid) -> Self {
+        Self {
+            session_id,
+            actor_did: None,
+            agent_id: None,
+            correlation_id: Uuid::new_v4(),
+        }
+    }
+
+    pub fn with_actor_did(mut self, did: &str) -> Self {
+        self.actor_did = Some(did.to_string());
+        self
+    }
+
+    pub fn with_agent_id(mut self, agent_id: Uuid) -> Self {
+        self.agent_id = Some(agent_id);
+        self
+    }
+
+    pub fn with_correlation_id(mut self, correlation_id: Uuid) -> Self {
+        self.correlation_id = correlation_id;
+        self
+    }
+
+    /// Same session and actor, fresh correlation id for the next request.
+    pub fn next_request(&self) -> Self {
+        self.clone().with_correlation_id(Uuid::new_v4())
+    }
+}
@@ -2,51 +2,436 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use super::{context::AuditContext, trace::DecisionTrace};
+use crate::aln::SanctuaryShardKind;
+use crate::governance_hooks::AppealDecision;
+use crate::hitl::HumanReview;
+use crate::nanopolygon::{AppealPathType, Staleness};
+use crate::policy::nist_80053::Sp80053Control;
+
+/// What an audit record is about (AU‑3 "where / which object").
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AuditSubject {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub polygon_id: Option<Uuid>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shard_id: Option<Uuid>,
+}
+
+impl AuditSubject {
+    pub fn polygon(polygon_id: Uuid) -> Self {
+        Self {
+            polygon_id: Some(polygon_id),
+            shard_id: None,
+        }
+    }
+
+    pub fn shard(shard_id: Uuid, polygon_id: Uuid) -> Self {
+        Self {
+            polygon_id: Some(polygon_id),
+            shard_id: Some(shard_id),
+        }
+    }
+}
+
+/// AU‑3 outcome of the recorded event.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum AuditOutcome {
+    Success,
+    Failure,
+    /// Decision deferred to a human or another body.
+    Pending,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum AuditKind {
     ValidationSuccess,
     ValidationFailed { reason: String },
     RoutingAllowed { label: String },
     RoutingDenied { reason: String },
+    GovernanceAllowed,
+    GovernanceHitlRequired { reason: String },
+    GovernanceRejected { reason: String },
+    HitlEnqueued { ticket_id: String, reason: String },
+    HumanApproved {
+        reviewer_did: String,
+        decision: AppealDecision,
+        rationale: String,
+        dwn_hexstamp: String,
+    },
+    HumanRejected {
+        reviewer_did: String,
+        rationale: String,
+        dwn_hexstamp: String,
+    },
+    AppealFiled {
+        appeal_id: Uuid,
+        appellant_did: String,
+        path: AppealPathType,
+    },
+    AppealEscalated {
+        appeal_id: Uuid,
+        from: AppealPathType,
+        to: AppealPathType,
+        reason: String,
+    },
+    AppealResolved {
+        appeal_id: Uuid,
+        resolver_did: String,
+        decision: AppealDecision,
+        reason: String,
+    },
+    SignatureVerified { signer_did: String, algorithm: String },
+    SignatureVerificationFailed { signer_did: String, reason: String },
+    ConsentGranted { grantor_did: String, scope: String },
+    ConsentRevoked { grantor_did: String, scope: String },
+    PolicyVersionChanged {
+        policy_id: String,
+        from_version: Option<String>,
+        to_version: String,
+        changed_by_did: String,
+    },
+    SanctuaryEnforced {
+        kind: SanctuaryShardKind,
+        action: String,
+    },
+    RightsRevised {
+        version: u64,
+        previous_version: Option<u64>,
+        changed_by_did: String,
+        effective_from: DateTime<Utc>,
+    },
+    RightsRevisionRejected {
+        version: u64,
+        changed_by_did: String,
+        reason: String,
+    },
+    StaleTelemetry {
+        observed_at: DateTime<Utc>,
+        age_seconds: i64,
+        max_age_seconds: i64,
+    },
+    FutureTelemetry {
+        observed_at: DateTime<Utc>,
+        ahead_seconds: i64,
+        clock_skew_seconds: i64,
+    },
+}
+
+impl AuditKind {
+    pub fn outcome(&self) -> AuditOutcome {
+        match self {
+            AuditKind::ValidationFailed { .. }
+            | AuditKind::RoutingDenied { .. }
+            | AuditKind::GovernanceRejected { .. }
+            | AuditKind::HumanRejected { .. }
+            | AuditKind::SignatureVerificationFailed { .. }
+            | AuditKind::RightsRevisionRejected { .. }
+            | AuditKind::StaleTelemetry { .. }
+            | AuditKind::FutureTelemetry { .. } => AuditOutcome::Failure,
+            AuditKind::GovernanceHitlRequired { .. }
+            | AuditKind::HitlEnqueued { .. }
+            | AuditKind::AppealFiled { .. }
+            | AuditKind::AppealEscalated { .. } => AuditOutcome::Pending,
+            AuditKind::ValidationSuccess
+            | AuditKind::RoutingAllowed { .. }
+            | AuditKind::GovernanceAllowed
+            | AuditKind::HumanApproved { .. }
+            | AuditKind::AppealResolved { .. }
+            | AuditKind::SignatureVerified { .. }
+            | AuditKind::ConsentGranted { .. }
+            | AuditKind::ConsentRevoked { .. }
+            | AuditKind::PolicyVersionChanged { .. }
+            | AuditKind::SanctuaryEnforced { .. }
+            | AuditKind::RightsRevised { .. } => AuditOutcome::Success,
+        }
+    }
+
+    /// SP 800‑53 controls this event provides evidence for, beyond the
+    /// AU‑2 / AU‑3 / AU‑12 baseline every record satisfies.
+    pub fn controls(&self) -> &'static [Sp80053Control] {
+        use Sp80053Control::*;
+        match self {
+            AuditKind::ValidationSuccess | AuditKind::ValidationFailed { .. } => &[Si10],
+            AuditKind::RoutingAllowed { .. } | AuditKind::RoutingDenied { .. } => &[Ac3, Ac4, Sc7],
+            AuditKind::GovernanceAllowed
+            | AuditKind::GovernanceHitlRequired { .. }
+            | AuditKind::GovernanceRejected { .. } => &[Ac3, Ra3],
+            AuditKind::HitlEnqueued { .. } => &[Ac3],
+            AuditKind::HumanApproved { .. } | AuditKind::HumanRejected { .. } => &[Ac3, Ia2, Au10],
+            AuditKind::AppealFiled { .. } | AuditKind::AppealEscalated { .. } => &[Au10],
+            AuditKind::AppealResolved { .. } => &[Ia2, Au10],
+            AuditKind::SignatureVerified { .. }
+            | AuditKind::SignatureVerificationFailed { .. } => &[Si7, Au10],
+            AuditKind::ConsentGranted { .. } | AuditKind::ConsentRevoked { .. } => &[Pt4, Au10],
+            AuditKind::PolicyVersionChanged { .. } => &[Cm3],
+            AuditKind::RightsRevised { .. } => &[Cm3, Au10],
+            AuditKind::RightsRevisionRejected { .. } => &[Ac3, Cm3, Au10],
+            AuditKind::SanctuaryEnforced { .. } => &[Ac4, Sc7],
+            AuditKind::StaleTelemetry { .. } | AuditKind::FutureTelemetry { .. } => &[Si10],
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "StoredAuditEvent")]
 pub struct AuditEvent {
     pub event_id: Uuid,
     pub session_id: Uuid,
+    pub correlation_id: Uuid,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_did: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agent_id: Option<Uuid>,
     pub timestamp_utc: DateTime<Utc>,
+    #[serde(default)]
+    pub subject: AuditSubject,
     pub kind: AuditKind,
+    pub outcome: AuditOutcome,
+    pub controls: Vec<Sp80053Control>,
+    /// Hash of the preceding event when recorded through a chained sink.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prev_hash: Option<String>,
+    /// Explanation of the decision this event records, when one was traced.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trace: Option<DecisionTrace>,
+}
+
+/// On-disk shape of `AuditEvent`. Records written before `outcome` and
+/// `controls` existed get them derived from `kind` on replay.
+#[derive(Deserialize)]
+struct StoredAuditEvent {
+    event_id: Uuid,
+    session_id: Uuid,
+    correlation_id: Uuid,
+    #[serde(default)]
+    actor_did: Option<String>,
+    #[serde(default)]
+    agent_id: Option<Uuid>,
+    timestamp_utc: DateTime<Utc>,
+    #[serde(default)]
+    subject: AuditSubject,
+    kind: AuditKind,
+    #[serde(default)]
+    outcome: Option<AuditOutcome>,
+    #[serde(default)]
+    controls: Option<Vec<Sp80053Control>>,
+    #[serde(default)]
+    prev_hash: Option<String>,
+    #[serde(default)]
+    trace: Option<DecisionTrace>,
+}
+
+impl From<StoredAuditEvent> for AuditEvent {
+    fn from(stored: StoredAuditEvent) -> Self {
+        AuditEvent {
+            event_id: stored.event_id,
+            session_id: stored.session_id,
+            correlation_id: stored.correlation_id,
+            actor_did: stored.actor_did,
+            agent_id: stored.agent_id,
+            timestamp_utc: stored.timestamp_utc,
+            subject: stored.subject,
+            outcome: stored.outcome.unwrap_or_else(|| stored.kind.outcome()),
+            controls: stored
+                .controls
+                .unwrap_or_else(|| stored.kind.controls().to_vec()),
+            kind: stored.kind,
+            prev_hash: stored.prev_hash,
+            trace: stored.trace,
+        }
+    }
 }
 
 impl AuditEvent {
-    pub fn validation_success() -> Self {
-        Self::simple(AuditKind::ValidationSuccess)
+    pub fn validation_success(ctx: &AuditContext) -> Self {
+        Self::new(ctx, AuditKind::ValidationSuccess)
     }
 
-    pub fn validation_failed(reason: &str) -> Self {
-        Self::simple(AuditKind::ValidationFailed {
+    pub fn validation_failed(ctx: &AuditContext, reason: &str) -> Self {
+        Self::new(ctx, AuditKind::ValidationFailed {
             reason: reason.to_string(),
         })
     }
 
-    pub fn routing_allowed(label: &str) -> Self {
-        Self::simple(AuditKind::RoutingAllowed {
+    pub fn routing_allowed(ctx: &AuditContext, label: &str) -> Self {
+        Self::new(ctx, AuditKind::RoutingAllowed {
             label: label.to_string(),
         })
     }
 
-    pub fn routing_denied(reason: &str) -> Self {
-        Self::simple(AuditKind::RoutingDenied {
+    pub fn routing_denied(ctx: &AuditContext, reason: &str) -> Self {
+        Self::new(ctx, AuditKind::RoutingDenied {
+            reason: reason.to_string(),
+        })
+    }
+
+    pub fn governance_allowed(ctx: &AuditContext) -> Self {
+        Self::new(ctx, AuditKind::GovernanceAllowed)
+    }
+
+    pub fn governance_hitl_required(ctx: &AuditContext, reason: &str) -> Self {
+        Self::new(ctx, AuditKind::GovernanceHitlRequired {
+            reason: reason.to_string(),
+        })
+    }
+
+    pub fn governance_rejected(ctx: &AuditContext, reason: &str) -> Self {
+        Self::new(ctx, AuditKind::GovernanceRejected {
             reason: reason.to_string(),
         })
     }
 
-    fn simple(kind: AuditKind) -> Self {
+    pub fn hitl_enqueued(ctx: &AuditContext, ticket_id: &str, reason: &str) -> Self {
+        Self::new(ctx, AuditKind::HitlEnqueued {
+            ticket_id: ticket_id.to_string(),
+            reason: reason.to_string(),
+        })
+    }
+
+    /// `HumanApproved` or `HumanRejected` depending on the review decision.
+    pub fn human_review(ctx: &AuditContext, review: &HumanReview) -> Self {
+        let kind = match review.decision {
+            AppealDecision::Rejected => AuditKind::HumanRejected {
+                reviewer_did: review.reviewer_did.clone(),
+                rationale: review.rationale.clone(),
+                dwn_hexstamp: review.dwn_hexstamp.clone(),
+            },
+            decision => AuditKind::HumanApproved {
+                reviewer_did: review.reviewer_did.clone(),
+                decision,
+                rationale: review.rationale.clone(),
+                dwn_hexstamp: review.dwn_hexstamp.clone(),
+            },
+        };
+        Self::new(ctx, kind)
+    }
+
+    pub fn signature_verified(ctx: &AuditContext, signer_did: &str, algorithm: &str) -> Self {
+        Self::new(ctx, AuditKind::SignatureVerified {
+            signer_did: signer_did.to_string(),
+            algorithm: algorithm.to_string(),
+        })
+    }
+
+    pub fn signature_verification_failed(ctx: &AuditContext, signer_did: &str, reason: &str) -> Self {
+        Self::new(ctx, AuditKind::SignatureVerificationFailed {
+            signer_did: signer_did.to_string(),
+            reason: reason.to_string(),
+        })
+    }
+
+    pub fn sanctuary_enforced(ctx: &AuditContext, kind: SanctuaryShardKind, action: &str) -> Self {
+        Self::new(ctx, AuditKind::SanctuaryEnforced {
+            kind,
+            action: action.to_string(),
+        })
+    }
+
+    /// `StaleTelemetry` or `FutureTelemetry` for a reading taken at
+    /// `observed_at`.
+    pub fn telemetry_not_fresh(ctx: &AuditContext, observed_at: DateTime<Utc>, staleness: &Staleness) -> Self {
+        let kind = match *staleness {
+            Staleness::Stale { age, max_age } => AuditKind::StaleTelemetry {
+                observed_at,
+                age_seconds: age.num_seconds(),
+                max_age_seconds: max_age.num_seconds(),
+            },
+            Staleness::FromFuture { ahead, clock_skew } => AuditKind::FutureTelemetry {
+                observed_at,
+                ahead_seconds: ahead.num_seconds(),
+                clock_skew_seconds: clock_skew.num_seconds(),
+            },
+        };
+        Self::new(ctx, kind)
+    }
+
+    pub fn new(ctx: &AuditContext, kind: AuditKind) -> Self {
         AuditEvent {
             event_id: Uuid::new_v4(),
-            session_id: Uuid::new_v4(), // in production, bind to caller’s session token. [file:1][file:5]
+            session_id: ctx.session_id,
+            correlation_id: ctx.correlation_id,
+            actor_did: ctx.actor_did.clone(),
+            agent_id: ctx.agent_id,
             timestamp_utc: Utc::now(),
+            subject: AuditSubject::default(),
+            outcome: kind.outcome(),
+            controls: kind.controls().to_vec(),
             kind,
+            prev_hash: None,
+            trace: None,
+        }
+    }
+
+    pub fn with_subject(mut self, subject: AuditSubject) -> Self {
+        self.subject = subject;
+        self
+    }
+
+    pub fn with_trace(mut self, trace: DecisionTrace) -> Self {
+        self.trace = Some(trace);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn records_without_outcome_or_controls_still_replay() {
+        let line = r#"{
+            "event_id": "00000000-0000-0000-0000-000000000001",
+            "session_id": "00000000-0000-0000-0000-000000000002",
+            "correlation_id": "00000000-0000-0000-0000-000000000003",
+            "timestamp_utc": "2026-01-02T03:04:05Z",
+            "kind": {"RoutingDenied": {"reason": "default_deny"}},
+            "prev_hash": "00"
+        }"#;
+        let event: AuditEvent = serde_json::from_str(line).unwrap();
+        assert_eq!(event.outcome, AuditOutcome::Failure);
+        assert_eq!(event.controls, AuditKind::RoutingDenied { reason: String::new() }.controls());
+        assert_eq!(event.subject, AuditSubject::default());
+        assert_eq!(event.prev_hash.as_deref(), Some("00"));
+    }
+
+    #[test]
+    fn stored_outcome_and_controls_are_kept() {
+        let ctx = AuditContext::new(Uuid::nil());
+        let mut event = AuditEvent::governance_allowed(&ctx);
+        event.outcome = AuditOutcome::Pending;
+        event.controls.clear();
+        let json = serde_json::to_string(&event).unwrap();
+        let back: AuditEvent = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.outcome, AuditOutcome::Pending);
+        assert!(back.controls.is_empty());
+        assert_eq!(serde_json::to_string(&back).unwrap(), json);
+    }
+
+    #[test]
+    fn every_kind_has_an_outcome_and_controls() {
+        let review = |decision| HumanReview {
+            reviewer_did: "did:ex:r".to_string(),
+            reviewed_at: Utc::now(),
+            decision,
+            rationale: "r".to_string(),
+            dwn_hexstamp: "ab".to_string(),
+        };
+        let ctx = AuditContext::new(Uuid::nil());
+        let cases = [
+            (AuditEvent::hitl_enqueued(&ctx, "t", "r"), AuditOutcome::Pending),
+            (AuditEvent::human_review(&ctx, &review(AppealDecision::Modified)), AuditOutcome::Success),
+            (AuditEvent::human_review(&ctx, &review(AppealDecision::Rejected)), AuditOutcome::Failure),
+            (AuditEvent::signature_verified(&ctx, "did:ex:s", "ML-DSA-65"), AuditOutcome::Success),
+            (AuditEvent::signature_verification_failed(&ctx, "did:ex:s", "x"), AuditOutcome::Failure),
+            (
+                AuditEvent::sanctuary_enforced(&ctx, SanctuaryShardKind::None, "a"),
+                AuditOutcome::Success,
+            ),
+        ];
+        for (event, outcome) in cases {
+            assert_eq!(event.outcome, outcome, "{:?}", event.kind);
+            assert!(!event.controls.is_empty(), "{:?}", event.kind);
         }
     }
 }
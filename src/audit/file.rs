@@ -0,0 +1,474 @@
+use std::collections::VecDeque;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use super::chain::{event_hash, ChainError, GENESIS_HASH};
+use super::event::AuditEvent;
+use super::sink::AuditSink;
+
+#[derive(Debug, Error)]
+pub enum AuditFileError {
+    #[error("audit log I/O failed: {0}")]
+    Io(#[from] io::Error),
+    #[error("audit event could not be encoded: {0}")]
+    Encode(serde_json::Error),
+    #[error("malformed audit record at {}:{line}: {source}", path.display())]
+    Malformed {
+        path: PathBuf,
+        line: usize,
+        source: serde_json::Error,
+    },
+    #[error("audit log chain is broken: {0}")]
+    Chain(#[from] ChainError),
+}
+
+/// When appended records are forced to stable storage.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum SyncPolicy {
+    /// fsync after every event (AU‑9 strict).
+    EveryEvent,
+    /// fsync after every `n` events.
+    EveryN(u32),
+    /// fsync only when a segment is rotated or the sink is dropped.
+    OnRotate,
+}
+
+/// When the active segment is closed and a new one started.
+#[derive(Debug, Clone, Default)]
+pub struct RotationPolicy {
+    pub max_bytes: Option<u64>,
+    pub max_age: Option<Duration>,
+}
+
+/// Append‑only JSONL audit sink. Events are written one per line to
+/// `<dir>/<prefix>-<segment>.jsonl`; every `open` starts a fresh segment so
+/// a torn write from a crash is never appended to. Wrap it in a
+/// `HashChainedAuditSink` resumed from `recover_chain_head` to keep one
+/// chain across restarts.
+pub struct FileAuditSink {
+    dir: PathBuf,
+    prefix: String,
+    sync: SyncPolicy,
+    rotation: RotationPolicy,
+    file: File,
+    segment: u64,
+    bytes_written: u64,
+    opened_at: DateTime<Utc>,
+    unsynced: u32,
+    last_error: Option<AuditFileError>,
+}
+
+impl FileAuditSink {
+    pub fn open(
+        dir: impl AsRef<Path>,
+        prefix: &str,
+        sync: SyncPolicy,
+        rotation: RotationPolicy,
+    ) -> Result<Self, AuditFileError> {
+        let dir = dir.as_ref().to_path_buf();
+        fs::create_dir_all(&dir)?;
+
+        let segment = list_segments(&dir, prefix)?
+            .back()
+            .map(|(n, _)| n + 1)
+            .unwrap_or(1);
+        let file = open_segment(&dir, prefix, segment)?;
+
+        Ok(Self {
+            dir,
+            prefix: prefix.to_string(),
+            sync,
+            rotation,
+            file,
+            segment,
+            bytes_written: 0,
+            opened_at: Utc::now(),
+            unsynced: 0,
+            last_error: None,
+        })
+    }
+
+    /// Fallible counterpart of `AuditSink::record`.
+    pub fn append(&mut self, event: &AuditEvent) -> Result<(), AuditFileError> {
+        if self.should_rotate() {
+            self.rotate()?;
+        }
+
+        let mut line = serde_json::to_vec(event).map_err(AuditFileError::Encode)?;
+        line.push(b'\n');
+        self.file.write_all(&line)?;
+        self.bytes_written += line.len() as u64;
+        self.unsynced += 1;
+
+        let due = match self.sync {
+            SyncPolicy::EveryEvent => true,
+            SyncPolicy::EveryN(n) => self.unsynced >= n.max(1),
+            SyncPolicy::OnRotate => false,
+        };
+        if due {
+            self.sync()?;
+        }
+        Ok(())
+    }
+
+    pub fn sync(&mut self) -> Result<(), AuditFileError> {
+        self.file.sync_data()?;
+        self.unsynced = 0;
+        Ok(())
+    }
+
+    pub fn active_segment(&self) -> PathBuf {
+        segment_path(&self.dir, &self.prefix, self.segment)
+    }
+
+    /// First write failure swallowed by `record` since the last call.
+    /// Callers should treat `Some` as an AU‑5 audit processing failure.
+    pub fn take_error(&mut self) -> Option<AuditFileError> {
+        self.last_error.take()
+    }
+
+    fn should_rotate(&self) -> bool {
+        if self.bytes_written == 0 {
+            return false;
+        }
+        let too_big = self
+            .rotation
+            .max_bytes
+            .is_some_and(|max| self.bytes_written >= max);
+        let too_old = self
+            .rotation
+            .max_age
+            .is_some_and(|max| Utc::now() - self.opened_at >= max);
+        too_big || too_old
+    }
+
+    fn rotate(&mut self) -> Result<(), AuditFileError> {
+        self.sync()?;
+        self.segment += 1;
+        self.file = open_segment(&self.dir, &self.prefix, self.segment)?;
+        self.bytes_written = 0;
+        self.opened_at = Utc::now();
+        Ok(())
+    }
+}
+
+impl AuditSink for FileAuditSink {
+    fn record(&mut self, event: AuditEvent) {
+        if let Err(e) = self.append(&event) {
+            self.last_error.get_or_insert(e);
+        }
+    }
+}
+
+impl Drop for FileAuditSink {
+    fn drop(&mut self) {
+        let _ = self.file.sync_data();
+    }
+}
+
+/// Iterate every event ever written under `dir`/`prefix`, oldest segment
+/// first. Feed the result into `chain::verify_chain` to check integrity.
+///
+/// A segment's last line that lacks its newline and does not parse is the
+/// torn tail of a crashed write: it is skipped and reported through
+/// `AuditReplay::torn_segments`. Malformed complete lines are errors.
+pub fn replay(dir: impl AsRef<Path>, prefix: &str) -> Result<AuditReplay, AuditFileError> {
+    let segments = list_segments(dir.as_ref(), prefix)?
+        .into_iter()
+        .map(|(_, path)| path)
+        .collect();
+    Ok(AuditReplay {
+        segments,
+        current: None,
+        torn: Vec::new(),
+    })
+}
+
+/// Replays a chained log, checking every link from `GENESIS_HASH`, and
+/// returns the hash of the last event: the head to pass to
+/// `HashChainedAuditSink::resume` after a restart. A missing `dir` is an
+/// empty log.
+pub fn recover_chain_head(dir: impl AsRef<Path>, prefix: &str) -> Result<String, AuditFileError> {
+    let mut head = GENESIS_HASH.to_string();
+    if !dir.as_ref().exists() {
+        return Ok(head);
+    }
+    for (index, event) in replay(dir, prefix)?.enumerate() {
+        let event = event?;
+        match &event.prev_hash {
+            None => return Err(ChainError::MissingLink { index }.into()),
+            Some(link) if *link != head => {
+                return Err(ChainError::BrokenLink {
+                    index,
+                    expected: head,
+                    found: link.clone(),
+                }
+                .into())
+            }
+            Some(_) => {}
+        }
+        head = event_hash(&event).map_err(|e| ChainError::Unencodable {
+            index,
+            reason: e.to_string(),
+        })?;
+    }
+    Ok(head)
+}
+
+pub struct AuditReplay {
+    segments: VecDeque<PathBuf>,
+    current: Option<(PathBuf, BufReader<File>, usize)>,
+    torn: Vec<PathBuf>,
+}
+
+impl AuditReplay {
+    /// Segments whose torn final line was skipped so far.
+    pub fn torn_segments(&self) -> &[PathBuf] {
+        &self.torn
+    }
+}
+
+impl Iterator for AuditReplay {
+    type Item = Result<AuditEvent, AuditFileError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut line = Vec::new();
+        loop {
+            if self.current.is_none() {
+                let path = self.segments.pop_front()?;
+                match File::open(&path) {
+                    Ok(file) => self.current = Some((path, BufReader::new(file), 0)),
+                    Err(e) => return Some(Err(e.into())),
+                }
+            }
+
+            let (path, reader, line_no) = self.current.as_mut()?;
+            line.clear();
+            match reader.read_until(b'\n', &mut line) {
+                Ok(0) => self.current = None,
+                Err(e) => return Some(Err(e.into())),
+                Ok(_) => {
+                    *line_no += 1;
+                    let complete = line.ends_with(b"\n");
+                    if line.iter().all(u8::is_ascii_whitespace) {
+                        continue;
+                    }
+                    match serde_json::from_slice(&line) {
+                        Ok(event) => return Some(Ok(event)),
+                        Err(_) if !complete => {
+                            self.torn.push(path.clone());
+                            self.current = None;
+                        }
+                        Err(source) => {
+                            return Some(Err(AuditFileError::Malformed {
+                                path: path.clone(),
+                                line: *line_no,
+                                source,
+                            }))
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn segment_path(dir: &Path, prefix: &str, segment: u64) -> PathBuf {
+    dir.join(format!("{prefix}-{segment:08}.jsonl"))
+}
+
+fn open_segment(dir: &Path, prefix: &str, segment: u64) -> io::Result<File> {
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(segment_path(dir, prefix, segment))
+}
+
+fn list_segments(dir: &Path, prefix: &str) -> io::Result<VecDeque<(u64, PathBuf)>> {
+    let mut segments = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let number = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .and_then(|n| n.strip_prefix(prefix))
+            .and_then(|n| n.strip_prefix('-'))
+            .and_then(|n| n.strip_suffix(".jsonl"))
+            .and_then(|n| n.parse::<u64>().ok());
+        if let Some(number) = number {
+            segments.push((number, path));
+        }
+    }
+    segments.sort_by_key(|(n, _)| *n);
+    Ok(segments.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::audit::chain::{verify_chain, HashChainedAuditSink};
+    use crate::audit::context::AuditContext;
+
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new() -> Self {
+            Self(std::env::temp_dir().join(format!("ecoinfra-audit-{}", Uuid::new_v4())))
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn event(label: &str) -> AuditEvent {
+        AuditEvent::routing_allowed(&AuditContext::new(Uuid::nil()), label)
+    }
+
+    fn labels(dir: &Path) -> Vec<String> {
+        replay(dir, "audit")
+            .unwrap()
+            .map(|e| match e.unwrap().kind {
+                crate::audit::event::AuditKind::RoutingAllowed { label } => label,
+                other => panic!("unexpected {other:?}"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn rotates_by_size_and_replays_in_order() {
+        let dir = TempDir::new();
+        let rotation = RotationPolicy {
+            max_bytes: Some(1),
+            max_age: None,
+        };
+        let mut sink = FileAuditSink::open(&dir.0, "audit", SyncPolicy::EveryEvent, rotation).unwrap();
+        for label in ["a", "b", "c"] {
+            sink.record(event(label));
+        }
+        assert!(sink.take_error().is_none());
+        assert_eq!(sink.active_segment(), segment_path(&dir.0, "audit", 3));
+        drop(sink);
+
+        assert_eq!(list_segments(&dir.0, "audit").unwrap().len(), 3);
+        assert_eq!(labels(&dir.0), ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn rotates_by_age() {
+        let dir = TempDir::new();
+        let rotation = RotationPolicy {
+            max_bytes: None,
+            max_age: Some(Duration::zero()),
+        };
+        let mut sink = FileAuditSink::open(&dir.0, "audit", SyncPolicy::OnRotate, rotation).unwrap();
+        sink.append(&event("a")).unwrap();
+        sink.append(&event("b")).unwrap();
+        assert_eq!(sink.segment, 2);
+    }
+
+    #[test]
+    fn sync_policy_controls_fsync_cadence() {
+        let dir = TempDir::new();
+        let open = |sync| FileAuditSink::open(&dir.0, "audit", sync, RotationPolicy::default()).unwrap();
+
+        let mut every = open(SyncPolicy::EveryEvent);
+        every.append(&event("a")).unwrap();
+        assert_eq!(every.unsynced, 0);
+
+        let mut every_two = open(SyncPolicy::EveryN(2));
+        every_two.append(&event("a")).unwrap();
+        assert_eq!(every_two.unsynced, 1);
+        every_two.append(&event("b")).unwrap();
+        assert_eq!(every_two.unsynced, 0);
+
+        let mut on_rotate = open(SyncPolicy::OnRotate);
+        on_rotate.append(&event("a")).unwrap();
+        on_rotate.append(&event("b")).unwrap();
+        assert_eq!(on_rotate.unsynced, 2);
+        on_rotate.rotate().unwrap();
+        assert_eq!(on_rotate.unsynced, 0);
+    }
+
+    #[test]
+    fn reopen_starts_a_new_segment() {
+        let dir = TempDir::new();
+        for label in ["a", "b"] {
+            let mut sink =
+                FileAuditSink::open(&dir.0, "audit", SyncPolicy::EveryEvent, RotationPolicy::default()).unwrap();
+            sink.record(event(label));
+        }
+        assert_eq!(list_segments(&dir.0, "audit").unwrap().len(), 2);
+        assert_eq!(labels(&dir.0), ["a", "b"]);
+    }
+
+    #[test]
+    fn torn_final_line_is_skipped_but_malformed_lines_fail() {
+        let dir = TempDir::new();
+        let mut sink =
+            FileAuditSink::open(&dir.0, "audit", SyncPolicy::EveryEvent, RotationPolicy::default()).unwrap();
+        sink.record(event("a"));
+        let segment = sink.active_segment();
+        drop(sink);
+        let mut file = OpenOptions::new().append(true).open(&segment).unwrap();
+        file.write_all(br#"{"event_id":"#).unwrap();
+
+        let mut events = replay(&dir.0, "audit").unwrap();
+        assert!(events.next().unwrap().is_ok());
+        assert!(events.next().is_none());
+        assert_eq!(events.torn_segments(), std::slice::from_ref(&segment));
+
+        file.write_all(b"\n").unwrap();
+        let results: Vec<_> = replay(&dir.0, "audit").unwrap().collect();
+        assert!(matches!(
+            results.last(),
+            Some(Err(AuditFileError::Malformed { line: 2, .. }))
+        ));
+    }
+
+    #[test]
+    fn chain_head_is_recovered_across_restarts() {
+        let dir = TempDir::new();
+        let open = || FileAuditSink::open(&dir.0, "audit", SyncPolicy::EveryEvent, RotationPolicy::default()).unwrap();
+        assert_eq!(recover_chain_head(&dir.0, "audit").unwrap(), GENESIS_HASH);
+
+        let mut sink = HashChainedAuditSink::new(open());
+        sink.record(event("a"));
+        sink.record(event("b"));
+        let head = sink.head().to_string();
+        drop(sink);
+
+        let recovered = recover_chain_head(&dir.0, "audit").unwrap();
+        assert_eq!(recovered, head);
+        let mut sink = HashChainedAuditSink::resume(open(), recovered);
+        sink.record(event("c"));
+        let head = sink.head().to_string();
+        drop(sink);
+
+        let events: Vec<_> = replay(&dir.0, "audit").unwrap().map(Result::unwrap).collect();
+        assert_eq!(verify_chain(&events, GENESIS_HASH, &head), Ok(()));
+        assert_eq!(recover_chain_head(&dir.0, "audit").unwrap(), head);
+    }
+
+    #[test]
+    fn unchained_log_has_no_recoverable_head() {
+        let dir = TempDir::new();
+        let mut sink =
+            FileAuditSink::open(&dir.0, "audit", SyncPolicy::EveryEvent, RotationPolicy::default()).unwrap();
+        sink.record(event("a"));
+        drop(sink);
+        assert!(matches!(
+            recover_chain_head(&dir.0, "audit"),
+            Err(AuditFileError::Chain(ChainError::MissingLink { index: 0 }))
+        ));
+    }
+}
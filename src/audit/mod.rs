@@ -1,2 +1,6 @@
 pub mod event;
 pub mod sink;
+pub mod chain;
+pub mod file;
+pub mod context;
+pub mod trace;
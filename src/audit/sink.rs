@@ -4,7 +4,7 @@ pub trait AuditSink {
     fn record(&mut self, event: AuditEvent);
 }
 
-// Example in-memory sink; production hooking goes to append-only, chained storage. [file:1][file:5]
+// Example in-memory sink; wrap in `chain::HashChainedAuditSink` for tamper-evident storage. [file:1][file:5]
 pub struct InMemoryAuditSink {
     pub events: Vec<AuditEvent>,
 }
@@ -15,6 +15,12 @@ impl InMemoryAuditSink {
     }
 }
 
+impl Default for InMemoryAuditSink {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl AuditSink for InMemoryAuditSink {
     fn record(&mut self, event: AuditEvent) {
         self.events.push(event);
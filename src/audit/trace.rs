@@ -0,0 +1,68 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Explanation of a routing or governance decision: every rule that was
+/// looked at, the inputs it read and why it did or did not match. Attached
+/// to the decision's audit event and to HITL tickets so reviewers can see
+/// why a subject reached them.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DecisionTrace {
+    /// Which evaluator produced the trace, e.g. `aln_routing:aln-baseline@1`.
+    pub evaluator: String,
+    pub steps: Vec<TraceStep>,
+    /// Reason code of the final decision.
+    #[serde(default)]
+    pub outcome: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TraceStep {
+    pub rule: String,
+    #[serde(default)]
+    pub inputs: BTreeMap<String, Value>,
+    pub matched: bool,
+    pub reason: String,
+}
+
+impl DecisionTrace {
+    pub fn new(evaluator: impl Into<String>) -> Self {
+        Self {
+            evaluator: evaluator.into(),
+            steps: Vec::new(),
+            outcome: String::new(),
+        }
+    }
+
+    pub fn push(&mut self, step: TraceStep) {
+        self.steps.push(step);
+    }
+
+    pub fn finish(mut self, outcome: &str) -> Self {
+        self.outcome = outcome.to_string();
+        self
+    }
+
+    /// The step that decided the outcome, if any rule matched.
+    pub fn deciding_step(&self) -> Option<&TraceStep> {
+        self.steps.iter().rev().find(|s| s.matched)
+    }
+}
+
+impl TraceStep {
+    pub fn new(rule: impl Into<String>, matched: bool, reason: impl Into<String>) -> Self {
+        Self {
+            rule: rule.into(),
+            inputs: BTreeMap::new(),
+            matched,
+            reason: reason.into(),
+        }
+    }
+
+    pub fn with_input(mut self, name: &str, value: impl Serialize) -> Self {
+        let value = serde_json::to_value(value).unwrap_or(Value::Null);
+        self.inputs.insert(name.to_string(), value);
+        self
+    }
+}
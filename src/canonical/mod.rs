@@ -0,0 +1,393 @@
+//! Canonical byte encoding used for every signature and hash in the crate.
+//!
+//! The profile is JSON Canonicalization Scheme (RFC 8785):
+//!
+//! - values are first serialized with their `serde` representation;
+//! - object members are sorted by the UTF‑16 code units of their keys;
+//! - no insignificant whitespace is emitted;
+//! - strings use the minimal JSON escapes (`\b \f \n \r \t \" \\`, other
+//!   control characters as lowercase `\u00xx`), everything else literal UTF‑8;
+//! - floating point numbers use the ECMAScript `Number.prototype.toString`
+//!   form (shortest round‑trip digits, `1e+21`, `1e-7`, `-0` → `0`);
+//! - integers are written in the same form, so only those within the
+//!   IEEE‑754 safe range (±(2^53 − 1)) are accepted.
+//!
+//! JSON cannot represent NaN or infinities, and an integer beyond 2^53 has
+//! no exact ES6 form that other JCS implementations would reproduce. Both
+//! are rejected with `CanonicalError` instead of being encoded.
+
+use std::fmt::Display;
+
+use serde::ser::{self, Serialize};
+use serde_json::{Number, Value};
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+/// Largest integer magnitude an IEEE‑754 double holds exactly.
+pub const MAX_SAFE_INTEGER: u64 = (1 << 53) - 1;
+
+#[derive(Debug, Error)]
+pub enum CanonicalError {
+    #[error("value does not serialize: {0}")]
+    Serialize(#[from] serde_json::Error),
+    #[error("non-finite number {0} has no JSON representation")]
+    NonFinite(f64),
+    #[error("integer {0} is outside the IEEE-754 safe range")]
+    UnsafeInteger(String),
+}
+
+impl ser::Error for CanonicalError {
+    fn custom<T: Display>(msg: T) -> Self {
+        CanonicalError::Serialize(serde_json::Error::custom(msg))
+    }
+}
+
+/// RFC 8785 canonical bytes of `value`.
+pub fn to_canonical_bytes<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>, CanonicalError> {
+    // `to_value` would turn NaN into `null`, so numbers are checked on the
+    // serde data model first.
+    value.serialize(NumberCheck)?;
+    let value = serde_json::to_value(value)?;
+    let mut out = String::new();
+    write_value(&value, &mut out);
+    Ok(out.into_bytes())
+}
+
+/// Hex SHA‑256 over the canonical bytes of `value`.
+pub fn canonical_sha256<T: Serialize + ?Sized>(value: &T) -> Result<String, CanonicalError> {
+    Ok(hex::encode(Sha256::digest(to_canonical_bytes(value)?)))
+}
+
+fn write_value(value: &Value, out: &mut String) {
+    match value {
+        Value::Null => out.push_str("null"),
+        Value::Bool(b) => out.push_str(if *b { "true" } else { "false" }),
+        Value::Number(n) => write_number(n, out),
+        Value::String(s) => write_string(s, out),
+        Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_value(item, out);
+            }
+            out.push(']');
+        }
+        Value::Object(map) => {
+            let mut entries: Vec<_> = map.iter().collect();
+            entries.sort_by(|(a, _), (b, _)| a.encode_utf16().cmp(b.encode_utf16()));
+            out.push('{');
+            for (i, (key, item)) in entries.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_string(key, out);
+                out.push(':');
+                write_value(item, out);
+            }
+            out.push('}');
+        }
+    }
+}
+
+fn write_string(s: &str, out: &mut String) {
+    // serde_json's escaping already matches RFC 8785 §3.2.2.2.
+    out.push_str(&serde_json::to_string(s).expect("strings always serialize"));
+}
+
+fn write_number(n: &Number, out: &mut String) {
+    // Safe integers print identically as `i64`/`u64` and as ES6 numbers.
+    if let Some(i) = n.as_i64() {
+        out.push_str(&i.to_string());
+    } else if let Some(u) = n.as_u64() {
+        out.push_str(&u.to_string());
+    } else if let Some(f) = n.as_f64() {
+        out.push_str(&format_es_number(f));
+    }
+}
+
+fn check_integer<T: Display>(v: T, magnitude: u128) -> Result<(), CanonicalError> {
+    if magnitude > u128::from(MAX_SAFE_INTEGER) {
+        return Err(CanonicalError::UnsafeInteger(v.to_string()));
+    }
+    Ok(())
+}
+
+fn check_float(v: f64) -> Result<(), CanonicalError> {
+    if !v.is_finite() {
+        return Err(CanonicalError::NonFinite(v));
+    }
+    Ok(())
+}
+
+/// Walks a value through the serde data model and rejects numbers that
+/// have no canonical JSON form. Produces no output.
+struct NumberCheck;
+
+impl ser::Serializer for NumberCheck {
+    type Ok = ();
+    type Error = CanonicalError;
+    type SerializeSeq = Self;
+    type SerializeTuple = Self;
+    type SerializeTupleStruct = Self;
+    type SerializeTupleVariant = Self;
+    type SerializeMap = Self;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = Self;
+
+    fn serialize_bool(self, _: bool) -> Result<(), CanonicalError> {
+        Ok(())
+    }
+    fn serialize_i8(self, _: i8) -> Result<(), CanonicalError> {
+        Ok(())
+    }
+    fn serialize_i16(self, _: i16) -> Result<(), CanonicalError> {
+        Ok(())
+    }
+    fn serialize_i32(self, _: i32) -> Result<(), CanonicalError> {
+        Ok(())
+    }
+    fn serialize_i64(self, v: i64) -> Result<(), CanonicalError> {
+        check_integer(v, u128::from(v.unsigned_abs()))
+    }
+    fn serialize_i128(self, v: i128) -> Result<(), CanonicalError> {
+        check_integer(v, v.unsigned_abs())
+    }
+    fn serialize_u8(self, _: u8) -> Result<(), CanonicalError> {
+        Ok(())
+    }
+    fn serialize_u16(self, _: u16) -> Result<(), CanonicalError> {
+        Ok(())
+    }
+    fn serialize_u32(self, _: u32) -> Result<(), CanonicalError> {
+        Ok(())
+    }
+    fn serialize_u64(self, v: u64) -> Result<(), CanonicalError> {
+        check_integer(v, u128::from(v))
+    }
+    fn serialize_u128(self, v: u128) -> Result<(), CanonicalError> {
+        check_integer(v, v)
+    }
+    fn serialize_f32(self, v: f32) -> Result<(), CanonicalError> {
+        check_float(f64::from(v))
+    }
+    fn serialize_f64(self, v: f64) -> Result<(), CanonicalError> {
+        check_float(v)
+    }
+    fn serialize_char(self, _: char) -> Result<(), CanonicalError> {
+        Ok(())
+    }
+    fn serialize_str(self, _: &str) -> Result<(), CanonicalError> {
+        Ok(())
+    }
+    fn serialize_bytes(self, _: &[u8]) -> Result<(), CanonicalError> {
+        Ok(())
+    }
+    fn serialize_none(self) -> Result<(), CanonicalError> {
+        Ok(())
+    }
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<(), CanonicalError> {
+        value.serialize(self)
+    }
+    fn serialize_unit(self) -> Result<(), CanonicalError> {
+        Ok(())
+    }
+    fn serialize_unit_struct(self, _: &'static str) -> Result<(), CanonicalError> {
+        Ok(())
+    }
+    fn serialize_unit_variant(self, _: &'static str, _: u32, _: &'static str) -> Result<(), CanonicalError> {
+        Ok(())
+    }
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<(), CanonicalError> {
+        value.serialize(self)
+    }
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        value: &T,
+    ) -> Result<(), CanonicalError> {
+        value.serialize(self)
+    }
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self, CanonicalError> {
+        Ok(self)
+    }
+    fn serialize_tuple(self, _: usize) -> Result<Self, CanonicalError> {
+        Ok(self)
+    }
+    fn serialize_tuple_struct(self, _: &'static str, _: usize) -> Result<Self, CanonicalError> {
+        Ok(self)
+    }
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self, CanonicalError> {
+        Ok(self)
+    }
+    fn serialize_map(self, _: Option<usize>) -> Result<Self, CanonicalError> {
+        Ok(self)
+    }
+    fn serialize_struct(self, _: &'static str, _: usize) -> Result<Self, CanonicalError> {
+        Ok(self)
+    }
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self, CanonicalError> {
+        Ok(self)
+    }
+}
+
+impl ser::SerializeSeq for NumberCheck {
+    type Ok = ();
+    type Error = CanonicalError;
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), CanonicalError> {
+        value.serialize(NumberCheck)
+    }
+    fn end(self) -> Result<(), CanonicalError> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeTuple for NumberCheck {
+    type Ok = ();
+    type Error = CanonicalError;
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), CanonicalError> {
+        value.serialize(NumberCheck)
+    }
+    fn end(self) -> Result<(), CanonicalError> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeTupleStruct for NumberCheck {
+    type Ok = ();
+    type Error = CanonicalError;
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), CanonicalError> {
+        value.serialize(NumberCheck)
+    }
+    fn end(self) -> Result<(), CanonicalError> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeTupleVariant for NumberCheck {
+    type Ok = ();
+    type Error = CanonicalError;
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), CanonicalError> {
+        value.serialize(NumberCheck)
+    }
+    fn end(self) -> Result<(), CanonicalError> {
+        Ok(())
+    }
+}
+
+// Map keys end up as JSON strings, so only values are checked.
+impl ser::SerializeMap for NumberCheck {
+    type Ok = ();
+    type Error = CanonicalError;
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, _: &T) -> Result<(), CanonicalError> {
+        Ok(())
+    }
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), CanonicalError> {
+        value.serialize(NumberCheck)
+    }
+    fn end(self) -> Result<(), CanonicalError> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeStruct for NumberCheck {
+    type Ok = ();
+    type Error = CanonicalError;
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, _: &'static str, value: &T) -> Result<(), CanonicalError> {
+        value.serialize(NumberCheck)
+    }
+    fn end(self) -> Result<(), CanonicalError> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeStructVariant for NumberCheck {
+    type Ok = ();
+    type Error = CanonicalError;
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, _: &'static str, value: &T) -> Result<(), CanonicalError> {
+        value.serialize(NumberCheck)
+    }
+    fn end(self) -> Result<(), CanonicalError> {
+        Ok(())
+    }
+}
+
+/// ECMAScript `Number::toString(10)` for a finite double.
+pub fn format_es_number(v: f64) -> String {
+    if v == 0.0 {
+        return "0".to_string();
+    }
+
+    // `{:e}` yields the shortest round‑trip digits, e.g. "-1.2345e-7".
+    let sci = format!("{:e}", v);
+    let (mantissa, exp) = sci.split_once('e').expect("scientific notation");
+    let exp: i32 = exp.parse().expect("integer exponent");
+    let (sign, mantissa) = match mantissa.strip_prefix('-') {
+        Some(m) => ("-", m),
+        None => ("", mantissa),
+    };
+    let mut digits: String = mantissa.chars().filter(|c| *c != '.').collect();
+    if digits.len() >= 16 {
+        digits = round_half_even(v, exp, digits);
+    }
+    let k = digits.len() as i32;
+    let n = exp + 1;
+
+    let body = if k <= n && n <= 21 {
+        format!("{digits}{}", "0".repeat((n - k) as usize))
+    } else if 0 < n && n <= 21 {
+        let (int, frac) = digits.split_at(n as usize);
+        format!("{int}.{frac}")
+    } else if -6 < n && n <= 0 {
+        format!("0.{}{digits}", "0".repeat((-n) as usize))
+    } else {
+        let exp_sign = if n - 1 < 0 { '-' } else { '+' };
+        let (first, rest) = digits.split_at(1);
+        let rest = if rest.is_empty() {
+            String::new()
+        } else {
+            format!(".{rest}")
+        };
+        format!("{first}{rest}e{exp_sign}{}", (n - 1).abs())
+    };
+    format!("{sign}{body}")
+}
+
+// Rust's shortest-digit formatting rounds an exact halfway case up, while
+// ECMAScript picks the even neighbour. Halfway cases need at least 16
+// significant digits, so only those are re-checked against the exact value.
+fn round_half_even(v: f64, exp: i32, digits: String) -> String {
+    let exact = format!("{:.767e}", v.abs());
+    let (mantissa, exact_exp) = exact.split_once('e').expect("scientific notation");
+    if exact_exp.parse::<i32>() != Ok(exp) {
+        return digits;
+    }
+    let exact_digits: String = mantissa.chars().filter(|c| *c != '.').collect();
+    let (prefix, tail) = exact_digits.split_at(digits.len());
+    let halfway = tail.starts_with('5') && tail[1..].bytes().all(|b| b == b'0');
+    let last_is_odd = digits.as_bytes()[digits.len() - 1] % 2 == 1;
+    if halfway && prefix != digits && last_is_odd {
+        prefix.to_string()
+    } else {
+        digits
+    }
+}
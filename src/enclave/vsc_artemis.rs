@@ -1,23 +1,67 @@
 use crate::{
-    aln::{AlnShard, RoutingDecision, RoutingPolicy},
-    audit::{event::AuditEvent, sink::AuditSink},
+    aln::{AlnShard, RoutingDecision, RoutingDenial, RoutingPolicy},
+    audit::{
+        context::AuditContext,
+        event::{AuditEvent, AuditKind},
+        sink::AuditSink,
+    },
+    policy::governance_metadata::HasGovernanceMetadata,
 };
 
-pub struct VscArtemisEnclave;
+pub struct VscArtemisEnclave {
+    policy: RoutingPolicy,
+}
 
 impl VscArtemisEnclave {
+    pub fn new(policy: RoutingPolicy) -> Self {
+        VscArtemisEnclave { policy }
+    }
+
+    pub fn policy(&self) -> &RoutingPolicy {
+        &self.policy
+    }
+
+    /// Swaps in a new routing policy and records the change (CM-3).
+    pub fn replace_policy<P: AuditSink>(
+        &mut self,
+        policy: RoutingPolicy,
+        ctx: &AuditContext,
+        audit_sink: &mut P,
+    ) {
+        let from_version = (self.policy.policy_id() == policy.policy_id())
+            .then(|| self.policy.version().to_string());
+        audit_sink.record(AuditEvent::new(ctx, AuditKind::PolicyVersionChanged {
+            policy_id: policy.policy_id().to_string(),
+            from_version,
+            to_version: policy.version().to_string(),
+            changed_by_did: ctx.actor_did.clone().unwrap_or_default(),
+        }));
+        self.policy = policy;
+    }
+
     pub fn process_shard<P: AuditSink>(
+        &self,
         shard: AlnShard,
+        ctx: &AuditContext,
         audit_sink: &mut P,
-    ) -> Result<(), &'static str> {
+    ) -> Result<(), RoutingDenial> {
         // Enforce routing policy before any optimization logic runs. [file:2][file:3]
-        match RoutingPolicy::evaluate(&shard, audit_sink) {
-            RoutingDecision::Allowed => {
-                audit_sink.record(AuditEvent::routing_allowed("enclave_accept"));
+        match self.policy.evaluate(&shard, ctx, audit_sink) {
+            RoutingDecision::Allowed { .. } => {
+                audit_sink.record(
+                    AuditEvent::routing_allowed(ctx, "enclave_accept")
+                        .with_subject(shard.audit_subject()),
+                );
                 // Here you call your optimization / eco-routing solvers.
                 Ok(())
             }
-            RoutingDecision::Denied(reason) => Err(reason),
+            RoutingDecision::Denied(denial) => Err(denial),
         }
     }
 }
+
+impl Default for VscArtemisEnclave {
+    fn default() -> Self {
+        Self::new(RoutingPolicy::default())
+    }
+}
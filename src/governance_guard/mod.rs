@@ -0,0 +1,625 @@
+use std::collections::{BTreeMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::audit::{
+    context::AuditContext,
+    event::AuditEvent,
+    sink::AuditSink,
+    trace::{DecisionTrace, TraceStep},
+};
+use crate::hitl::PendingReview;
+use crate::nanopolygon::{NanopolygonSafetyObject, RiskOfHarm, RohWeights};
+use crate::policy::{
+    governance_metadata::HasGovernanceMetadata,
+    nist_80053::Sp80053Impact,
+};
+
+pub use crate::hitl::HitlReason;
+
+/// AC‑style role constraint: at least `min_signatures` distinct DIDs holding
+/// `role` must be among the callers.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RoleConstraint {
+    pub role: String,
+    pub min_signatures: usize,
+}
+
+/// A caller identity as resolved by your own stake / VC / role logic.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CallerRole {
+    pub did: String,
+    pub role: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuardConfig {
+    /// Absolute risk‑of‑harm ceiling (0.0–1.0); anything above is rejected.
+    pub roh_ceiling: f64,
+    /// Per‑jurisdiction ceilings keyed by `jurisdiction_code` ("US-AZ") or
+    /// its country part ("US"). Strictest wins; see `ceiling_for`.
+    #[serde(default)]
+    pub jurisdiction_ceilings: BTreeMap<String, f64>,
+    /// Model weights used by `evaluate_transition`.
+    #[serde(default)]
+    pub roh_weights: RohWeights,
+    /// Impact level from which the AI RMF `requires_human_in_loop` flag
+    /// forces a HITL gate.
+    pub hitl_min_impact: Sp80053Impact,
+    pub role_constraints: Vec<RoleConstraint>,
+}
+
+impl GuardConfig {
+    /// Rejects ceilings outside 0.0–1.0 and negative or non‑finite weights,
+    /// which would otherwise silently loosen or skew every decision.
+    pub fn validate(&self) -> Result<(), GuardError> {
+        let invalid = |field: String, value: f64| Err(GuardError::InvalidConfig { field, value });
+        let ceilings = std::iter::once(("roh_ceiling".to_string(), self.roh_ceiling)).chain(
+            self.jurisdiction_ceilings
+                .iter()
+                .map(|(code, c)| (format!("jurisdiction_ceilings.{code}"), *c)),
+        );
+        for (field, ceiling) in ceilings {
+            if !(0.0..=1.0).contains(&ceiling) {
+                return invalid(field, ceiling);
+            }
+        }
+
+        let w = &self.roh_weights;
+        let weights = [
+            ("hazard", w.hazard),
+            ("infrastructure_criticality", w.infrastructure_criticality),
+            ("resource_stress", w.resource_stress),
+            ("heat_stress", w.heat_stress),
+            ("pollution_exposure", w.pollution_exposure),
+            ("safety_index", w.safety_index),
+        ];
+        for (name, weight) in weights {
+            if !weight.is_finite() || weight < 0.0 {
+                return invalid(format!("roh_weights.{name}"), weight);
+            }
+        }
+        Ok(())
+    }
+
+    /// The ceiling for a jurisdiction: the lowest of `roh_ceiling`, the
+    /// country entry and the exact entry, so an overlay can only tighten.
+    pub fn ceiling_for(&self, jurisdiction_code: &str) -> f64 {
+        let country = jurisdiction_code.split('-').next().unwrap_or(jurisdiction_code);
+        [country, jurisdiction_code]
+            .iter()
+            .filter_map(|code| self.jurisdiction_ceilings.get(*code))
+            .fold(self.roh_ceiling, |ceiling, c| ceiling.min(*c))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum RejectionReason {
+    #[error("governance invariants not satisfied: {0}")]
+    GovernanceInvariant(&'static str),
+    #[error("risk of harm {roh_after} exceeds ceiling {ceiling}")]
+    RohCeilingExceeded { roh_after: f64, ceiling: f64 },
+    #[error("role `{role}` requires {required} signature(s), got {present}")]
+    RoleConstraintUnmet {
+        role: String,
+        required: usize,
+        present: usize,
+    },
+}
+
+impl RejectionReason {
+    pub fn code(&self) -> &'static str {
+        match self {
+            RejectionReason::GovernanceInvariant(_) => "governance_invariant_violation",
+            RejectionReason::RohCeilingExceeded { .. } => "roh_ceiling_exceeded",
+            RejectionReason::RoleConstraintUnmet { .. } => "role_constraint_unmet",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum GovernanceDecision<T> {
+    Allowed(T),
+    RequiresHitlGate(PendingReview<T>),
+    Rejected(RejectionReason),
+}
+
+#[derive(Debug, Error)]
+pub enum GuardError {
+    #[error("risk-of-harm value {0} is not a finite number in 0.0–1.0")]
+    InvalidRoh(f64),
+    #[error("transition compares polygon {before} with polygon {after}")]
+    PolygonMismatch { before: Uuid, after: Uuid },
+    #[error("guard config `{field}` has invalid value {value}")]
+    InvalidConfig { field: String, value: f64 },
+}
+
+/// Single decision point to call before any high‑impact action or route is
+/// accepted. Voting‑agnostic: callers supply roles, and either RoH values
+/// from their own model (`evaluate`) or telemetry for the built‑in
+/// `RiskOfHarm` model (`evaluate_transition`).
+pub struct GovernanceGuard {
+    config: GuardConfig,
+}
+
+impl GovernanceGuard {
+    /// Fails if `config` does not pass `GuardConfig::validate`.
+    pub fn new(config: GuardConfig) -> Result<Self, GuardError> {
+        config.validate()?;
+        Ok(Self { config })
+    }
+
+    pub fn config(&self) -> &GuardConfig {
+        &self.config
+    }
+
+    pub fn evaluate<T, P>(
+        &self,
+        subject: T,
+        roh_before: f64,
+        roh_after: f64,
+        caller_roles: &[CallerRole],
+        ctx: &AuditContext,
+        audit_sink: &mut P,
+    ) -> Result<GovernanceDecision<T>, GuardError>
+    where
+        T: HasGovernanceMetadata,
+        P: AuditSink,
+    {
+        let trace = DecisionTrace::new("governance_guard");
+        self.decide(subject, (roh_before, roh_after), caller_roles, trace, ctx, audit_sink)
+    }
+
+    /// Like `evaluate`, computing RoH before and after a proposed change
+    /// from the polygon's telemetry with the configured `roh_weights`. Any
+    /// increase needs a human in the loop; the jurisdiction's ceiling still
+    /// rejects outright.
+    pub fn evaluate_transition<T, P>(
+        &self,
+        subject: T,
+        before: &NanopolygonSafetyObject,
+        after: &NanopolygonSafetyObject,
+        caller_roles: &[CallerRole],
+        ctx: &AuditContext,
+        audit_sink: &mut P,
+    ) -> Result<GovernanceDecision<T>, GuardError>
+    where
+        T: HasGovernanceMetadata,
+        P: AuditSink,
+    {
+        if before.polygon_id != after.polygon_id {
+            audit_sink.record(
+                AuditEvent::governance_rejected(ctx, "polygon_mismatch")
+                    .with_subject(subject.audit_subject()),
+            );
+            return Err(GuardError::PolygonMismatch {
+                before: before.polygon_id,
+                after: after.polygon_id,
+            });
+        }
+
+        let weights = &self.config.roh_weights;
+        let roh_before = RiskOfHarm::assess(before, weights);
+        let roh_after = RiskOfHarm::assess(after, weights);
+        let mut trace = DecisionTrace::new("governance_guard");
+        trace.push(
+            TraceStep::new("risk_of_harm_model", false, "risk of harm assessed from telemetry")
+                .with_input("before", roh_before)
+                .with_input("after", roh_after)
+                .with_input("weights", weights),
+        );
+        self.decide(
+            subject,
+            (roh_before.value, roh_after.value),
+            caller_roles,
+            trace,
+            ctx,
+            audit_sink,
+        )
+    }
+
+    fn decide<T, P>(
+        &self,
+        subject: T,
+        (roh_before, roh_after): (f64, f64),
+        caller_roles: &[CallerRole],
+        mut trace: DecisionTrace,
+        ctx: &AuditContext,
+        audit_sink: &mut P,
+    ) -> Result<GovernanceDecision<T>, GuardError>
+    where
+        T: HasGovernanceMetadata,
+        P: AuditSink,
+    {
+        let subject_ids = subject.audit_subject();
+        for roh in [roh_before, roh_after] {
+            if !(0.0..=1.0).contains(&roh) {
+                audit_sink.record(
+                    AuditEvent::governance_rejected(ctx, "invalid_roh").with_subject(subject_ids),
+                );
+                return Err(GuardError::InvalidRoh(roh));
+            }
+        }
+
+        let decision = match self.check(&subject, roh_before, roh_after, caller_roles, &mut trace) {
+            Err(reason) => GovernanceDecision::Rejected(reason),
+            Ok(Some(reason)) => {
+                GovernanceDecision::RequiresHitlGate(PendingReview::new(subject, reason, subject_ids))
+            }
+            Ok(None) => GovernanceDecision::Allowed(subject),
+        };
+
+        let (event, code) = match &decision {
+            GovernanceDecision::Allowed(_) => (AuditEvent::governance_allowed(ctx), "allowed"),
+            GovernanceDecision::RequiresHitlGate(pending) => {
+                let code = pending.reason().code();
+                (AuditEvent::governance_hitl_required(ctx, code), code)
+            }
+            GovernanceDecision::Rejected(reason) => {
+                (AuditEvent::governance_rejected(ctx, reason.code()), reason.code())
+            }
+        };
+        let trace = trace.finish(code);
+        audit_sink.record(event.with_subject(subject_ids).with_trace(trace.clone()));
+
+        let decision = match decision {
+            GovernanceDecision::RequiresHitlGate(pending) => {
+                GovernanceDecision::RequiresHitlGate(pending.with_trace(trace))
+            }
+            other => other,
+        };
+        Ok(decision)
+    }
+
+    // Rejections take precedence over HITL gates; `Ok(None)` means allowed.
+    // Every check evaluated is recorded in `trace`, with `matched` set when
+    // the check fired.
+    fn check<T: HasGovernanceMetadata>(
+        &self,
+        subject: &T,
+        roh_before: f64,
+        roh_after: f64,
+        caller_roles: &[CallerRole],
+        trace: &mut DecisionTrace,
+    ) -> Result<Option<HitlReason>, RejectionReason> {
+        let meta = subject.governance_metadata();
+        let invariants = meta.assert_invariants();
+        trace.push(
+            TraceStep::new(
+                "governance_invariants",
+                invariants.is_err(),
+                invariants.err().unwrap_or("AI RMF, SP 800‑53 and ethics invariants hold"),
+            )
+            .with_input("ai_rmf_functions", meta.ai_rmf_profile.functions)
+            .with_input("sp80053_families", meta.sp80053_profile.families)
+            .with_input("ethics_profile", &meta.ethics_profile),
+        );
+        invariants.map_err(RejectionReason::GovernanceInvariant)?;
+
+        let ceiling = self.config.ceiling_for(&meta.jurisdiction_code);
+        let over_ceiling = roh_after > ceiling;
+        trace.push(
+            TraceStep::new(
+                "roh_ceiling",
+                over_ceiling,
+                if over_ceiling {
+                    "risk of harm after the change exceeds the ceiling"
+                } else {
+                    "risk of harm within ceiling"
+                },
+            )
+            .with_input("roh_after", roh_after)
+            .with_input("roh_ceiling", ceiling)
+            .with_input("jurisdiction_code", &meta.jurisdiction_code),
+        );
+        if over_ceiling {
+            return Err(RejectionReason::RohCeilingExceeded {
+                roh_after,
+                ceiling,
+            });
+        }
+
+        for constraint in &self.config.role_constraints {
+            let present = caller_roles
+                .iter()
+                .filter(|c| c.role == constraint.role)
+                .map(|c| c.did.as_str())
+                .collect::<HashSet<_>>()
+                .len();
+            let unmet = present < constraint.min_signatures;
+            trace.push(
+                TraceStep::new(
+                    format!("role_constraint:{}", constraint.role),
+                    unmet,
+                    if unmet {
+                        "not enough distinct callers hold this role"
+                    } else {
+                        "enough distinct callers hold this role"
+                    },
+                )
+                .with_input("required", constraint.min_signatures)
+                .with_input("present", present),
+            );
+            if unmet {
+                return Err(RejectionReason::RoleConstraintUnmet {
+                    role: constraint.role.clone(),
+                    required: constraint.min_signatures,
+                    present,
+                });
+            }
+        }
+
+        // Monotonic safety: no harmful deltas without a human in the loop.
+        let harmful = roh_after > roh_before;
+        trace.push(
+            TraceStep::new(
+                "harmful_roh_delta",
+                harmful,
+                if harmful {
+                    "risk of harm would increase"
+                } else {
+                    "risk of harm does not increase"
+                },
+            )
+            .with_input("roh_before", roh_before)
+            .with_input("roh_after", roh_after),
+        );
+        if harmful {
+            return Ok(Some(HitlReason::HarmfulDelta {
+                roh_before,
+                roh_after,
+            }));
+        }
+
+        let impact = meta.sp80053_profile.impact;
+        let hitl = meta.ai_rmf_profile.requires_human_in_loop && impact >= self.config.hitl_min_impact;
+        trace.push(
+            TraceStep::new(
+                "human_in_loop_required",
+                hitl,
+                if hitl {
+                    "profile requires a human in the loop at this impact"
+                } else {
+                    "no human in the loop required at this impact"
+                },
+            )
+            .with_input("requires_human_in_loop", meta.ai_rmf_profile.requires_human_in_loop)
+            .with_input("impact", impact)
+            .with_input("hitl_min_impact", self.config.hitl_min_impact),
+        );
+        if hitl {
+            return Ok(Some(HitlReason::HumanInLoopRequired { impact }));
+        }
+
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::{event::AuditKind, sink::InMemoryAuditSink};
+    use crate::nanopolygon::fixtures::nanopolygon;
+
+    fn config() -> GuardConfig {
+        GuardConfig {
+            roh_ceiling: 0.6,
+            jurisdiction_ceilings: BTreeMap::new(),
+            roh_weights: RohWeights::default(),
+            hitl_min_impact: Sp80053Impact::High,
+            role_constraints: vec![RoleConstraint {
+                role: "operator".to_string(),
+                min_signatures: 2,
+            }],
+        }
+    }
+
+    fn operators(dids: &[&str]) -> Vec<CallerRole> {
+        dids.iter()
+            .map(|did| CallerRole {
+                did: did.to_string(),
+                role: "operator".to_string(),
+            })
+            .collect()
+    }
+
+    fn run(
+        np: NanopolygonSafetyObject,
+        roh: (f64, f64),
+        callers: &[CallerRole],
+    ) -> (Result<GovernanceDecision<NanopolygonSafetyObject>, GuardError>, InMemoryAuditSink) {
+        let guard = GovernanceGuard::new(config()).unwrap();
+        let mut sink = InMemoryAuditSink::new();
+        let decision = guard.evaluate(np, roh.0, roh.1, callers, &AuditContext::new(Uuid::nil()), &mut sink);
+        (decision, sink)
+    }
+
+    fn rejection(decision: GovernanceDecision<NanopolygonSafetyObject>) -> RejectionReason {
+        match decision {
+            GovernanceDecision::Rejected(reason) => reason,
+            other => panic!("expected a rejection, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn invariants_are_checked_before_everything_else() {
+        let mut np = nanopolygon();
+        np.metadata.ethics_profile.human_primacy = false;
+        let (decision, sink) = run(np, (0.1, 0.9), &[]);
+        assert!(matches!(
+            rejection(decision.unwrap()),
+            RejectionReason::GovernanceInvariant(_)
+        ));
+        let trace = sink.events[0].trace.as_ref().unwrap();
+        assert_eq!(trace.outcome, "governance_invariant_violation");
+        assert_eq!(trace.steps.len(), 1);
+    }
+
+    #[test]
+    fn ceiling_is_checked_before_roles() {
+        let (decision, _) = run(nanopolygon(), (0.1, 0.9), &[]);
+        assert_eq!(
+            rejection(decision.unwrap()),
+            RejectionReason::RohCeilingExceeded {
+                roh_after: 0.9,
+                ceiling: 0.6
+            }
+        );
+    }
+
+    #[test]
+    fn roles_are_checked_before_hitl() {
+        // The same DID twice counts once.
+        let (decision, _) = run(nanopolygon(), (0.1, 0.5), &operators(&["did:ex:a", "did:ex:a"]));
+        assert_eq!(
+            rejection(decision.unwrap()),
+            RejectionReason::RoleConstraintUnmet {
+                role: "operator".to_string(),
+                required: 2,
+                present: 1
+            }
+        );
+    }
+
+    #[test]
+    fn harmful_delta_requires_hitl() {
+        let (decision, sink) = run(nanopolygon(), (0.1, 0.5), &operators(&["did:ex:a", "did:ex:b"]));
+        match decision.unwrap() {
+            GovernanceDecision::RequiresHitlGate(pending) => {
+                assert_eq!(pending.reason().code(), "harmful_roh_delta");
+                assert_eq!(pending.trace().unwrap().outcome, "harmful_roh_delta");
+            }
+            other => panic!("expected a HITL gate, got {other:?}"),
+        }
+        assert!(matches!(sink.events[0].kind, AuditKind::GovernanceHitlRequired { .. }));
+    }
+
+    #[test]
+    fn hitl_flag_gates_high_impact_and_allows_otherwise() {
+        let callers = operators(&["did:ex:a", "did:ex:b"]);
+        let (decision, _) = run(nanopolygon(), (0.5, 0.5), &callers);
+        match decision.unwrap() {
+            GovernanceDecision::RequiresHitlGate(pending) => {
+                assert_eq!(pending.reason().code(), "human_in_loop_required")
+            }
+            other => panic!("expected a HITL gate, got {other:?}"),
+        }
+
+        let mut np = nanopolygon();
+        np.metadata.ai_rmf_profile.requires_human_in_loop = false;
+        let (decision, sink) = run(np, (0.5, 0.4), &callers);
+        assert!(matches!(decision.unwrap(), GovernanceDecision::Allowed(_)));
+        assert!(matches!(sink.events[0].kind, AuditKind::GovernanceAllowed));
+    }
+
+    #[test]
+    fn non_finite_or_out_of_range_roh_is_an_error() {
+        for roh in [(f64::NAN, 0.1), (0.1, f64::INFINITY), (-0.1, 0.1), (0.1, 1.5)] {
+            let (decision, sink) = run(nanopolygon(), roh, &[]);
+            assert!(matches!(decision, Err(GuardError::InvalidRoh(_))));
+            match &sink.events[0].kind {
+                AuditKind::GovernanceRejected { reason } => assert_eq!(reason, "invalid_roh"),
+                other => panic!("unexpected event {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn config_ceilings_and_weights_are_validated() {
+        assert!(config().validate().is_ok());
+        let mut bad = config();
+        bad.roh_ceiling = f64::NAN;
+        assert!(matches!(GovernanceGuard::new(bad), Err(GuardError::InvalidConfig { ref field, .. }) if field == "roh_ceiling"));
+
+        let mut bad = config();
+        bad.jurisdiction_ceilings.insert("US-AZ".to_string(), 1.2);
+        assert!(matches!(bad.validate(), Err(GuardError::InvalidConfig { ref field, .. }) if field == "jurisdiction_ceilings.US-AZ"));
+
+        for weight in [-0.1, f64::INFINITY] {
+            let mut bad = config();
+            bad.roh_weights.heat_stress = weight;
+            assert!(matches!(bad.validate(), Err(GuardError::InvalidConfig { ref field, .. }) if field == "roh_weights.heat_stress"));
+        }
+    }
+
+    #[test]
+    fn strictest_of_country_and_subdivision_ceiling_applies() {
+        let mut config = config();
+        config.jurisdiction_ceilings.insert("US".to_string(), 0.5);
+        config.jurisdiction_ceilings.insert("US-AZ".to_string(), 0.4);
+        assert_eq!(config.ceiling_for("US-AZ"), 0.4);
+        assert_eq!(config.ceiling_for("US-CA"), 0.5);
+        assert_eq!(config.ceiling_for("FR"), 0.6);
+        config.jurisdiction_ceilings.insert("US".to_string(), 0.3);
+        assert_eq!(config.ceiling_for("US-AZ"), 0.3);
+
+        let guard = GovernanceGuard::new(config).unwrap();
+        let mut sink = InMemoryAuditSink::new();
+        let callers = operators(&["did:ex:a", "did:ex:b"]);
+        let decision = guard
+            .evaluate(nanopolygon(), 0.35, 0.35, &callers, &AuditContext::new(Uuid::nil()), &mut sink)
+            .unwrap();
+        assert_eq!(
+            rejection(decision),
+            RejectionReason::RohCeilingExceeded {
+                roh_after: 0.35,
+                ceiling: 0.3
+            }
+        );
+    }
+
+    #[test]
+    fn harmful_transition_requires_hitl() {
+        let mut config = config();
+        config.roh_ceiling = 1.0;
+        let guard = GovernanceGuard::new(config).unwrap();
+        let mut sink = InMemoryAuditSink::new();
+        let before = nanopolygon();
+        let mut after = before.clone();
+        after.biospatial.heat_stress = 0.9;
+
+        let decision = guard
+            .evaluate_transition(
+                after.clone(),
+                &before,
+                &after,
+                &operators(&["did:ex:a", "did:ex:b"]),
+                &AuditContext::new(Uuid::nil()),
+                &mut sink,
+            )
+            .unwrap();
+        let GovernanceDecision::RequiresHitlGate(pending) = decision else {
+            panic!("expected a HITL gate, got {decision:?}");
+        };
+        let HitlReason::HarmfulDelta { roh_before, roh_after } = *pending.reason() else {
+            panic!("unexpected reason {:?}", pending.reason());
+        };
+        assert!(roh_after > roh_before);
+        assert_eq!(pending.trace().unwrap().steps[0].rule, "risk_of_harm_model");
+    }
+
+    #[test]
+    fn transition_between_different_polygons_is_an_error() {
+        let guard = GovernanceGuard::new(config()).unwrap();
+        let mut sink = InMemoryAuditSink::new();
+        let before = nanopolygon();
+        let mut after = nanopolygon();
+        after.polygon_id = Uuid::from_u128(7);
+
+        let result = guard.evaluate_transition(
+            after.clone(),
+            &before,
+            &after,
+            &[],
+            &AuditContext::new(Uuid::nil()),
+            &mut sink,
+        );
+        assert!(matches!(
+            result,
+            Err(GuardError::PolygonMismatch { before: b, after: a }) if b == before.polygon_id && a == after.polygon_id
+        ));
+        assert!(matches!(&sink.events[0].kind, AuditKind::GovernanceRejected { reason } if reason == "polygon_mismatch"));
+    }
+}
@@ -1,23 +1,32 @@
-use crate::aln_shard::AlnShard;
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug)]
+use crate::aln::AlnShard;
+use crate::audit::trace::DecisionTrace;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct HitlTicket {
     pub ticket_id: String,
     pub shard_did: String,
 }
 
+/// Callers fail closed when a hook errors: a shard whose ticket could not
+/// be stored is rejected, an appeal whose outcome could not be recorded
+/// stays open.
 pub trait GovernanceHooks {
-    fn enqueue_hitl(&self, shard: AlnShard, confidence: f64) -> HitlTicket;
+    type Error: std::error::Error;
+
+    /// `trace` explains why the shard was routed to human review.
+    fn enqueue_hitl(&self, shard: AlnShard, confidence: f64, trace: DecisionTrace) -> Result<HitlTicket, Self::Error>;
     fn record_appeal(
         &self,
         ticket: &HitlTicket,
         human_did: &str,
         decision: AppealDecision,
         reason: &str,
-    );
+    ) -> Result<(), Self::Error>;
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum AppealDecision {
     Approved,
     Rejected,
@@ -0,0 +1,26 @@
+pub mod queue;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
+pub mod typestate;
+
+pub use queue::{
+    HitlAppealRecord,
+    HitlItemState,
+    HitlQueue,
+    HitlQueueConfig,
+    HitlQueueError,
+    HitlQueueItem,
+    InMemoryHitlQueue,
+};
+#[cfg(feature = "sqlite")]
+pub use sqlite::SqliteHitlQueue;
+pub use typestate::{
+    ApprovedByHuman,
+    Executed,
+    HitlError,
+    HitlReason,
+    HumanReview,
+    PendingReview,
+    RejectedByHuman,
+    ReviewOutcome,
+};
@@ -0,0 +1,700 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{de::{DeserializeSeed, Error as _}, Deserialize, Deserializer, Serialize};
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::aln::AlnShard;
+use crate::audit::{context::AuditContext, event::AuditEvent, sink::AuditSink, trace::DecisionTrace};
+use crate::governance_hooks::{AppealDecision, GovernanceHooks, HitlTicket};
+use crate::nanopolygon::ValidationSeed;
+use crate::policy::{governance_metadata::HasGovernanceMetadata, nist_80053::Sp80053Impact};
+use crate::web5_integration::vc::{OperatorCredential, OperatorRole};
+use super::typestate::HumanReview;
+
+/// SLA, claim timeout and reviewer roles of a HITL queue. Durations are
+/// whole seconds so the config can live in a file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct HitlQueueConfig {
+    pub low_sla_secs: u64,
+    pub moderate_sla_secs: u64,
+    pub high_sla_secs: u64,
+    /// A claim not completed within this time lapses and the ticket returns
+    /// to the queue.
+    pub claim_timeout_secs: u64,
+    pub low_reviewer_role: OperatorRole,
+    pub moderate_reviewer_role: OperatorRole,
+    pub high_reviewer_role: OperatorRole,
+}
+
+impl Default for HitlQueueConfig {
+    fn default() -> Self {
+        Self {
+            low_sla_secs: 72 * 3600,
+            moderate_sla_secs: 24 * 3600,
+            high_sla_secs: 4 * 3600,
+            claim_timeout_secs: 30 * 60,
+            low_reviewer_role: OperatorRole::HitlReviewer,
+            moderate_reviewer_role: OperatorRole::HitlReviewer,
+            high_reviewer_role: OperatorRole::HitlReviewer,
+        }
+    }
+}
+
+impl HitlQueueConfig {
+    pub fn sla(&self, impact: Sp80053Impact) -> Duration {
+        seconds(match impact {
+            Sp80053Impact::Low => self.low_sla_secs,
+            Sp80053Impact::Moderate => self.moderate_sla_secs,
+            Sp80053Impact::High => self.high_sla_secs,
+        })
+    }
+
+    pub fn claim_timeout(&self) -> Duration {
+        seconds(self.claim_timeout_secs)
+    }
+
+    pub fn reviewer_role(&self, impact: Sp80053Impact) -> &OperatorRole {
+        match impact {
+            Sp80053Impact::Low => &self.low_reviewer_role,
+            Sp80053Impact::Moderate => &self.moderate_reviewer_role,
+            Sp80053Impact::High => &self.high_reviewer_role,
+        }
+    }
+
+    /// Builds the queue item for a newly gated shard.
+    pub fn new_item(
+        &self,
+        shard: AlnShard,
+        confidence: f64,
+        trace: DecisionTrace,
+        now: DateTime<Utc>,
+    ) -> HitlQueueItem {
+        let impact = shard.governance_metadata().sp80053_profile.impact;
+        let shard_did = match &shard.signature {
+            Some(block) => block.signer_did.clone(),
+            None => shard.governance_metadata().data_owner_did.clone(),
+        };
+        HitlQueueItem {
+            ticket: HitlTicket {
+                ticket_id: Uuid::new_v4().to_string(),
+                shard_did,
+            },
+            priority: priority(impact, confidence),
+            impact,
+            confidence,
+            required_role: self.reviewer_role(impact).clone(),
+            enqueued_at: now,
+            due_at: now.checked_add_signed(self.sla(impact)).unwrap_or(DateTime::<Utc>::MAX_UTC),
+            state: HitlItemState::Queued,
+            appeals: Vec::new(),
+            shard,
+            trace,
+        }
+    }
+}
+
+/// Queue order: impact first, then model confidence (a more certain model
+/// pushes harder to actuate, so a human should look sooner). Ties are
+/// served first come, first served.
+pub fn priority(impact: Sp80053Impact, confidence: f64) -> i64 {
+    let impact_rank = match impact {
+        Sp80053Impact::Low => 0,
+        Sp80053Impact::Moderate => 1,
+        Sp80053Impact::High => 2,
+    };
+    let confidence = if confidence.is_finite() { confidence.clamp(0.0, 1.0) } else { 1.0 };
+    impact_rank * 1_000_000 + (confidence * 1_000_000.0).round() as i64
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "state", rename_all = "snake_case")]
+pub enum HitlItemState {
+    Queued,
+    Claimed {
+        reviewer_did: String,
+        claimed_at: DateTime<Utc>,
+        expires_at: DateTime<Utc>,
+    },
+    Decided(HumanReview),
+}
+
+/// Appeal outcome recorded against a ticket through `record_appeal`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HitlAppealRecord {
+    pub human_did: String,
+    pub decision: AppealDecision,
+    pub reason: String,
+    pub recorded_at: DateTime<Utc>,
+}
+
+/// Decode with a `ValidationSeed`, which re-validates the shard.
+#[derive(Debug, Clone, Serialize)]
+pub struct HitlQueueItem {
+    pub ticket: HitlTicket,
+    pub shard: AlnShard,
+    pub confidence: f64,
+    pub impact: Sp80053Impact,
+    pub priority: i64,
+    /// Role a reviewer's credential must carry to claim this ticket.
+    pub required_role: OperatorRole,
+    pub trace: DecisionTrace,
+    pub enqueued_at: DateTime<Utc>,
+    /// SLA deadline for a human decision.
+    pub due_at: DateTime<Utc>,
+    #[serde(flatten)]
+    pub state: HitlItemState,
+    #[serde(default)]
+    pub appeals: Vec<HitlAppealRecord>,
+}
+
+/// Wire shape of `HitlQueueItem` before its shard is validated.
+#[derive(Deserialize)]
+struct StoredHitlQueueItem {
+    ticket: HitlTicket,
+    shard: serde_json::Value,
+    confidence: f64,
+    impact: Sp80053Impact,
+    priority: i64,
+    required_role: OperatorRole,
+    trace: DecisionTrace,
+    enqueued_at: DateTime<Utc>,
+    due_at: DateTime<Utc>,
+    #[serde(flatten)]
+    state: HitlItemState,
+    #[serde(default)]
+    appeals: Vec<HitlAppealRecord>,
+}
+
+impl<'de> DeserializeSeed<'de> for ValidationSeed<'_, HitlQueueItem> {
+    type Value = HitlQueueItem;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        let stored = StoredHitlQueueItem::deserialize(deserializer)?;
+        let shard = ValidationSeed::<AlnShard>::new(self.config())
+            .deserialize(stored.shard)
+            .map_err(D::Error::custom)?;
+        Ok(HitlQueueItem {
+            ticket: stored.ticket,
+            shard,
+            confidence: stored.confidence,
+            impact: stored.impact,
+            priority: stored.priority,
+            required_role: stored.required_role,
+            trace: stored.trace,
+            enqueued_at: stored.enqueued_at,
+            due_at: stored.due_at,
+            state: stored.state,
+            appeals: stored.appeals,
+        })
+    }
+}
+
+impl HitlQueueItem {
+    /// Queued, or claimed with a lapsed claim.
+    pub fn is_claimable(&self, now: DateTime<Utc>) -> bool {
+        match &self.state {
+            HitlItemState::Queued => true,
+            HitlItemState::Claimed { expires_at, .. } => now >= *expires_at,
+            HitlItemState::Decided(_) => false,
+        }
+    }
+
+    pub fn is_decided(&self) -> bool {
+        matches!(self.state, HitlItemState::Decided(_))
+    }
+
+    pub fn is_overdue(&self, now: DateTime<Utc>) -> bool {
+        !self.is_decided() && now >= self.due_at
+    }
+
+    /// The reviewer holding an unexpired claim, if any.
+    pub fn claimant(&self, now: DateTime<Utc>) -> Option<&str> {
+        match &self.state {
+            HitlItemState::Claimed { reviewer_did, expires_at, .. } if now < *expires_at => {
+                Some(reviewer_did)
+            }
+            _ => None,
+        }
+    }
+
+    /// Sort key: highest priority first, then oldest.
+    pub(crate) fn queue_order(a: &Self, b: &Self) -> std::cmp::Ordering {
+        b.priority
+            .cmp(&a.priority)
+            .then(a.enqueued_at.cmp(&b.enqueued_at))
+    }
+
+    pub(crate) fn claim(
+        &mut self,
+        reviewer: &OperatorCredential,
+        timeout: Duration,
+        now: DateTime<Utc>,
+    ) -> Result<(), HitlQueueError> {
+        if self.is_decided() {
+            return Err(HitlQueueError::AlreadyDecided(self.ticket.ticket_id.clone()));
+        }
+        if let Some(claimant) = self.claimant(now) {
+            if claimant != reviewer.did {
+                return Err(HitlQueueError::ClaimedByOther {
+                    ticket_id: self.ticket.ticket_id.clone(),
+                    reviewer_did: claimant.to_string(),
+                });
+            }
+        }
+        if !reviewer.is_valid_at(now) {
+            return Err(HitlQueueError::CredentialNotValid(reviewer.did.clone()));
+        }
+        if !reviewer.has_role(&self.required_role) {
+            return Err(HitlQueueError::RoleMissing {
+                ticket_id: self.ticket.ticket_id.clone(),
+                required: self.required_role.clone(),
+            });
+        }
+        self.state = HitlItemState::Claimed {
+            reviewer_did: reviewer.did.clone(),
+            claimed_at: now,
+            expires_at: now.checked_add_signed(timeout).unwrap_or(DateTime::<Utc>::MAX_UTC),
+        };
+        Ok(())
+    }
+
+    pub(crate) fn release(&mut self, reviewer_did: &str, now: DateTime<Utc>) -> Result<(), HitlQueueError> {
+        self.require_claimant(reviewer_did, now)?;
+        self.state = HitlItemState::Queued;
+        Ok(())
+    }
+
+    pub(crate) fn complete(&mut self, review: HumanReview, now: DateTime<Utc>) -> Result<(), HitlQueueError> {
+        self.require_claimant(&review.reviewer_did, now)?;
+        self.state = HitlItemState::Decided(review);
+        Ok(())
+    }
+
+    fn require_claimant(&self, reviewer_did: &str, now: DateTime<Utc>) -> Result<(), HitlQueueError> {
+        if self.is_decided() {
+            return Err(HitlQueueError::AlreadyDecided(self.ticket.ticket_id.clone()));
+        }
+        if self.claimant(now) != Some(reviewer_did) {
+            return Err(HitlQueueError::NotClaimant {
+                ticket_id: self.ticket.ticket_id.clone(),
+                reviewer_did: reviewer_did.to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum HitlQueueError {
+    #[error("no HITL ticket `{0}`")]
+    UnknownTicket(String),
+    #[error("HITL ticket `{0}` is already decided")]
+    AlreadyDecided(String),
+    #[error("HITL ticket `{ticket_id}` is claimed by {reviewer_did}")]
+    ClaimedByOther {
+        ticket_id: String,
+        reviewer_did: String,
+    },
+    #[error("{reviewer_did} does not hold a live claim on HITL ticket `{ticket_id}`")]
+    NotClaimant {
+        ticket_id: String,
+        reviewer_did: String,
+    },
+    #[error("HITL ticket `{ticket_id}` requires the {required} role")]
+    RoleMissing {
+        ticket_id: String,
+        required: OperatorRole,
+    },
+    #[error("reviewer credential for {0} is not valid at the time of the claim")]
+    CredentialNotValid(String),
+    #[error("could not encode queue item: {0}")]
+    Encoding(#[from] serde_json::Error),
+    #[cfg(feature = "sqlite")]
+    #[error("HITL queue storage failed: {0}")]
+    Sqlite(#[from] rusqlite::Error),
+}
+
+impl HitlQueueError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            HitlQueueError::UnknownTicket(_) => "unknown_ticket",
+            HitlQueueError::AlreadyDecided(_) => "ticket_already_decided",
+            HitlQueueError::ClaimedByOther { .. } => "ticket_claimed_by_other",
+            HitlQueueError::NotClaimant { .. } => "not_claimant",
+            HitlQueueError::RoleMissing { .. } => "reviewer_role_missing",
+            HitlQueueError::CredentialNotValid(_) => "reviewer_credential_not_valid",
+            HitlQueueError::Encoding(_) => "encoding_failed",
+            #[cfg(feature = "sqlite")]
+            HitlQueueError::Sqlite(_) => "storage_failed",
+        }
+    }
+}
+
+/// A HITL work queue. Every implementation is also `GovernanceHooks`, so it
+/// can back `RoutingContext::decide` directly.
+pub trait HitlQueue {
+    fn enqueue(
+        &self,
+        shard: AlnShard,
+        confidence: f64,
+        trace: DecisionTrace,
+        now: DateTime<Utc>,
+    ) -> Result<HitlTicket, HitlQueueError>;
+
+    fn get(&self, ticket_id: &str) -> Result<Option<HitlQueueItem>, HitlQueueError>;
+
+    /// Undecided tickets in queue order.
+    fn pending(&self) -> Result<Vec<HitlQueueItem>, HitlQueueError>;
+
+    /// Claims `ticket_id` for `reviewer`, whose credential must be valid at
+    /// `now` and carry the ticket's role. Re-claiming one's own ticket extends the claim.
+    fn claim(
+        &self,
+        ticket_id: &str,
+        reviewer: &OperatorCredential,
+        now: DateTime<Utc>,
+    ) -> Result<HitlQueueItem, HitlQueueError>;
+
+    /// Claims the highest-priority claimable ticket `reviewer` may take.
+    fn claim_next(
+        &self,
+        reviewer: &OperatorCredential,
+        now: DateTime<Utc>,
+    ) -> Result<Option<HitlQueueItem>, HitlQueueError> {
+        let next = self.pending()?.into_iter().find(|item| {
+            item.is_claimable(now)
+                && reviewer.is_valid_at(now)
+                && reviewer.has_role(&item.required_role)
+        });
+        next.map(|item| self.claim(&item.ticket.ticket_id, reviewer, now))
+            .transpose()
+    }
+
+    /// Hands a claimed ticket back to the queue.
+    fn release(&self, ticket_id: &str, reviewer_did: &str, now: DateTime<Utc>) -> Result<(), HitlQueueError>;
+
+    /// Stores the claimant's decision; the reviewer must hold a live claim.
+    /// Use `complete`, which also audits it.
+    fn record_decision(&self, ticket_id: &str, review: HumanReview, now: DateTime<Utc>) -> Result<HitlQueueItem, HitlQueueError>;
+
+    /// Records the claimant's decision and emits `HumanApproved` or
+    /// `HumanRejected` with the ticket's routing trace.
+    fn complete<P: AuditSink>(
+        &self,
+        ticket_id: &str,
+        review: HumanReview,
+        now: DateTime<Utc>,
+        ctx: &AuditContext,
+        audit_sink: &mut P,
+    ) -> Result<HitlQueueItem, HitlQueueError> {
+        let item = self.record_decision(ticket_id, review.clone(), now)?;
+        audit_sink.record(
+            AuditEvent::human_review(ctx, &review)
+                .with_subject(item.shard.audit_subject())
+                .with_trace(item.trace.clone()),
+        );
+        Ok(item)
+    }
+
+    fn record_appeal_outcome(&self, ticket_id: &str, appeal: HitlAppealRecord) -> Result<(), HitlQueueError>;
+
+    /// Undecided tickets past their SLA, in queue order.
+    fn overdue(&self, now: DateTime<Utc>) -> Result<Vec<HitlQueueItem>, HitlQueueError> {
+        Ok(self
+            .pending()?
+            .into_iter()
+            .filter(|item| item.is_overdue(now))
+            .collect())
+    }
+}
+
+impl<Q: HitlQueue> GovernanceHooks for Q {
+    type Error = HitlQueueError;
+
+    fn enqueue_hitl(&self, shard: AlnShard, confidence: f64, trace: DecisionTrace) -> Result<HitlTicket, HitlQueueError> {
+        self.enqueue(shard, confidence, trace, Utc::now())
+    }
+
+    fn record_appeal(
+        &self,
+        ticket: &HitlTicket,
+        human_did: &str,
+        decision: AppealDecision,
+        reason: &str,
+    ) -> Result<(), HitlQueueError> {
+        let appeal = HitlAppealRecord {
+            human_did: human_did.to_string(),
+            decision,
+            reason: reason.to_string(),
+            recorded_at: Utc::now(),
+        };
+        // Tickets from another queue are not ours to annotate; the appeal
+        // itself is still on the audit trail.
+        match self.record_appeal_outcome(&ticket.ticket_id, appeal) {
+            Ok(()) | Err(HitlQueueError::UnknownTicket(_)) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+/// In-process queue, for tests and single-node deployments.
+#[derive(Debug, Default)]
+pub struct InMemoryHitlQueue {
+    config: HitlQueueConfig,
+    items: Mutex<HashMap<String, HitlQueueItem>>,
+}
+
+impl InMemoryHitlQueue {
+    pub fn new(config: HitlQueueConfig) -> Self {
+        Self {
+            config,
+            items: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn config(&self) -> &HitlQueueConfig {
+        &self.config
+    }
+
+    fn with_item<T>(
+        &self,
+        ticket_id: &str,
+        f: impl FnOnce(&mut HitlQueueItem) -> Result<T, HitlQueueError>,
+    ) -> Result<T, HitlQueueError> {
+        let mut items = self.items.lock().unwrap_or_else(|e| e.into_inner());
+        let item = items
+            .get_mut(ticket_id)
+            .ok_or_else(|| HitlQueueError::UnknownTicket(ticket_id.to_string()))?;
+        f(item)
+    }
+}
+
+impl HitlQueue for InMemoryHitlQueue {
+    fn enqueue(
+        &self,
+        shard: AlnShard,
+        confidence: f64,
+        trace: DecisionTrace,
+        now: DateTime<Utc>,
+    ) -> Result<HitlTicket, HitlQueueError> {
+        let item = self.config.new_item(shard, confidence, trace, now);
+        let ticket = item.ticket.clone();
+        self.items
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(ticket.ticket_id.clone(), item);
+        Ok(ticket)
+    }
+
+    fn get(&self, ticket_id: &str) -> Result<Option<HitlQueueItem>, HitlQueueError> {
+        let items = self.items.lock().unwrap_or_else(|e| e.into_inner());
+        Ok(items.get(ticket_id).cloned())
+    }
+
+    fn pending(&self) -> Result<Vec<HitlQueueItem>, HitlQueueError> {
+        let items = self.items.lock().unwrap_or_else(|e| e.into_inner());
+        let mut pending: Vec<HitlQueueItem> = items
+            .values()
+            .filter(|item| !item.is_decided())
+            .cloned()
+            .collect();
+        pending.sort_by(HitlQueueItem::queue_order);
+        Ok(pending)
+    }
+
+    fn claim(
+        &self,
+        ticket_id: &str,
+        reviewer: &OperatorCredential,
+        now: DateTime<Utc>,
+    ) -> Result<HitlQueueItem, HitlQueueError> {
+        let timeout = self.config.claim_timeout();
+        self.with_item(ticket_id, |item| {
+            item.claim(reviewer, timeout, now)?;
+            Ok(item.clone())
+        })
+    }
+
+    fn release(&self, ticket_id: &str, reviewer_did: &str, now: DateTime<Utc>) -> Result<(), HitlQueueError> {
+        self.with_item(ticket_id, |item| item.release(reviewer_did, now))
+    }
+
+    fn record_decision(&self, ticket_id: &str, review: HumanReview, now: DateTime<Utc>) -> Result<HitlQueueItem, HitlQueueError> {
+        self.with_item(ticket_id, |item| {
+            item.complete(review, now)?;
+            Ok(item.clone())
+        })
+    }
+
+    fn record_appeal_outcome(&self, ticket_id: &str, appeal: HitlAppealRecord) -> Result<(), HitlQueueError> {
+        self.with_item(ticket_id, |item| {
+            item.appeals.push(appeal);
+            Ok(())
+        })
+    }
+}
+
+fn seconds(secs: u64) -> Duration {
+    Duration::try_seconds(i64::try_from(secs).unwrap_or(i64::MAX)).unwrap_or(Duration::MAX)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::audit::{event::AuditKind, sink::InMemoryAuditSink};
+    use crate::nanopolygon::fixtures::{nanopolygon, shard};
+    use crate::nanopolygon::ValidatedNanopolygon;
+
+    pub(crate) fn shard_at(impact: Sp80053Impact) -> AlnShard {
+        let mut np = nanopolygon();
+        np.metadata.sp80053_profile.impact = impact;
+        AlnShard::new(shard().header, ValidatedNanopolygon::try_from(np).unwrap())
+    }
+
+    pub(crate) fn reviewer(did: &str, role: OperatorRole) -> OperatorCredential {
+        OperatorCredential {
+            did: did.to_string(),
+            issuer: "did:web:issuer.example".to_string(),
+            roles: vec![role],
+            valid_from: None,
+            valid_until: None,
+        }
+    }
+
+    pub(crate) fn review(reviewer_did: &str, decision: AppealDecision) -> HumanReview {
+        HumanReview {
+            reviewer_did: reviewer_did.to_string(),
+            reviewed_at: Utc::now(),
+            decision,
+            rationale: "checked the corridor".to_string(),
+            dwn_hexstamp: "ab12".to_string(),
+        }
+    }
+
+    fn enqueue(queue: &impl HitlQueue, impact: Sp80053Impact, confidence: f64, now: DateTime<Utc>) -> String {
+        queue
+            .enqueue(shard_at(impact), confidence, DecisionTrace::new("test"), now)
+            .unwrap()
+            .ticket_id
+    }
+
+    fn ethics_config() -> HitlQueueConfig {
+        HitlQueueConfig {
+            high_reviewer_role: OperatorRole::EthicsPanel,
+            ..HitlQueueConfig::default()
+        }
+    }
+
+    #[test]
+    fn pending_is_ordered_by_impact_then_confidence_then_age() {
+        let queue = InMemoryHitlQueue::default();
+        let t0 = Utc::now();
+        let low = enqueue(&queue, Sp80053Impact::Low, 0.99, t0);
+        let high_old = enqueue(&queue, Sp80053Impact::High, 0.5, t0);
+        let moderate = enqueue(&queue, Sp80053Impact::Moderate, 0.1, t0);
+        let high_sure = enqueue(&queue, Sp80053Impact::High, 0.9, t0 + Duration::seconds(2));
+        let high_new = enqueue(&queue, Sp80053Impact::High, 0.5, t0 + Duration::seconds(1));
+
+        let order: Vec<String> = queue.pending().unwrap().into_iter().map(|i| i.ticket.ticket_id).collect();
+        assert_eq!(order, [high_sure, high_old, high_new, moderate, low]);
+        assert_eq!(priority(Sp80053Impact::Low, f64::NAN), priority(Sp80053Impact::Low, 1.0));
+    }
+
+    #[test]
+    fn claims_are_gated_by_the_ticket_role() {
+        let queue = InMemoryHitlQueue::new(ethics_config());
+        let now = Utc::now();
+        let high = enqueue(&queue, Sp80053Impact::High, 0.5, now);
+        let low = enqueue(&queue, Sp80053Impact::Low, 0.5, now);
+        let generalist = reviewer("did:web:reviewer.example", OperatorRole::HitlReviewer);
+        let ethicist = reviewer("did:web:ethicist.example", OperatorRole::EthicsPanel);
+
+        let err = queue.claim(&high, &generalist, now).unwrap_err();
+        assert_eq!(err.code(), "reviewer_role_missing");
+        let next = queue.claim_next(&generalist, now).unwrap().unwrap();
+        assert_eq!(next.ticket.ticket_id, low);
+        assert!(queue.claim_next(&generalist, now).unwrap().is_none());
+
+        let next = queue.claim_next(&ethicist, now).unwrap().unwrap();
+        assert_eq!(next.ticket.ticket_id, high);
+        assert_eq!(next.claimant(now), Some("did:web:ethicist.example"));
+    }
+
+    #[test]
+    fn claims_need_a_credential_valid_now() {
+        let queue = InMemoryHitlQueue::default();
+        let now = Utc::now();
+        let ticket = enqueue(&queue, Sp80053Impact::Low, 0.5, now);
+        let mut expired = reviewer("did:web:reviewer.example", OperatorRole::HitlReviewer);
+        expired.valid_until = Some(now - Duration::minutes(1));
+
+        let err = queue.claim(&ticket, &expired, now).unwrap_err();
+        assert!(matches!(err, HitlQueueError::CredentialNotValid(ref did) if *did == expired.did));
+        assert_eq!(err.code(), "reviewer_credential_not_valid");
+        assert!(queue.claim_next(&expired, now).unwrap().is_none());
+
+        let mut not_yet = expired.clone();
+        not_yet.valid_until = None;
+        not_yet.valid_from = Some(now + Duration::minutes(1));
+        assert!(queue.claim_next(&not_yet, now).unwrap().is_none());
+        assert_eq!(queue.get(&ticket).unwrap().unwrap().claimant(now), None);
+
+        let next = queue.claim_next(&not_yet, now + Duration::minutes(2)).unwrap().unwrap();
+        assert_eq!(next.ticket.ticket_id, ticket);
+    }
+
+    #[test]
+    fn slas_and_claims_lapse() {
+        let config = HitlQueueConfig::default();
+        let queue = InMemoryHitlQueue::new(config.clone());
+        let now = Utc::now();
+        let id = enqueue(&queue, Sp80053Impact::High, 0.5, now);
+        let first = reviewer("did:web:first.example", OperatorRole::HitlReviewer);
+        let second = reviewer("did:web:second.example", OperatorRole::HitlReviewer);
+
+        queue.claim(&id, &first, now).unwrap();
+        let err = queue.claim(&id, &second, now).unwrap_err();
+        assert_eq!(err.code(), "ticket_claimed_by_other");
+        let lapsed = now + config.claim_timeout();
+        assert_eq!(queue.claim(&id, &second, lapsed).unwrap().claimant(lapsed), Some("did:web:second.example"));
+
+        let due = now + config.sla(Sp80053Impact::High);
+        assert!(queue.overdue(due - Duration::seconds(1)).unwrap().is_empty());
+        assert_eq!(queue.overdue(due).unwrap()[0].ticket.ticket_id, id);
+    }
+
+    #[test]
+    fn completion_is_audited_as_a_human_review() {
+        let queue = InMemoryHitlQueue::default();
+        let ctx = AuditContext::new(Uuid::nil());
+        let mut sink = InMemoryAuditSink::new();
+        let now = Utc::now();
+        let id = enqueue(&queue, Sp80053Impact::High, 0.5, now);
+        let did = "did:web:reviewer.example";
+
+        let err = queue
+            .complete(&id, review(did, AppealDecision::Approved), now, &ctx, &mut sink)
+            .unwrap_err();
+        assert_eq!(err.code(), "not_claimant");
+        assert!(sink.events.is_empty());
+
+        queue.claim(&id, &reviewer(did, OperatorRole::HitlReviewer), now).unwrap();
+        let item = queue
+            .complete(&id, review(did, AppealDecision::Rejected), now, &ctx, &mut sink)
+            .unwrap();
+        assert!(item.is_decided());
+        assert!(queue.pending().unwrap().is_empty());
+        let [event] = sink.events.as_slice() else {
+            panic!("expected one event, got {:?}", sink.events);
+        };
+        assert!(matches!(&event.kind, AuditKind::HumanRejected { reviewer_did, .. } if reviewer_did == did));
+        assert_eq!(event.subject, item.shard.audit_subject());
+        assert_eq!(event.trace.as_ref(), Some(&item.trace));
+    }
+}
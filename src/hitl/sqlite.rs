@@ -0,0 +1,351 @@
+use std::path::Path;
+use std::sync::Mutex;
+
+use chrono::{DateTime, Utc};
+use rusqlite::{params, Connection, OptionalExtension, TransactionBehavior};
+use serde::de::DeserializeSeed;
+
+use crate::aln::AlnShard;
+use crate::audit::trace::DecisionTrace;
+use crate::governance_hooks::HitlTicket;
+use crate::nanopolygon::{ValidationConfig, ValidationSeed};
+use crate::web5_integration::vc::OperatorCredential;
+use super::queue::{HitlAppealRecord, HitlQueue, HitlQueueConfig, HitlQueueError, HitlQueueItem};
+use super::typestate::HumanReview;
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS hitl_tickets (
+    ticket_id   TEXT PRIMARY KEY,
+    priority    INTEGER NOT NULL,
+    enqueued_at INTEGER NOT NULL,
+    decided     INTEGER NOT NULL DEFAULT 0,
+    item        TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS hitl_tickets_queue
+    ON hitl_tickets (decided, priority DESC, enqueued_at);
+";
+
+/// HITL queue persisted in SQLite. Each ticket is one row: the JSON-encoded
+/// `HitlQueueItem` plus the columns needed to order the queue. Mutations
+/// run in `IMMEDIATE` transactions, so several processes can share one
+/// database file. Stored shards are re-validated when read back, under
+/// `with_validation` (default `ValidationConfig::archival()`: a ticket may
+/// wait longer than its telemetry stays fresh).
+pub struct SqliteHitlQueue {
+    config: HitlQueueConfig,
+    validation: ValidationConfig,
+    conn: Mutex<Connection>,
+}
+
+impl SqliteHitlQueue {
+    pub fn open(path: impl AsRef<Path>, config: HitlQueueConfig) -> Result<Self, HitlQueueError> {
+        Self::from_connection(Connection::open(path)?, config)
+    }
+
+    pub fn open_in_memory(config: HitlQueueConfig) -> Result<Self, HitlQueueError> {
+        Self::from_connection(Connection::open_in_memory()?, config)
+    }
+
+    /// Creates the schema if needed.
+    pub fn from_connection(conn: Connection, config: HitlQueueConfig) -> Result<Self, HitlQueueError> {
+        conn.execute_batch(SCHEMA)?;
+        Ok(Self {
+            config,
+            validation: ValidationConfig::archival(),
+            conn: Mutex::new(conn),
+        })
+    }
+
+    /// Config the stored shards were validated under when enqueued.
+    pub fn with_validation(mut self, validation: ValidationConfig) -> Self {
+        self.validation = validation;
+        self
+    }
+
+    pub fn config(&self) -> &HitlQueueConfig {
+        &self.config
+    }
+
+    fn decode(&self, json: &str) -> Result<HitlQueueItem, HitlQueueError> {
+        let mut de = serde_json::Deserializer::from_str(json);
+        let item = ValidationSeed::<HitlQueueItem>::new(&self.validation).deserialize(&mut de)?;
+        de.end()?;
+        Ok(item)
+    }
+
+    /// Loads, changes and stores one ticket inside a single transaction.
+    fn update<T>(
+        &self,
+        ticket_id: &str,
+        f: impl FnOnce(&mut HitlQueueItem) -> Result<T, HitlQueueError>,
+    ) -> Result<T, HitlQueueError> {
+        let mut conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
+        let tx = conn.transaction_with_behavior(TransactionBehavior::Immediate)?;
+        let json: String = tx
+            .query_row(
+                "SELECT item FROM hitl_tickets WHERE ticket_id = ?1",
+                params![ticket_id],
+                |row| row.get(0),
+            )
+            .optional()?
+            .ok_or_else(|| HitlQueueError::UnknownTicket(ticket_id.to_string()))?;
+        let mut item = self.decode(&json)?;
+        let out = f(&mut item)?;
+        tx.execute(
+            "UPDATE hitl_tickets SET decided = ?2, item = ?3 WHERE ticket_id = ?1",
+            params![ticket_id, item.is_decided(), serde_json::to_string(&item)?],
+        )?;
+        tx.commit()?;
+        Ok(out)
+    }
+}
+
+impl HitlQueue for SqliteHitlQueue {
+    fn enqueue(
+        &self,
+        shard: AlnShard,
+        confidence: f64,
+        trace: DecisionTrace,
+        now: DateTime<Utc>,
+    ) -> Result<HitlTicket, HitlQueueError> {
+        let item = self.config.new_item(shard, confidence, trace, now);
+        let conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
+        conn.execute(
+            "INSERT INTO hitl_tickets (ticket_id, priority, enqueued_at, decided, item)
+             VALUES (?1, ?2, ?3, 0, ?4)",
+            params![
+                item.ticket.ticket_id,
+                item.priority,
+                item.enqueued_at.timestamp_micros(),
+                serde_json::to_string(&item)?,
+            ],
+        )?;
+        Ok(item.ticket)
+    }
+
+    fn get(&self, ticket_id: &str) -> Result<Option<HitlQueueItem>, HitlQueueError> {
+        let conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
+        let json: Option<String> = conn
+            .query_row(
+                "SELECT item FROM hitl_tickets WHERE ticket_id = ?1",
+                params![ticket_id],
+                |row| row.get(0),
+            )
+            .optional()?;
+        json.map(|j| self.decode(&j)).transpose()
+    }
+
+    fn pending(&self) -> Result<Vec<HitlQueueItem>, HitlQueueError> {
+        let conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
+        let mut stmt = conn.prepare(
+            "SELECT item FROM hitl_tickets WHERE decided = 0
+             ORDER BY priority DESC, enqueued_at ASC",
+        )?;
+        let rows = stmt.query_map([], |row| row.get::<_, String>(0))?;
+        let mut pending = Vec::new();
+        for json in rows {
+            pending.push(self.decode(&json?)?);
+        }
+        Ok(pending)
+    }
+
+    fn claim(
+        &self,
+        ticket_id: &str,
+        reviewer: &OperatorCredential,
+        now: DateTime<Utc>,
+    ) -> Result<HitlQueueItem, HitlQueueError> {
+        let timeout = self.config.claim_timeout();
+        self.update(ticket_id, |item| {
+            item.claim(reviewer, timeout, now)?;
+            Ok(item.clone())
+        })
+    }
+
+    /// Picks and claims the ticket inside one `IMMEDIATE` transaction, so
+    /// two reviewers cannot both be handed the same ticket.
+    fn claim_next(
+        &self,
+        reviewer: &OperatorCredential,
+        now: DateTime<Utc>,
+    ) -> Result<Option<HitlQueueItem>, HitlQueueError> {
+        let mut conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
+        let tx = conn.transaction_with_behavior(TransactionBehavior::Immediate)?;
+        let mut next = None;
+        {
+            let mut stmt = tx.prepare(
+                "SELECT item FROM hitl_tickets WHERE decided = 0
+                 ORDER BY priority DESC, enqueued_at ASC",
+            )?;
+            let rows = stmt.query_map([], |row| row.get::<_, String>(0))?;
+            for json in rows {
+                let item = self.decode(&json?)?;
+                if item.is_claimable(now)
+                    && reviewer.is_valid_at(now)
+                    && reviewer.has_role(&item.required_role)
+                {
+                    next = Some(item);
+                    break;
+                }
+            }
+        }
+        let Some(mut item) = next else {
+            return Ok(None);
+        };
+        item.claim(reviewer, self.config.claim_timeout(), now)?;
+        tx.execute(
+            "UPDATE hitl_tickets SET item = ?2 WHERE ticket_id = ?1",
+            params![item.ticket.ticket_id, serde_json::to_string(&item)?],
+        )?;
+        tx.commit()?;
+        Ok(Some(item))
+    }
+
+    fn release(&self, ticket_id: &str, reviewer_did: &str, now: DateTime<Utc>) -> Result<(), HitlQueueError> {
+        self.update(ticket_id, |item| item.release(reviewer_did, now))
+    }
+
+    fn record_decision(&self, ticket_id: &str, review: HumanReview, now: DateTime<Utc>) -> Result<HitlQueueItem, HitlQueueError> {
+        self.update(ticket_id, |item| {
+            item.complete(review, now)?;
+            Ok(item.clone())
+        })
+    }
+
+    fn record_appeal_outcome(&self, ticket_id: &str, appeal: HitlAppealRecord) -> Result<(), HitlQueueError> {
+        self.update(ticket_id, |item| {
+            item.appeals.push(appeal);
+            Ok(())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use chrono::Duration;
+    use uuid::Uuid;
+
+    use super::*;
+    use super::super::queue::tests::{review, reviewer, shard_at};
+    use crate::audit::{context::AuditContext, event::AuditKind, sink::InMemoryAuditSink};
+    use crate::governance_hooks::AppealDecision;
+    use crate::policy::nist_80053::Sp80053Impact;
+    use crate::routing_policy::{RouteDecision, RoutingContext, HITL_ENQUEUE_FAILED};
+    use crate::web5_integration::vc::OperatorRole;
+
+    struct TempDb(PathBuf);
+
+    impl TempDb {
+        fn new() -> Self {
+            Self(std::env::temp_dir().join(format!("ecoinfra-hitl-{}.sqlite", Uuid::new_v4())))
+        }
+    }
+
+    impl Drop for TempDb {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.0);
+        }
+    }
+
+    fn enqueue(queue: &SqliteHitlQueue, impact: Sp80053Impact, now: DateTime<Utc>) -> String {
+        queue
+            .enqueue(shard_at(impact), 0.5, DecisionTrace::new("test"), now)
+            .unwrap()
+            .ticket_id
+    }
+
+    #[test]
+    fn tickets_survive_a_reopen() {
+        let db = TempDb::new();
+        let now = Utc::now() - Duration::days(2);
+        let did = "did:web:reviewer.example";
+        let (low, high) = {
+            let queue = SqliteHitlQueue::open(&db.0, HitlQueueConfig::default()).unwrap();
+            let low = enqueue(&queue, Sp80053Impact::Low, now);
+            let high = enqueue(&queue, Sp80053Impact::High, now);
+            queue.claim(&high, &reviewer(did, OperatorRole::HitlReviewer), now).unwrap();
+            (low, high)
+        };
+
+        let queue = SqliteHitlQueue::open(&db.0, HitlQueueConfig::default()).unwrap();
+        let pending: Vec<String> = queue.pending().unwrap().into_iter().map(|i| i.ticket.ticket_id).collect();
+        assert_eq!(pending, [high.clone(), low]);
+        assert_eq!(queue.get(&high).unwrap().unwrap().claimant(now), Some(did));
+
+        let ctx = AuditContext::new(Uuid::nil());
+        let mut sink = InMemoryAuditSink::new();
+        queue
+            .complete(&high, review(did, AppealDecision::Approved), now, &ctx, &mut sink)
+            .unwrap();
+        drop(queue);
+        let queue = SqliteHitlQueue::open(&db.0, HitlQueueConfig::default()).unwrap();
+        assert!(queue.get(&high).unwrap().unwrap().is_decided());
+        assert_eq!(queue.pending().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn claim_next_hands_out_each_ticket_once() {
+        let db = TempDb::new();
+        let config = HitlQueueConfig {
+            high_reviewer_role: OperatorRole::EthicsPanel,
+            ..HitlQueueConfig::default()
+        };
+        let first = SqliteHitlQueue::open(&db.0, config.clone()).unwrap();
+        let second = SqliteHitlQueue::open(&db.0, config).unwrap();
+        let now = Utc::now();
+        let high = enqueue(&first, Sp80053Impact::High, now);
+        let moderate = enqueue(&first, Sp80053Impact::Moderate, now);
+        let low = enqueue(&first, Sp80053Impact::Low, now);
+        let a = reviewer("did:web:a.example", OperatorRole::HitlReviewer);
+        let b = reviewer("did:web:b.example", OperatorRole::HitlReviewer);
+
+        let claimed = first.claim_next(&a, now).unwrap().unwrap();
+        assert_eq!(claimed.ticket.ticket_id, moderate);
+        let claimed = second.claim_next(&b, now).unwrap().unwrap();
+        assert_eq!(claimed.ticket.ticket_id, low);
+        assert!(first.claim_next(&b, now).unwrap().is_none());
+        assert_eq!(first.get(&high).unwrap().unwrap().claimant(now), None);
+    }
+
+    #[test]
+    fn claim_next_skips_expired_credentials() {
+        let queue = SqliteHitlQueue::open_in_memory(HitlQueueConfig::default()).unwrap();
+        let now = Utc::now();
+        let low = enqueue(&queue, Sp80053Impact::Low, now);
+        let mut expired = reviewer("did:web:a.example", OperatorRole::HitlReviewer);
+        expired.valid_until = Some(now - Duration::minutes(1));
+
+        assert!(queue.claim_next(&expired, now).unwrap().is_none());
+        assert_eq!(queue.get(&low).unwrap().unwrap().claimant(now), None);
+        let err = queue.claim(&low, &expired, now).unwrap_err();
+        assert_eq!(err.code(), "reviewer_credential_not_valid");
+    }
+
+    #[test]
+    fn routing_fails_closed_when_the_queue_cannot_store_a_ticket() {
+        let queue = SqliteHitlQueue::open_in_memory(HitlQueueConfig::default()).unwrap();
+        queue
+            .conn
+            .lock()
+            .unwrap()
+            .execute_batch("DROP TABLE hitl_tickets")
+            .unwrap();
+        let ctx = AuditContext::new(Uuid::nil());
+        let mut sink = InMemoryAuditSink::new();
+
+        let (decision, trace) = RoutingContext { hooks: &queue }.decide_explained(
+            &shard_at(Sp80053Impact::High),
+            0.5,
+            &ctx,
+            &mut sink,
+        );
+        assert!(matches!(decision, RouteDecision::Reject(HITL_ENQUEUE_FAILED)));
+        assert_eq!(trace.outcome, HITL_ENQUEUE_FAILED);
+        let [event] = sink.events.as_slice() else {
+            panic!("expected one event, got {:?}", sink.events);
+        };
+        assert!(matches!(&event.kind, AuditKind::RoutingDenied { reason } if reason == HITL_ENQUEUE_FAILED));
+    }
+}
@@ -0,0 +1,332 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::audit::{
+    context::AuditContext,
+    event::{AuditEvent, AuditSubject},
+    sink::AuditSink,
+    trace::DecisionTrace,
+};
+use crate::governance_hooks::AppealDecision;
+use crate::policy::nist_80053::Sp80053Impact;
+
+/// Why a subject was gated for human review.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Error)]
+pub enum HitlReason {
+    #[error("risk of harm would rise from {roh_before} to {roh_after}")]
+    HarmfulDelta { roh_before: f64, roh_after: f64 },
+    #[error("human in the loop required at {impact:?} impact")]
+    HumanInLoopRequired { impact: Sp80053Impact },
+}
+
+impl HitlReason {
+    pub fn code(&self) -> &'static str {
+        match self {
+            HitlReason::HarmfulDelta { .. } => "harmful_roh_delta",
+            HitlReason::HumanInLoopRequired { .. } => "human_in_loop_required",
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum HitlError {
+    #[error("reviewer identifier `{0}` is not a DID")]
+    InvalidReviewerDid(String),
+    #[error("review rationale must not be empty")]
+    MissingRationale,
+    #[error("DWN hexstamp `{0}` is not a non-empty hex string")]
+    InvalidHexstamp(String),
+}
+
+/// Record of a human review, kept alongside the subject in every state
+/// after `PendingReview`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HumanReview {
+    pub reviewer_did: String,
+    pub reviewed_at: DateTime<Utc>,
+    pub decision: AppealDecision,
+    pub rationale: String,
+    /// donutloop / DWN record hexstamp anchoring this review.
+    pub dwn_hexstamp: String,
+}
+
+/// A subject gated for human review. The only way out is `review`, so an
+/// `Executed<T>` cannot be reached without a recorded human decision.
+/// Pending reviews are only minted by `GovernanceGuard`:
+///
+/// ```compile_fail,E0624
+/// use ecoinfra_governance::audit::event::AuditSubject;
+/// use ecoinfra_governance::hitl::{HitlReason, PendingReview};
+/// use ecoinfra_governance::policy::nist_80053::Sp80053Impact;
+///
+/// let reason = HitlReason::HumanInLoopRequired { impact: Sp80053Impact::High };
+/// let _ = PendingReview::new(42u8, reason, AuditSubject::default());
+/// ```
+#[derive(Debug, Clone)]
+pub struct PendingReview<T> {
+    subject: T,
+    reason: HitlReason,
+    audit_subject: AuditSubject,
+    enqueued_at: DateTime<Utc>,
+    trace: Option<DecisionTrace>,
+}
+
+/// Approved (or approved with modifications) by a named human reviewer.
+#[derive(Debug, Clone)]
+pub struct ApprovedByHuman<T> {
+    subject: T,
+    review: HumanReview,
+}
+
+/// Rejected by a named human reviewer; the subject can only be inspected.
+#[derive(Debug, Clone)]
+pub struct RejectedByHuman<T> {
+    subject: T,
+    review: HumanReview,
+}
+
+/// Handed to the actuation layer after human approval. Neither a pending
+/// review nor a rejection can be executed:
+///
+/// ```compile_fail,E0599
+/// use ecoinfra_governance::hitl::{Executed, PendingReview};
+///
+/// fn skip_review(pending: PendingReview<u8>) -> Executed<u8> {
+///     pending.execute()
+/// }
+/// ```
+///
+/// ```compile_fail,E0599
+/// use ecoinfra_governance::hitl::{Executed, RejectedByHuman};
+///
+/// fn overrule(rejected: RejectedByHuman<u8>) -> Executed<u8> {
+///     rejected.execute()
+/// }
+/// ```
+///
+/// ```compile_fail,E0451
+/// use chrono::Utc;
+/// use ecoinfra_governance::hitl::{Executed, HumanReview};
+///
+/// fn forge(subject: u8, review: HumanReview) -> Executed<u8> {
+///     Executed { subject, review, executed_at: Utc::now() }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Executed<T> {
+    subject: T,
+    review: HumanReview,
+    executed_at: DateTime<Utc>,
+}
+
+#[derive(Debug)]
+pub enum ReviewOutcome<T> {
+    Approved(ApprovedByHuman<T>),
+    Rejected(RejectedByHuman<T>),
+}
+
+impl<T> PendingReview<T> {
+    pub(crate) fn new(subject: T, reason: HitlReason, audit_subject: AuditSubject) -> Self {
+        Self {
+            subject,
+            reason,
+            audit_subject,
+            enqueued_at: Utc::now(),
+            trace: None,
+        }
+    }
+
+    /// Attach the explanation of why the subject was gated.
+    pub fn with_trace(mut self, trace: DecisionTrace) -> Self {
+        self.trace = Some(trace);
+        self
+    }
+
+    pub fn subject(&self) -> &T {
+        &self.subject
+    }
+
+    pub fn reason(&self) -> &HitlReason {
+        &self.reason
+    }
+
+    pub fn enqueued_at(&self) -> DateTime<Utc> {
+        self.enqueued_at
+    }
+
+    pub fn trace(&self) -> Option<&DecisionTrace> {
+        self.trace.as_ref()
+    }
+
+    /// Record the human decision. `Approved` and `Modified` both lead to
+    /// `ApprovedByHuman`; for `Modified` the rationale must describe the
+    /// amendment. Emits `HumanApproved` or `HumanRejected`.
+    pub fn review<P: AuditSink>(
+        self,
+        reviewer_did: &str,
+        decision: AppealDecision,
+        rationale: &str,
+        dwn_hexstamp: &str,
+        ctx: &AuditContext,
+        audit_sink: &mut P,
+    ) -> Result<ReviewOutcome<T>, HitlError> {
+        if !reviewer_did.starts_with("did:") {
+            return Err(HitlError::InvalidReviewerDid(reviewer_did.to_string()));
+        }
+        if rationale.trim().is_empty() {
+            return Err(HitlError::MissingRationale);
+        }
+        if dwn_hexstamp.is_empty() || !dwn_hexstamp.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(HitlError::InvalidHexstamp(dwn_hexstamp.to_string()));
+        }
+
+        let review = HumanReview {
+            reviewer_did: reviewer_did.to_string(),
+            reviewed_at: Utc::now(),
+            decision,
+            rationale: rationale.to_string(),
+            dwn_hexstamp: dwn_hexstamp.to_string(),
+        };
+        audit_sink.record(AuditEvent::human_review(ctx, &review).with_subject(self.audit_subject));
+
+        Ok(match review.decision {
+            AppealDecision::Approved | AppealDecision::Modified => {
+                ReviewOutcome::Approved(ApprovedByHuman {
+                    subject: self.subject,
+                    review,
+                })
+            }
+            AppealDecision::Rejected => ReviewOutcome::Rejected(RejectedByHuman {
+                subject: self.subject,
+                review,
+            }),
+        })
+    }
+}
+
+impl<T> ApprovedByHuman<T> {
+    pub fn subject(&self) -> &T {
+        &self.subject
+    }
+
+    pub fn review(&self) -> &HumanReview {
+        &self.review
+    }
+
+    pub fn execute(self) -> Executed<T> {
+        Executed {
+            subject: self.subject,
+            review: self.review,
+            executed_at: Utc::now(),
+        }
+    }
+}
+
+impl<T> RejectedByHuman<T> {
+    pub fn subject(&self) -> &T {
+        &self.subject
+    }
+
+    pub fn review(&self) -> &HumanReview {
+        &self.review
+    }
+
+    pub fn into_inner(self) -> (T, HumanReview) {
+        (self.subject, self.review)
+    }
+}
+
+impl<T> Executed<T> {
+    pub fn subject(&self) -> &T {
+        &self.subject
+    }
+
+    pub fn review(&self) -> &HumanReview {
+        &self.review
+    }
+
+    pub fn executed_at(&self) -> DateTime<Utc> {
+        self.executed_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::audit::{event::AuditKind, sink::InMemoryAuditSink};
+
+    fn pending() -> PendingReview<&'static str> {
+        PendingReview::new(
+            "route",
+            HitlReason::HumanInLoopRequired {
+                impact: Sp80053Impact::High,
+            },
+            AuditSubject::polygon(Uuid::nil()),
+        )
+    }
+
+    fn review(
+        pending: PendingReview<&'static str>,
+        reviewer_did: &str,
+        decision: AppealDecision,
+        rationale: &str,
+        dwn_hexstamp: &str,
+    ) -> (Result<ReviewOutcome<&'static str>, HitlError>, InMemoryAuditSink) {
+        let mut sink = InMemoryAuditSink::new();
+        let ctx = AuditContext::new(Uuid::nil());
+        let outcome = pending.review(reviewer_did, decision, rationale, dwn_hexstamp, &ctx, &mut sink);
+        (outcome, sink)
+    }
+
+    #[test]
+    fn review_rejects_non_did_reviewer() {
+        let (outcome, sink) = review(pending(), "alice", AppealDecision::Approved, "ok", "ab12");
+        assert!(matches!(outcome, Err(HitlError::InvalidReviewerDid(id)) if id == "alice"));
+        assert!(sink.events.is_empty());
+    }
+
+    #[test]
+    fn review_rejects_blank_rationale() {
+        let (outcome, _) = review(pending(), "did:ex:alice", AppealDecision::Approved, "  ", "ab12");
+        assert!(matches!(outcome, Err(HitlError::MissingRationale)));
+    }
+
+    #[test]
+    fn review_rejects_empty_or_non_hex_hexstamp() {
+        for stamp in ["", "0xab12", "zz"] {
+            let (outcome, _) = review(pending(), "did:ex:alice", AppealDecision::Approved, "ok", stamp);
+            assert!(matches!(outcome, Err(HitlError::InvalidHexstamp(s)) if s == stamp));
+        }
+    }
+
+    #[test]
+    fn approval_and_modification_lead_to_execution() {
+        for decision in [AppealDecision::Approved, AppealDecision::Modified] {
+            let (outcome, sink) = review(pending(), "did:ex:alice", decision, "checked the corridor", "AB12cd");
+            let Ok(ReviewOutcome::Approved(approved)) = outcome else {
+                panic!("expected approval for {decision:?}");
+            };
+            assert!(matches!(&sink.events[0].kind, AuditKind::HumanApproved { decision: d, .. } if *d == decision));
+            assert_eq!(sink.events[0].subject, AuditSubject::polygon(Uuid::nil()));
+            let executed = approved.execute();
+            assert_eq!(*executed.subject(), "route");
+            assert_eq!(executed.review().reviewer_did, "did:ex:alice");
+            assert_eq!(executed.review().decision, decision);
+        }
+    }
+
+    #[test]
+    fn rejection_keeps_subject_and_review() {
+        let (outcome, sink) = review(pending(), "did:ex:alice", AppealDecision::Rejected, "too hot", "ab12");
+        let Ok(ReviewOutcome::Rejected(rejected)) = outcome else {
+            panic!("expected rejection");
+        };
+        assert!(matches!(sink.events[0].kind, AuditKind::HumanRejected { .. }));
+        let (subject, review) = rejected.into_inner();
+        assert_eq!(subject, "route");
+        assert_eq!(review.rationale, "too hot");
+    }
+}
@@ -3,6 +3,14 @@ pub mod aln;
 pub mod policy;
 pub mod audit;
 pub mod enclave;
+pub mod governance_guard;
+pub mod governance_hooks;
+pub mod routing_policy;
+pub mod hitl;
+pub mod appeal;
+pub mod web5_integration;
+pub mod aln_shard;
+pub mod canonical;
 
 // High-level invariant marker for the entire crate.
 pub trait InvariantGovernance: Sized {}
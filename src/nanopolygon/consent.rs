@@ -0,0 +1,621 @@
+use std::collections::{BTreeSet, HashMap};
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::audit::{
+    context::AuditContext,
+    event::{AuditEvent, AuditKind, AuditSubject},
+    sink::AuditSink,
+};
+use crate::canonical::{to_canonical_bytes, CanonicalError};
+use crate::web5_integration::multisig::{MultisigApproval, MultisigError, MultisigPolicy};
+use crate::web5_integration::pqc::{SignatureBlock, SignatureError, Signer, Verifier};
+use crate::web5_integration::vc::OperatorCredential;
+use super::types::NanopolygonSafetyObject;
+
+/// Which layer of a `ConsentRequirement` a record speaks for.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum ConsentLayer {
+    /// Signed by the polygon's community authority (`data_owner_did`).
+    Community,
+    /// Signed by an affected individual's own DID.
+    Individual,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ConsentAction {
+    Grant,
+    Revoke,
+}
+
+/// A signed consent grant or revocation for one polygon and action scope.
+/// The grantor is `signature.signer_did`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConsentRecord {
+    pub record_id: Uuid,
+    pub polygon_id: Uuid,
+    /// Action scope, e.g. "aln_routing" or "actuation:cooling".
+    pub scope: String,
+    pub layer: ConsentLayer,
+    pub action: ConsentAction,
+    pub issued_at: DateTime<Utc>,
+    /// Grants only; `None` means until revoked.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<DateTime<Utc>>,
+    pub signature: SignatureBlock,
+}
+
+#[derive(Serialize)]
+struct SignedConsent<'a> {
+    record_id: Uuid,
+    polygon_id: Uuid,
+    scope: &'a str,
+    layer: ConsentLayer,
+    action: ConsentAction,
+    issued_at: DateTime<Utc>,
+    expires_at: Option<DateTime<Utc>>,
+}
+
+impl ConsentRecord {
+    pub fn grant<S: Signer>(
+        polygon_id: Uuid,
+        scope: &str,
+        layer: ConsentLayer,
+        expires_at: Option<DateTime<Utc>>,
+        signer: &S,
+    ) -> Result<Self, SignatureError> {
+        Self::sign(polygon_id, scope, layer, ConsentAction::Grant, expires_at, signer)
+    }
+
+    /// Withdraws every earlier grant by the same signer for this polygon,
+    /// scope and layer.
+    pub fn revoke<S: Signer>(
+        polygon_id: Uuid,
+        scope: &str,
+        layer: ConsentLayer,
+        signer: &S,
+    ) -> Result<Self, SignatureError> {
+        Self::sign(polygon_id, scope, layer, ConsentAction::Revoke, None, signer)
+    }
+
+    fn sign<S: Signer>(
+        polygon_id: Uuid,
+        scope: &str,
+        layer: ConsentLayer,
+        action: ConsentAction,
+        expires_at: Option<DateTime<Utc>>,
+        signer: &S,
+    ) -> Result<Self, SignatureError> {
+        let mut record = Self {
+            record_id: Uuid::new_v4(),
+            polygon_id,
+            scope: scope.to_string(),
+            layer,
+            action,
+            issued_at: Utc::now(),
+            expires_at,
+            signature: SignatureBlock {
+                signer_did: signer.did().to_string(),
+                algorithm: signer.algorithm(),
+                signature: Vec::new(),
+            },
+        };
+        record.signature = SignatureBlock::sign(signer, &record.signing_payload()?)?;
+        Ok(record)
+    }
+
+    pub fn grantor_did(&self) -> &str {
+        &self.signature.signer_did
+    }
+
+    pub fn verify<V: Verifier>(&self, verifier: &V) -> Result<(), SignatureError> {
+        self.signature.verify(verifier, &self.signing_payload()?)
+    }
+
+    fn signing_payload(&self) -> Result<Vec<u8>, CanonicalError> {
+        to_canonical_bytes(&SignedConsent {
+            record_id: self.record_id,
+            polygon_id: self.polygon_id,
+            scope: &self.scope,
+            layer: self.layer,
+            action: self.action,
+            issued_at: self.issued_at,
+            expires_at: self.expires_at,
+        })
+    }
+}
+
+/// A community k‑of‑n approval of consent to `scope` on one polygon. The
+/// approved action is the canonical JSON of `{polygon_id, scope}`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommunityApproval {
+    pub polygon_id: Uuid,
+    pub scope: String,
+    pub approval: MultisigApproval,
+}
+
+#[derive(Serialize)]
+struct ApprovedConsent<'a> {
+    polygon_id: Uuid,
+    scope: &'a str,
+}
+
+impl CommunityApproval {
+    /// Starts an unsigned approval under `policy`; signers add their
+    /// partial signatures with `approval.sign`.
+    pub fn request(
+        policy: &MultisigPolicy,
+        polygon_id: Uuid,
+        scope: &str,
+        expires_at: DateTime<Utc>,
+    ) -> Result<Self, MultisigError> {
+        let approval = policy.request(&ApprovedConsent { polygon_id, scope }, expires_at)?;
+        Ok(Self {
+            polygon_id,
+            scope: scope.to_string(),
+            approval,
+        })
+    }
+
+    /// Verifies the approval under `policy` at `now`; returns the DIDs that
+    /// approved.
+    pub fn verify<V: Verifier>(
+        &self,
+        policy: &MultisigPolicy,
+        verifier: &V,
+        credentials: &[OperatorCredential],
+        now: DateTime<Utc>,
+    ) -> Result<Vec<String>, MultisigError> {
+        let action = ApprovedConsent {
+            polygon_id: self.polygon_id,
+            scope: &self.scope,
+        };
+        policy.verify(&self.approval, &action, verifier, credentials, now)
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ConsentLedgerError {
+    #[error("consent record signature rejected: {0}")]
+    Signature(#[from] SignatureError),
+    #[error("consent scope must not be empty")]
+    EmptyScope,
+    #[error("consent grant expires ({expires_at}) before it was issued ({issued_at})")]
+    ExpiresBeforeIssued {
+        issued_at: DateTime<Utc>,
+        expires_at: DateTime<Utc>,
+    },
+    #[error("consent record issued at {issued_at}, after the ledger's time {now} plus skew")]
+    IssuedInFuture {
+        issued_at: DateTime<Utc>,
+        now: DateTime<Utc>,
+    },
+    #[error("consent record {0} is already in the ledger")]
+    DuplicateRecord(Uuid),
+    #[error("no community multisig policy registered for polygon {0}")]
+    NoCommunityPolicy(Uuid),
+    #[error("community approval rejected: {0}")]
+    Multisig(#[from] MultisigError),
+}
+
+/// Why an action does not currently satisfy a polygon's consent
+/// requirement.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ConsentDenial {
+    #[error("community consent from {authority_did} missing for scope `{scope}`")]
+    CommunityConsentMissing { authority_did: String, scope: String },
+    #[error("no unexpired community multisig approval for scope `{0}`")]
+    CommunityMultisigMissing(String),
+    #[error("individual consent required for scope `{0}` but no affected individual was named")]
+    NoIndividualsNamed(String),
+    #[error("individual consent missing for scope `{scope}` from {missing:?}")]
+    IndividualConsentMissing { scope: String, missing: Vec<String> },
+}
+
+impl ConsentDenial {
+    pub fn code(&self) -> &'static str {
+        match self {
+            ConsentDenial::CommunityConsentMissing { .. } => "community_consent_missing",
+            ConsentDenial::CommunityMultisigMissing(_) => "community_multisig_missing",
+            ConsentDenial::NoIndividualsNamed(_) => "no_individuals_named",
+            ConsentDenial::IndividualConsentMissing { .. } => "individual_consent_missing",
+        }
+    }
+}
+
+/// Tolerated lead of a record's `issued_at` over the ledger clock.
+pub const DEFAULT_CONSENT_CLOCK_SKEW_SECS: i64 = 2 * 60;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RecordedApproval {
+    recorded_at: DateTime<Utc>,
+    approval: CommunityApproval,
+}
+
+/// Append-only store of signed consent grants and revocations, keyed by
+/// polygon. Answers whether an action scope currently satisfies a polygon's
+/// `ConsentRequirement`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ConsentLedger {
+    records: HashMap<Uuid, Vec<ConsentRecord>>,
+    /// Per-polygon k‑of‑n policy that community consent must satisfy.
+    #[serde(default)]
+    community_policies: HashMap<Uuid, MultisigPolicy>,
+    #[serde(default)]
+    community_approvals: HashMap<Uuid, Vec<RecordedApproval>>,
+    #[serde(default = "default_clock_skew_secs")]
+    clock_skew_secs: i64,
+}
+
+fn default_clock_skew_secs() -> i64 {
+    DEFAULT_CONSENT_CLOCK_SKEW_SECS
+}
+
+impl Default for ConsentLedger {
+    fn default() -> Self {
+        Self {
+            records: HashMap::new(),
+            community_policies: HashMap::new(),
+            community_approvals: HashMap::new(),
+            clock_skew_secs: DEFAULT_CONSENT_CLOCK_SKEW_SECS,
+        }
+    }
+}
+
+impl ConsentLedger {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the multisig policy whose approval the polygon's community
+    /// layer requires.
+    pub fn with_community_policy(mut self, polygon_id: Uuid, policy: MultisigPolicy) -> Result<Self, MultisigError> {
+        policy.validate()?;
+        self.community_policies.insert(polygon_id, policy);
+        Ok(self)
+    }
+
+    pub fn with_clock_skew(mut self, clock_skew: Duration) -> Self {
+        self.clock_skew_secs = clock_skew.num_seconds();
+        self
+    }
+
+    pub fn clock_skew(&self) -> Duration {
+        Duration::try_seconds(self.clock_skew_secs).unwrap_or(Duration::MAX)
+    }
+
+    pub fn community_policy(&self, polygon_id: Uuid) -> Option<&MultisigPolicy> {
+        self.community_policies.get(&polygon_id)
+    }
+
+    /// Verifies and appends a record at ledger time `now`, emitting
+    /// `ConsentGranted` or `ConsentRevoked` (or
+    /// `SignatureVerificationFailed`). `issued_at` is signer-chosen, so it
+    /// may not lead `now` by more than the clock skew.
+    pub fn record<V: Verifier, P: AuditSink>(
+        &mut self,
+        record: ConsentRecord,
+        verifier: &V,
+        now: DateTime<Utc>,
+        ctx: &AuditContext,
+        audit_sink: &mut P,
+    ) -> Result<(), ConsentLedgerError> {
+        let subject = AuditSubject::polygon(record.polygon_id);
+
+        if let Err(e) = record.verify(verifier) {
+            audit_sink.record(
+                AuditEvent::signature_verification_failed(ctx, record.grantor_did(), &e.to_string())
+                    .with_subject(subject),
+            );
+            return Err(e.into());
+        }
+        if record.scope.trim().is_empty() {
+            return Err(ConsentLedgerError::EmptyScope);
+        }
+        if record.issued_at - now > self.clock_skew() {
+            return Err(ConsentLedgerError::IssuedInFuture {
+                issued_at: record.issued_at,
+                now,
+            });
+        }
+        if let Some(expires_at) = record.expires_at {
+            if expires_at <= record.issued_at {
+                return Err(ConsentLedgerError::ExpiresBeforeIssued {
+                    issued_at: record.issued_at,
+                    expires_at,
+                });
+            }
+        }
+        if self.records(record.polygon_id).iter().any(|r| r.record_id == record.record_id) {
+            return Err(ConsentLedgerError::DuplicateRecord(record.record_id));
+        }
+
+        let grantor_did = record.grantor_did().to_string();
+        let scope = record.scope.clone();
+        let kind = match record.action {
+            ConsentAction::Grant => AuditKind::ConsentGranted { grantor_did, scope },
+            ConsentAction::Revoke => AuditKind::ConsentRevoked { grantor_did, scope },
+        };
+        audit_sink.record(AuditEvent::new(ctx, kind).with_subject(subject));
+        self.records.entry(record.polygon_id).or_default().push(record);
+        Ok(())
+    }
+
+    /// Verifies a community approval against the polygon's registered
+    /// policy at `now` and appends it, emitting `ConsentGranted` for each
+    /// approving DID (or `SignatureVerificationFailed` naming the policy).
+    /// `credentials` must already be verified; they resolve role signers.
+    pub fn record_community_approval<V: Verifier, P: AuditSink>(
+        &mut self,
+        approval: CommunityApproval,
+        verifier: &V,
+        credentials: &[OperatorCredential],
+        now: DateTime<Utc>,
+        ctx: &AuditContext,
+        audit_sink: &mut P,
+    ) -> Result<Vec<String>, ConsentLedgerError> {
+        let subject = AuditSubject::polygon(approval.polygon_id);
+        let policy = self
+            .community_policy(approval.polygon_id)
+            .ok_or(ConsentLedgerError::NoCommunityPolicy(approval.polygon_id))?;
+
+        let approved = match approval.verify(policy, verifier, credentials, now) {
+            Ok(approved) => approved,
+            Err(e) => {
+                audit_sink.record(
+                    AuditEvent::signature_verification_failed(ctx, &policy.policy_id, &e.to_string())
+                        .with_subject(subject),
+                );
+                return Err(e.into());
+            }
+        };
+
+        for grantor_did in &approved {
+            audit_sink.record(
+                AuditEvent::new(ctx, AuditKind::ConsentGranted {
+                    grantor_did: grantor_did.clone(),
+                    scope: approval.scope.clone(),
+                })
+                .with_subject(subject),
+            );
+        }
+        self.community_approvals
+            .entry(approval.polygon_id)
+            .or_default()
+            .push(RecordedApproval { recorded_at: now, approval });
+        Ok(approved)
+    }
+
+    /// Every record for the polygon, in the order it was recorded.
+    pub fn records(&self, polygon_id: Uuid) -> &[ConsentRecord] {
+        self.records.get(&polygon_id).map_or(&[], Vec::as_slice)
+    }
+
+    /// DIDs holding an unexpired, unrevoked grant for `scope` at `at`. Each
+    /// signer's last-appended record issued at or before `at` decides.
+    pub fn active_grantors(
+        &self,
+        polygon_id: Uuid,
+        scope: &str,
+        layer: ConsentLayer,
+        at: DateTime<Utc>,
+    ) -> BTreeSet<&str> {
+        let mut latest: HashMap<&str, &ConsentRecord> = HashMap::new();
+        for record in self.records(polygon_id) {
+            if record.scope != scope || record.layer != layer || record.issued_at > at {
+                continue;
+            }
+            latest.insert(record.grantor_did(), record);
+        }
+        latest
+            .into_iter()
+            .filter(|(_, r)| r.action == ConsentAction::Grant)
+            .filter(|(_, r)| r.expires_at.is_none_or(|e| at < e))
+            .map(|(did, _)| did)
+            .collect()
+    }
+
+    /// Whether a community approval for `scope` was recorded by `at` and
+    /// has not expired.
+    pub fn community_approved(&self, polygon_id: Uuid, scope: &str, at: DateTime<Utc>) -> bool {
+        self.community_approvals
+            .get(&polygon_id)
+            .map_or(&[][..], Vec::as_slice)
+            .iter()
+            .any(|r| r.approval.scope == scope && r.recorded_at <= at && at < r.approval.approval.expires_at)
+    }
+
+    /// Does `scope` satisfy `np`'s consent requirement at `at`? Community
+    /// consent needs a grant from the polygon's `data_owner_did` and a
+    /// recorded k‑of‑n `CommunityApproval`; individual consent needs a
+    /// grant from every DID in `individuals`.
+    pub fn check(
+        &self,
+        np: &NanopolygonSafetyObject,
+        scope: &str,
+        individuals: &[&str],
+        at: DateTime<Utc>,
+    ) -> Result<(), ConsentDenial> {
+        let requirement = np.rights.neurorights.consent_requirement;
+
+        if requirement.requires_community() {
+            let authority = np.metadata.data_owner_did.as_str();
+            let community = self.active_grantors(np.polygon_id, scope, ConsentLayer::Community, at);
+            if !community.contains(authority) {
+                return Err(ConsentDenial::CommunityConsentMissing {
+                    authority_did: authority.to_string(),
+                    scope: scope.to_string(),
+                });
+            }
+            if !self.community_approved(np.polygon_id, scope, at) {
+                return Err(ConsentDenial::CommunityMultisigMissing(scope.to_string()));
+            }
+        }
+
+        if requirement.requires_individual() {
+            if individuals.is_empty() {
+                return Err(ConsentDenial::NoIndividualsNamed(scope.to_string()));
+            }
+            let granted = self.active_grantors(np.polygon_id, scope, ConsentLayer::Individual, at);
+            let missing: Vec<String> = individuals
+                .iter()
+                .filter(|did| !granted.contains(**did))
+                .map(|did| did.to_string())
+                .collect();
+            if !missing.is_empty() {
+                return Err(ConsentDenial::IndividualConsentMissing {
+                    scope: scope.to_string(),
+                    missing,
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "ml-dsa"))]
+mod tests {
+    use super::*;
+    use crate::audit::event::AuditOutcome;
+    use crate::audit::sink::InMemoryAuditSink;
+    use crate::nanopolygon::fixtures::nanopolygon;
+    use crate::nanopolygon::neurorights::ConsentRequirement;
+    use crate::web5_integration::did::{DidKeyPair, DidKeyVerifier};
+    use crate::web5_integration::multisig::EligibleSigner;
+
+    const SCOPE: &str = "aln_routing";
+
+    fn key(n: u8) -> DidKeyPair {
+        DidKeyPair::from_seed(&[n; 32])
+    }
+
+    fn polygon(owner: &DidKeyPair) -> NanopolygonSafetyObject {
+        let mut np = nanopolygon();
+        np.rights.neurorights.consent_requirement = ConsentRequirement::CommunityMultisigRequired;
+        np.metadata.data_owner_did = owner.did.clone();
+        np
+    }
+
+    fn policy() -> MultisigPolicy {
+        MultisigPolicy {
+            policy_id: "council-2-of-3".to_string(),
+            signers: (0x51..=0x53).map(|n| EligibleSigner::Did(key(n).did)).collect(),
+            threshold: 2,
+        }
+    }
+
+    /// Re-signs `record` as if it had been issued at `issued_at`.
+    fn reissued(mut record: ConsentRecord, issued_at: DateTime<Utc>, signer: &DidKeyPair) -> ConsentRecord {
+        record.issued_at = issued_at;
+        record.signature = SignatureBlock::sign(signer, &record.signing_payload().unwrap()).unwrap();
+        record
+    }
+
+    #[test]
+    fn owner_grant_alone_does_not_satisfy_community_multisig() {
+        let owner = key(0x50);
+        let np = polygon(&owner);
+        let ctx = AuditContext::new(Uuid::nil());
+        let mut sink = InMemoryAuditSink::new();
+        let mut ledger = ConsentLedger::new()
+            .with_community_policy(np.polygon_id, policy())
+            .unwrap();
+        let now = Utc::now();
+
+        let grant = ConsentRecord::grant(np.polygon_id, SCOPE, ConsentLayer::Community, None, &owner).unwrap();
+        let grant = reissued(grant, now - Duration::minutes(5), &owner);
+        ledger.record(grant, &DidKeyVerifier, now, &ctx, &mut sink).unwrap();
+        let denial = ledger.check(&np, SCOPE, &[], now).unwrap_err();
+        assert_eq!(denial.code(), "community_multisig_missing");
+
+        let mut approval = CommunityApproval::request(&policy(), np.polygon_id, SCOPE, now + Duration::hours(1)).unwrap();
+        approval.approval.sign(&key(0x51)).unwrap();
+        approval.approval.sign(&key(0x51)).unwrap();
+        let err = ledger
+            .record_community_approval(approval.clone(), &DidKeyVerifier, &[], now, &ctx, &mut sink)
+            .unwrap_err();
+        assert!(matches!(err, ConsentLedgerError::Multisig(MultisigError::ThresholdNotMet { required: 2, .. })));
+        assert_eq!(sink.events.last().unwrap().outcome, AuditOutcome::Failure);
+
+        approval.approval.sign(&key(0x53)).unwrap();
+        let approved = ledger
+            .record_community_approval(approval, &DidKeyVerifier, &[], now, &ctx, &mut sink)
+            .unwrap();
+        assert_eq!(approved.len(), 2);
+        ledger.check(&np, SCOPE, &[], now).unwrap();
+        assert!(ledger.check(&np, "actuation:cooling", &[], now).is_err());
+        assert_eq!(
+            ledger.check(&np, SCOPE, &[], now + Duration::hours(2)).unwrap_err().code(),
+            "community_multisig_missing"
+        );
+        assert!(ledger.check(&np, SCOPE, &[], now - Duration::minutes(1)).is_err());
+    }
+
+    #[test]
+    fn approvals_need_a_registered_policy_and_the_right_scope() {
+        let np = polygon(&key(0x50));
+        let ctx = AuditContext::new(Uuid::nil());
+        let mut sink = InMemoryAuditSink::new();
+        let now = Utc::now();
+        let mut approval = CommunityApproval::request(&policy(), np.polygon_id, SCOPE, now + Duration::hours(1)).unwrap();
+        approval.approval.sign(&key(0x51)).unwrap();
+        approval.approval.sign(&key(0x52)).unwrap();
+
+        let mut ledger = ConsentLedger::new();
+        let err = ledger
+            .record_community_approval(approval.clone(), &DidKeyVerifier, &[], now, &ctx, &mut sink)
+            .unwrap_err();
+        assert!(matches!(err, ConsentLedgerError::NoCommunityPolicy(_)));
+
+        let mut ledger = ledger.with_community_policy(np.polygon_id, policy()).unwrap();
+        approval.scope = "actuation:cooling".to_string();
+        let err = ledger
+            .record_community_approval(approval, &DidKeyVerifier, &[], now, &ctx, &mut sink)
+            .unwrap_err();
+        assert!(matches!(err, ConsentLedgerError::Multisig(MultisigError::DigestMismatch)));
+    }
+
+    #[test]
+    fn issued_at_may_not_lead_the_ledger_clock() {
+        let owner = key(0x50);
+        let np = polygon(&owner);
+        let ctx = AuditContext::new(Uuid::nil());
+        let mut sink = InMemoryAuditSink::new();
+        let mut ledger = ConsentLedger::new();
+        let now = Utc::now();
+
+        let grant = ConsentRecord::grant(np.polygon_id, SCOPE, ConsentLayer::Community, None, &owner).unwrap();
+        let future = reissued(grant, now + Duration::days(365), &owner);
+        let err = ledger.record(future, &DidKeyVerifier, now, &ctx, &mut sink).unwrap_err();
+        assert!(matches!(err, ConsentLedgerError::IssuedInFuture { .. }));
+
+        let grant = ConsentRecord::grant(np.polygon_id, SCOPE, ConsentLayer::Community, None, &owner).unwrap();
+        let within_skew = reissued(grant, now + Duration::seconds(30), &owner);
+        ledger.record(within_skew, &DidKeyVerifier, now, &ctx, &mut sink).unwrap();
+    }
+
+    #[test]
+    fn the_last_appended_record_decides() {
+        let owner = key(0x50);
+        let np = polygon(&owner);
+        let ctx = AuditContext::new(Uuid::nil());
+        let mut sink = InMemoryAuditSink::new();
+        let mut ledger = ConsentLedger::new();
+        let now = Utc::now();
+
+        let grant = ConsentRecord::grant(np.polygon_id, SCOPE, ConsentLayer::Community, None, &owner).unwrap();
+        let grant = reissued(grant, now - Duration::minutes(1), &owner);
+        ledger.record(grant, &DidKeyVerifier, now, &ctx, &mut sink).unwrap();
+        let revoke = ConsentRecord::revoke(np.polygon_id, SCOPE, ConsentLayer::Community, &owner).unwrap();
+        let revoke = reissued(revoke, now - Duration::minutes(2), &owner);
+        ledger.record(revoke, &DidKeyVerifier, now, &ctx, &mut sink).unwrap();
+
+        assert!(ledger
+            .active_grantors(np.polygon_id, SCOPE, ConsentLayer::Community, now)
+            .is_empty());
+    }
+}
@@ -0,0 +1,96 @@
+//! Shared test fixture: a small, valid, high-impact polygon in Phoenix.
+
+use chrono::Utc;
+use geojson::{Geometry, Value};
+use uuid::Uuid;
+
+use crate::aln::{AlnDeviceClass, AlnShard, AlnShardHeader};
+use crate::policy::{
+    ethics::EthicsProfile,
+    governance_metadata::GovernanceMetadata,
+    nist_ai_rmf::{AIRmfFunction, AIRmfProfile},
+    nist_80053::{Sp80053Family, Sp80053Impact, Sp80053Profile},
+};
+use super::neurorights::*;
+use super::types::*;
+use super::validation::ValidatedNanopolygon;
+
+/// Passes `validate` as of now; adjust fields to provoke failures.
+pub(crate) fn nanopolygon() -> NanopolygonSafetyObject {
+    NanopolygonSafetyObject {
+        polygon_id: Uuid::from_u128(0x0f1e2d3c_4b5a_6978_8796_a5b4c3d2e1f0),
+        geometry: Geometry::new(Value::Polygon(vec![vec![
+            vec![-112.074, 33.448],
+            vec![-112.072, 33.448],
+            vec![-112.072, 33.4495],
+            vec![-112.074, 33.4495],
+            vec![-112.074, 33.448],
+        ]])),
+        geo: GeoIntelligence {
+            location_band: "urban-core".to_string(),
+            hazard_level: HazardLevel::Moderate,
+            resource_stress: 0.7,
+            infrastructure_criticality: 8,
+        },
+        biospatial: BiospatialTelemetry {
+            heat_stress: 0.3,
+            pollution_exposure: 1.0,
+        },
+        learning: LearningSignal {
+            gradient_weight: 0.1,
+        },
+        intelligence: IntelligenceIndex {
+            quantified_safety_index: -0.2,
+        },
+        rights: RightsMetadata {
+            version: 1,
+            species_rights: SpeciesRightsProfile {
+                primary_species: SpeciesClass::Human,
+                recognizes_cybernetic_personhood: true,
+                territory_protection: TerritoryProtectionLevel::SanctuaryCorridor,
+                anti_discrimination_hard_floor: true,
+            },
+            neurorights: NeurorightsProfile {
+                allows_direct_neural_interfaces: false,
+                neurorights_sanctuary: true,
+                consent_requirement: ConsentRequirement::CommunityAndIndividual,
+                appeal_path: AppealPathType::EcoInfraCouncil,
+                hitl_trigger_threshold: 0.0,
+            },
+        },
+        metadata: GovernanceMetadata {
+            jurisdiction_code: "US-AZ".to_string(),
+            ai_rmf_profile: AIRmfProfile {
+                functions: AIRmfFunction::GOVERN | AIRmfFunction::MANAGE,
+                requires_human_in_loop: true,
+                requires_appeal_path: true,
+            },
+            sp80053_profile: Sp80053Profile {
+                families: Sp80053Family::AC | Sp80053Family::AU | Sp80053Family::SC,
+                impact: Sp80053Impact::High,
+            },
+            ethics_profile: EthicsProfile {
+                human_primacy: true,
+                equal_power_thresholds: true,
+                appeal_paths_available: true,
+            },
+            data_owner_did: "did:web:council.example".to_string(),
+            created_by_agent_id: Uuid::from_u128(1),
+            last_modified_by_agent_id: Uuid::from_u128(2),
+        },
+        timestamp_utc: Utc::now(),
+    }
+}
+
+/// Unsigned SCADA → supercomputer shard over `nanopolygon()`, allowed by
+/// the baseline routing policy.
+pub(crate) fn shard() -> AlnShard {
+    let header = AlnShardHeader {
+        shard_id: Uuid::from_u128(0x5a),
+        source_device_class: AlnDeviceClass::ScadaGateway,
+        target_device_class: AlnDeviceClass::SupercomputerNode,
+        segment_label: "eco-infra-routing".to_string(),
+    };
+    let np = ValidatedNanopolygon::try_from(nanopolygon()).expect("fixture is valid");
+    AlnShard::new(header, np)
+}
@@ -0,0 +1,89 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use super::types::{HazardLevel, NanopolygonSafetyObject};
+
+/// How old nanopolygon telemetry may be before it must not drive a
+/// decision. The more hazardous the polygon, the fresher the reading has to
+/// be. Durations are whole seconds so policies can live in config files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FreshnessPolicy {
+    pub low_max_age_secs: u64,
+    pub moderate_max_age_secs: u64,
+    pub high_max_age_secs: u64,
+    pub extreme_max_age_secs: u64,
+    /// Tolerated disagreement between producer and local clocks; also how
+    /// far in the future a timestamp may lie.
+    pub clock_skew_secs: u64,
+}
+
+impl Default for FreshnessPolicy {
+    fn default() -> Self {
+        Self {
+            low_max_age_secs: 24 * 3600,
+            moderate_max_age_secs: 4 * 3600,
+            high_max_age_secs: 30 * 60,
+            extreme_max_age_secs: 5 * 60,
+            clock_skew_secs: 2 * 60,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum Staleness {
+    #[error("telemetry is {}s old, limit {}s", age.num_seconds(), max_age.num_seconds())]
+    Stale { age: Duration, max_age: Duration },
+    #[error("telemetry timestamp is {}s in the future, skew tolerance {}s", ahead.num_seconds(), clock_skew.num_seconds())]
+    FromFuture { ahead: Duration, clock_skew: Duration },
+}
+
+impl Staleness {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Staleness::Stale { .. } => "stale_telemetry",
+            Staleness::FromFuture { .. } => "future_timestamp",
+        }
+    }
+}
+
+impl FreshnessPolicy {
+    pub fn max_age(&self, hazard: HazardLevel) -> Duration {
+        let secs = match hazard {
+            HazardLevel::Low => self.low_max_age_secs,
+            HazardLevel::Moderate => self.moderate_max_age_secs,
+            HazardLevel::High => self.high_max_age_secs,
+            HazardLevel::Extreme => self.extreme_max_age_secs,
+        };
+        seconds(secs)
+    }
+
+    pub fn clock_skew(&self) -> Duration {
+        seconds(self.clock_skew_secs)
+    }
+
+    /// Checks `np.timestamp_utc` against `now`. Skew is granted in both
+    /// directions.
+    pub fn check(&self, np: &NanopolygonSafetyObject, now: DateTime<Utc>) -> Result<(), Staleness> {
+        let age = now - np.timestamp_utc;
+        let clock_skew = self.clock_skew();
+
+        if -age > clock_skew {
+            return Err(Staleness::FromFuture {
+                ahead: -age,
+                clock_skew,
+            });
+        }
+
+        let max_age = self.max_age(np.geo.hazard_level);
+        if age > max_age.checked_add(&clock_skew).unwrap_or(Duration::MAX) {
+            return Err(Staleness::Stale { age, max_age });
+        }
+        Ok(())
+    }
+}
+
+fn seconds(secs: u64) -> Duration {
+    Duration::try_seconds(i64::try_from(secs).unwrap_or(i64::MAX)).unwrap_or(Duration::MAX)
+}
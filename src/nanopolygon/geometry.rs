@@ -0,0 +1,656 @@
+use geojson::{Geometry, PolygonType, Position, Value};
+use serde::{Deserialize, Serialize};
+
+use super::validation::{Violation, ViolationKind};
+
+/// Mean Earth radius (IUGG), used for spherical area.
+pub const EARTH_RADIUS_M: f64 = 6_371_008.8;
+
+/// Accepted area range for a nanopolygon geometry, in square metres.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct GeometryBounds {
+    pub min_area_m2: f64,
+    pub max_area_m2: f64,
+}
+
+impl Default for GeometryBounds {
+    /// 1 m² up to 1,000 km²: large enough for a city-scale sanctuary
+    /// corridor, small enough to catch degree/radian mix-ups.
+    fn default() -> Self {
+        Self {
+            min_area_m2: 1.0,
+            max_area_m2: 1.0e9,
+        }
+    }
+}
+
+/// Checks that `geometry` is a valid nanopolygon footprint: a Polygon or
+/// MultiPolygon (RFC 7946) whose rings are closed, have at least four
+/// positions, lie within WGS84 bounds, do not cross the antimeridian (split
+/// such shapes at ±180°, RFC 7946 §3.1.9), do not self-intersect or cross
+/// each other, wind counter-clockwise (exterior) / clockwise (holes), whose
+/// holes lie inside the exterior and not inside each other, whose
+/// MultiPolygon members do not overlap, and whose spherical area is within
+/// `bounds`.
+pub fn geometry_violations(geometry: &Geometry, bounds: &GeometryBounds) -> Vec<Violation> {
+    let mut out = Vec::new();
+
+    let polygons: Vec<(String, &PolygonType)> = match &geometry.value {
+        Value::Polygon(rings) => vec![("geometry.coordinates".to_string(), rings)],
+        Value::MultiPolygon(polygons) => polygons
+            .iter()
+            .enumerate()
+            .map(|(i, rings)| (format!("geometry.coordinates[{i}]"), rings))
+            .collect(),
+        other => {
+            out.push(violation(
+                "geometry.type",
+                serde_json::Value::from(other.type_name()),
+                "geometry_type",
+                "must be Polygon or MultiPolygon".to_string(),
+            ));
+            return out;
+        }
+    };
+
+    if polygons.is_empty() {
+        out.push(violation(
+            "geometry.coordinates",
+            serde_json::json!([]),
+            "polygon_empty",
+            "MultiPolygon must contain at least one polygon".to_string(),
+        ));
+    }
+
+    let mut valid = Vec::with_capacity(polygons.len());
+    for (path, rings) in &polygons {
+        if rings.is_empty() {
+            out.push(violation(
+                path,
+                serde_json::json!([]),
+                "polygon_empty",
+                "polygon must have an exterior ring".to_string(),
+            ));
+            continue;
+        }
+
+        let mut rings_valid = true;
+        for (r, ring) in rings.iter().enumerate() {
+            let ring_path = format!("{path}[{r}]");
+            if !check_ring(ring, r == 0, &ring_path, &mut out) {
+                rings_valid = false;
+            }
+        }
+        if !rings_valid {
+            continue;
+        }
+
+        let mut crossing = false;
+        for a in 0..rings.len() {
+            for b in a + 1..rings.len() {
+                if rings_cross(&rings[a], &rings[b]) {
+                    crossing = true;
+                    out.push(violation(
+                        &format!("{path}[{b}]"),
+                        serde_json::Value::from(a),
+                        "ring_intersection",
+                        format!("ring touches or crosses ring {a}"),
+                    ));
+                }
+            }
+        }
+        if crossing {
+            continue;
+        }
+
+        // Rings no longer touch, so one vertex decides which side a hole
+        // is on.
+        let mut holes_valid = true;
+        for (h, hole) in rings.iter().enumerate().skip(1) {
+            let p = (hole[0][0], hole[0][1]);
+            let hole_path = format!("{path}[{h}]");
+            if !ring_contains(&rings[0], p) {
+                holes_valid = false;
+                out.push(violation(
+                    &hole_path,
+                    serde_json::to_value(&hole[0]).unwrap_or_default(),
+                    "hole_outside_exterior",
+                    "hole must lie inside the exterior ring".to_string(),
+                ));
+            } else if let Some(k) = (1..rings.len()).find(|&k| k != h && ring_contains(&rings[k], p)) {
+                holes_valid = false;
+                out.push(violation(
+                    &hole_path,
+                    serde_json::Value::from(k),
+                    "hole_nested",
+                    format!("hole lies inside hole {k}"),
+                ));
+            }
+        }
+        if holes_valid {
+            valid.push((path, rings));
+        }
+    }
+
+    // MultiPolygon members must not share area or boundary, or the summed
+    // area counts the overlap twice.
+    for (i, (_, a)) in valid.iter().enumerate() {
+        for (path, b) in &valid[i + 1..] {
+            let overlap = a.iter().any(|ra| b.iter().any(|rb| rings_cross(ra, rb)))
+                || polygon_contains(a, (b[0][0][0], b[0][0][1]))
+                || polygon_contains(b, (a[0][0][0], a[0][0][1]));
+            if overlap {
+                out.push(violation(
+                    path,
+                    serde_json::Value::from(i),
+                    "polygon_overlap",
+                    format!("polygon overlaps or touches polygon {i}"),
+                ));
+            }
+        }
+    }
+
+    // Area is only meaningful for an otherwise valid geometry.
+    if out.is_empty() {
+        let area = spherical_area_m2(geometry);
+        if !(bounds.min_area_m2..=bounds.max_area_m2).contains(&area) {
+            out.push(Violation {
+                field: "geometry".to_string(),
+                value: serde_json::to_value(area).unwrap_or_default(),
+                kind: ViolationKind::OutOfRange {
+                    min: bounds.min_area_m2,
+                    max: bounds.max_area_m2,
+                },
+                message: format!(
+                    "area must be within [{}, {}] m²",
+                    bounds.min_area_m2, bounds.max_area_m2
+                ),
+            });
+        }
+    }
+
+    out
+}
+
+/// Area of a Polygon or MultiPolygon on a sphere of radius
+/// `EARTH_RADIUS_M`, holes subtracted; 0 for other geometry types.
+pub fn spherical_area_m2(geometry: &Geometry) -> f64 {
+    fn polygon_area(rings: &PolygonType) -> f64 {
+        let mut rings = rings.iter().map(|r| ring_area_m2(r).abs());
+        let exterior = rings.next().unwrap_or(0.0);
+        exterior - rings.sum::<f64>()
+    }
+
+    match &geometry.value {
+        Value::Polygon(rings) => polygon_area(rings),
+        Value::MultiPolygon(polygons) => polygons.iter().map(polygon_area).sum(),
+        _ => 0.0,
+    }
+}
+
+// Signed spherical area of a closed ring (Chamberlain & Duquette, "Some
+// algorithms for polygons on a sphere", JPL 2007); callers take `abs`.
+fn ring_area_m2(ring: &[Position]) -> f64 {
+    if ring.len() < 4 {
+        return 0.0;
+    }
+    let open = &ring[..ring.len() - 1];
+    let m = open.len();
+    let total: f64 = (0..m)
+        .map(|i| {
+            let next = open[(i + 1) % m][0].to_radians();
+            let prev = open[(i + m - 1) % m][0].to_radians();
+            (next - prev) * open[i][1].to_radians().sin()
+        })
+        .sum();
+    total * EARTH_RADIUS_M * EARTH_RADIUS_M / 2.0
+}
+
+// Returns false if the ring is structurally unusable for area and
+// intersection checks.
+fn check_ring(ring: &[Position], exterior: bool, path: &str, out: &mut Vec<Violation>) -> bool {
+    let mut usable = true;
+
+    for (p, pos) in ring.iter().enumerate() {
+        let in_bounds = (2..=3).contains(&pos.len())
+            && pos.iter().all(|c| c.is_finite())
+            && (-180.0..=180.0).contains(&pos[0])
+            && (-90.0..=90.0).contains(&pos[1]);
+        if !in_bounds {
+            out.push(violation(
+                &format!("{path}[{p}]"),
+                serde_json::to_value(pos).unwrap_or_default(),
+                "wgs84_bounds",
+                "position must be [lon, lat(, alt)] with lon in [-180, 180] and lat in [-90, 90]"
+                    .to_string(),
+            ));
+            usable = false;
+        }
+    }
+    if !usable {
+        return false;
+    }
+
+    if ring.len() < 4 {
+        out.push(violation(
+            path,
+            serde_json::Value::from(ring.len()),
+            "ring_too_short",
+            "linear ring must have at least 4 positions".to_string(),
+        ));
+        return false;
+    }
+
+    let (first, last) = (&ring[0], &ring[ring.len() - 1]);
+    if first[0] != last[0] || first[1] != last[1] {
+        out.push(violation(
+            path,
+            serde_json::json!([first, last]),
+            "ring_not_closed",
+            "first and last positions must be equal".to_string(),
+        ));
+        return false;
+    }
+
+    // An edge spanning more than half the globe is the short way round
+    // across ±180°; the planar checks below would read it the long way.
+    if let Some(p) = ring.windows(2).position(|w| (w[1][0] - w[0][0]).abs() > 180.0) {
+        out.push(violation(
+            &format!("{path}[{p}]"),
+            serde_json::json!([ring[p], ring[p + 1]]),
+            "antimeridian_crossing",
+            "ring crosses the antimeridian; split it into a MultiPolygon at ±180°".to_string(),
+        ));
+        return false;
+    }
+
+    let vertices = distinct_vertices(ring);
+    if vertices.len() < 3 {
+        out.push(violation(
+            path,
+            serde_json::Value::from(vertices.len()),
+            "ring_degenerate",
+            "linear ring must have at least 3 distinct vertices".to_string(),
+        ));
+        return false;
+    }
+
+    if let Some((i, j)) = self_intersection(&vertices) {
+        out.push(violation(
+            path,
+            serde_json::json!([vertices[i], vertices[j]]),
+            "self_intersection",
+            format!("edges starting at vertices {i} and {j} intersect"),
+        ));
+        return false;
+    }
+
+    let signed = planar_signed_area(&vertices);
+    if signed == 0.0 {
+        out.push(violation(
+            path,
+            serde_json::Value::from(0.0),
+            "ring_degenerate",
+            "linear ring encloses no area".to_string(),
+        ));
+        return false;
+    }
+    if (signed > 0.0) != exterior {
+        out.push(violation(
+            path,
+            serde_json::Value::from(if signed > 0.0 { "counter_clockwise" } else { "clockwise" }),
+            "winding_order",
+            if exterior {
+                "exterior ring must be counter-clockwise".to_string()
+            } else {
+                "hole must be clockwise".to_string()
+            },
+        ));
+    }
+
+    true
+}
+
+fn violation(field: &str, value: serde_json::Value, rule: &'static str, message: String) -> Violation {
+    Violation {
+        field: field.to_string(),
+        value,
+        kind: ViolationKind::Geometry { rule },
+        message,
+    }
+}
+
+type Point = (f64, f64);
+
+// Open ring of distinct consecutive vertices (closing position dropped,
+// repeated positions collapsed).
+fn distinct_vertices(ring: &[Position]) -> Vec<Point> {
+    let mut out: Vec<Point> = Vec::with_capacity(ring.len());
+    for pos in &ring[..ring.len() - 1] {
+        let p = (pos[0], pos[1]);
+        if out.last() != Some(&p) {
+            out.push(p);
+        }
+    }
+    while out.len() > 1 && out.first() == out.last() {
+        out.pop();
+    }
+    out
+}
+
+fn planar_signed_area(v: &[Point]) -> f64 {
+    let n = v.len();
+    (0..n)
+        .map(|i| {
+            let (a, b) = (v[i], v[(i + 1) % n]);
+            a.0 * b.1 - b.0 * a.1
+        })
+        .sum::<f64>()
+        / 2.0
+}
+
+fn self_intersection(v: &[Point]) -> Option<(usize, usize)> {
+    let n = v.len();
+    for i in 0..n {
+        for j in i + 1..n {
+            // Adjacent edges share a vertex by construction.
+            if j == i + 1 || (i == 0 && j == n - 1) {
+                continue;
+            }
+            if segments_intersect(v[i], v[(i + 1) % n], v[j], v[(j + 1) % n]) {
+                return Some((i, j));
+            }
+        }
+    }
+    None
+}
+
+fn rings_cross(a: &[Position], b: &[Position]) -> bool {
+    let (a, b) = (distinct_vertices(a), distinct_vertices(b));
+    (0..a.len()).any(|i| {
+        (0..b.len()).any(|j| {
+            segments_intersect(a[i], a[(i + 1) % a.len()], b[j], b[(j + 1) % b.len()])
+        })
+    })
+}
+
+fn orientation(a: Point, b: Point, c: Point) -> f64 {
+    (b.0 - a.0) * (c.1 - a.1) - (b.1 - a.1) * (c.0 - a.0)
+}
+
+fn on_segment(a: Point, b: Point, p: Point) -> bool {
+    p.0 >= a.0.min(b.0) && p.0 <= a.0.max(b.0) && p.1 >= a.1.min(b.1) && p.1 <= a.1.max(b.1)
+}
+
+/// Closed-segment intersection test (touching counts as intersecting).
+pub(crate) fn segments_intersect(p1: Point, p2: Point, q1: Point, q2: Point) -> bool {
+    let d1 = orientation(q1, q2, p1);
+    let d2 = orientation(q1, q2, p2);
+    let d3 = orientation(p1, p2, q1);
+    let d4 = orientation(p1, p2, q2);
+
+    if ((d1 > 0.0 && d2 < 0.0) || (d1 < 0.0 && d2 > 0.0))
+        && ((d3 > 0.0 && d4 < 0.0) || (d3 < 0.0 && d4 > 0.0))
+    {
+        return true;
+    }
+
+    (d1 == 0.0 && on_segment(q1, q2, p1))
+        || (d2 == 0.0 && on_segment(q1, q2, p2))
+        || (d3 == 0.0 && on_segment(p1, p2, q1))
+        || (d4 == 0.0 && on_segment(p1, p2, q2))
+}
+
+/// `[min, max]` corners (`[lon, lat]`) of a Polygon/MultiPolygon's exterior
+/// rings; `None` for other or empty geometries.
+pub(crate) fn bounding_box(geometry: &Geometry) -> Option<([f64; 2], [f64; 2])> {
+    let exteriors: Vec<&Vec<Position>> = match &geometry.value {
+        Value::Polygon(rings) => rings.first().into_iter().collect(),
+        Value::MultiPolygon(polygons) => polygons.iter().filter_map(|p| p.first()).collect(),
+        _ => return None,
+    };
+    let mut positions = exteriors.into_iter().flatten();
+    let first = positions.next()?;
+    let init = ([first[0], first[1]], [first[0], first[1]]);
+    Some(positions.fold(init, |(min, max), p| {
+        ([min[0].min(p[0]), min[1].min(p[1])], [max[0].max(p[0]), max[1].max(p[1])])
+    }))
+}
+
+/// Point-in-polygon (even-odd over all rings, so holes are excluded).
+/// Points on an edge count as contained.
+pub(crate) fn contains_point(geometry: &Geometry, point: [f64; 2]) -> bool {
+    let p = (point[0], point[1]);
+    polygons(geometry).any(|rings| {
+        let on_boundary = rings.iter().any(|ring| {
+            ring_edges(ring).any(|(a, b)| orientation(a, b, p) == 0.0 && on_segment(a, b, p))
+        });
+        if on_boundary {
+            return true;
+        }
+        rings.iter().fold(false, |inside, ring| inside ^ ring_contains(ring, p))
+    })
+}
+
+// Even-odd test against one ring; `p` is assumed not to lie on it.
+fn ring_contains(ring: &[Position], p: Point) -> bool {
+    let mut inside = false;
+    for (a, b) in ring_edges(ring) {
+        if (a.1 > p.1) != (b.1 > p.1) && p.0 < (b.0 - a.0) * (p.1 - a.1) / (b.1 - a.1) + a.0 {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
+// Inside the exterior and outside every hole of a validated polygon.
+fn polygon_contains(rings: &PolygonType, p: Point) -> bool {
+    let mut rings = rings.iter();
+    rings.next().is_some_and(|exterior| ring_contains(exterior, p))
+        && !rings.any(|hole| ring_contains(hole, p))
+}
+
+/// Whether the segment `a`–`b` touches the polygon's area or boundary.
+pub(crate) fn intersects_segment(geometry: &Geometry, a: [f64; 2], b: [f64; 2]) -> bool {
+    segment_entry(geometry, a, b).is_some()
+}
+
+/// How far along `a`→`b` (0 at `a`, 1 at `b`) the segment first touches the
+/// polygon; `None` if it never does.
+pub(crate) fn segment_entry(geometry: &Geometry, a: [f64; 2], b: [f64; 2]) -> Option<f64> {
+    if contains_point(geometry, a) {
+        return Some(0.0);
+    }
+    let end_inside = contains_point(geometry, b);
+    let (a, b) = ((a[0], a[1]), (b[0], b[1]));
+    polygons(geometry)
+        .flatten()
+        .flat_map(|ring| ring_edges(ring))
+        .filter(|&(p, q)| segments_intersect(a, b, p, q))
+        .map(|(p, q)| crossing_parameter(a, b, p, q))
+        .min_by(f64::total_cmp)
+        .or(end_inside.then_some(1.0))
+}
+
+/// Parameter along `a`→`b` of its first contact with the edge `p`→`q`,
+/// which it is known to touch. `a` lies outside the polygon, so `a != b`.
+fn crossing_parameter(a: Point, b: Point, p: Point, q: Point) -> f64 {
+    let r = (b.0 - a.0, b.1 - a.1);
+    let s = (q.0 - p.0, q.1 - p.1);
+    let denom = r.0 * s.1 - r.1 * s.0;
+    let t = if denom != 0.0 {
+        ((p.0 - a.0) * s.1 - (p.1 - a.1) * s.0) / denom
+    } else {
+        // Collinear overlap: the edge endpoint nearer `a`.
+        let along = |c: Point| ((c.0 - a.0) * r.0 + (c.1 - a.1) * r.1) / (r.0 * r.0 + r.1 * r.1);
+        along(p).min(along(q))
+    };
+    t.clamp(0.0, 1.0)
+}
+
+/// Whether the polygon overlaps the axis-aligned box `[min, max]`.
+pub(crate) fn intersects_box(geometry: &Geometry, min: [f64; 2], max: [f64; 2]) -> bool {
+    let corners = [min, [max[0], min[1]], max, [min[0], max[1]]];
+    let vertex_inside = polygons(geometry).flatten().flatten().any(|p| {
+        (min[0]..=max[0]).contains(&p[0]) && (min[1]..=max[1]).contains(&p[1])
+    });
+    vertex_inside
+        || corners.iter().any(|c| contains_point(geometry, *c))
+        || (0..4).any(|i| intersects_segment(geometry, corners[i], corners[(i + 1) % 4]))
+}
+
+fn polygons(geometry: &Geometry) -> impl Iterator<Item = &PolygonType> {
+    let polygons: &[PolygonType] = match &geometry.value {
+        Value::Polygon(rings) => std::slice::from_ref(rings),
+        Value::MultiPolygon(polygons) => polygons,
+        _ => &[],
+    };
+    polygons.iter()
+}
+
+fn ring_edges(ring: &[Position]) -> impl Iterator<Item = (Point, Point)> + '_ {
+    ring.windows(2).map(|w| ((w[0][0], w[0][1]), (w[1][0], w[1][1])))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Closed ring through `points`, in the order given.
+    fn ring(points: &[[f64; 2]]) -> Vec<Position> {
+        let mut ring: Vec<Position> = points.iter().map(|p| p.to_vec()).collect();
+        ring.push(points[0].to_vec());
+        ring
+    }
+
+    /// Counter-clockwise square with corner `(x, y)` and side `d` degrees.
+    fn square(x: f64, y: f64, d: f64) -> Vec<Position> {
+        ring(&[[x, y], [x + d, y], [x + d, y + d], [x, y + d]])
+    }
+
+    fn clockwise(mut ring: Vec<Position>) -> Vec<Position> {
+        ring.reverse();
+        ring
+    }
+
+    fn rules(value: Value) -> Vec<&'static str> {
+        geometry_violations(&Geometry::new(value), &GeometryBounds::default())
+            .into_iter()
+            .map(|v| match v.kind {
+                ViolationKind::Geometry { rule } => rule,
+                ViolationKind::OutOfRange { .. } => "area",
+                other => panic!("unexpected {other:?}"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn valid_polygon_with_hole_passes() {
+        let hole = clockwise(square(0.004, 0.004, 0.002));
+        assert!(rules(Value::Polygon(vec![square(0.0, 0.0, 0.01), hole])).is_empty());
+    }
+
+    #[test]
+    fn winding_order_is_checked() {
+        let exterior = clockwise(square(0.0, 0.0, 0.01));
+        assert_eq!(rules(Value::Polygon(vec![exterior])), ["winding_order"]);
+        let hole = square(0.004, 0.004, 0.002);
+        assert_eq!(rules(Value::Polygon(vec![square(0.0, 0.0, 0.01), hole])), ["winding_order"]);
+    }
+
+    #[test]
+    fn self_intersecting_rings_are_rejected() {
+        let bowtie = ring(&[[0.0, 0.0], [0.01, 0.01], [0.01, 0.0], [0.0, 0.01]]);
+        assert_eq!(rules(Value::Polygon(vec![bowtie])), ["self_intersection"]);
+    }
+
+    #[test]
+    fn rings_must_be_closed_and_long_enough() {
+        let mut open = square(0.0, 0.0, 0.01);
+        open.pop();
+        open.push(vec![0.0, 0.005]);
+        assert_eq!(rules(Value::Polygon(vec![open])), ["ring_not_closed"]);
+        let short = vec![vec![0.0, 0.0], vec![0.01, 0.0], vec![0.0, 0.0]];
+        assert_eq!(rules(Value::Polygon(vec![short])), ["ring_too_short"]);
+    }
+
+    #[test]
+    fn coordinates_must_be_wgs84() {
+        for bad in [[181.0, 0.0], [0.0, -90.5], [f64::NAN, 0.0]] {
+            let r = ring(&[[0.0, 0.0], bad, [0.01, 0.01], [0.0, 0.01]]);
+            assert_eq!(rules(Value::Polygon(vec![r])), ["wgs84_bounds"], "{bad:?}");
+        }
+    }
+
+    #[test]
+    fn holes_must_lie_inside_the_exterior() {
+        let outside = clockwise(square(0.02, 0.02, 0.002));
+        assert_eq!(
+            rules(Value::Polygon(vec![square(0.0, 0.0, 0.01), outside])),
+            ["hole_outside_exterior"]
+        );
+        // Enclosing the exterior is also outside it.
+        let around = clockwise(square(-0.01, -0.01, 0.03));
+        assert_eq!(
+            rules(Value::Polygon(vec![square(0.0, 0.0, 0.01), around])),
+            ["hole_outside_exterior"]
+        );
+    }
+
+    #[test]
+    fn holes_must_not_nest() {
+        let outer = clockwise(square(0.002, 0.002, 0.006));
+        let inner = clockwise(square(0.004, 0.004, 0.001));
+        let violations = rules(Value::Polygon(vec![square(0.0, 0.0, 0.01), outer, inner]));
+        assert_eq!(violations, ["hole_nested"]);
+    }
+
+    #[test]
+    fn crossing_rings_are_rejected() {
+        let hole = clockwise(square(0.008, 0.004, 0.004));
+        assert_eq!(
+            rules(Value::Polygon(vec![square(0.0, 0.0, 0.01), hole])),
+            ["ring_intersection"]
+        );
+    }
+
+    #[test]
+    fn multipolygon_members_must_not_overlap() {
+        let disjoint = vec![vec![square(0.0, 0.0, 0.01)], vec![square(0.02, 0.0, 0.01)]];
+        assert!(rules(Value::MultiPolygon(disjoint)).is_empty());
+
+        let crossing = vec![vec![square(0.0, 0.0, 0.01)], vec![square(0.005, 0.005, 0.01)]];
+        assert_eq!(rules(Value::MultiPolygon(crossing)), ["polygon_overlap"]);
+
+        let nested = vec![vec![square(0.0, 0.0, 0.01)], vec![square(0.004, 0.004, 0.001)]];
+        assert_eq!(rules(Value::MultiPolygon(nested)), ["polygon_overlap"]);
+
+        // An island inside another member's hole does not overlap it.
+        let with_hole = vec![square(0.0, 0.0, 0.01), clockwise(square(0.002, 0.002, 0.006))];
+        let island = vec![with_hole, vec![square(0.004, 0.004, 0.001)]];
+        assert!(rules(Value::MultiPolygon(island)).is_empty());
+    }
+
+    #[test]
+    fn antimeridian_crossings_must_be_split() {
+        let crossing = ring(&[[179.99, 0.0], [-179.99, 0.0], [-179.99, 0.01], [179.99, 0.01]]);
+        assert_eq!(rules(Value::Polygon(vec![crossing])), ["antimeridian_crossing"]);
+
+        let split = vec![
+            vec![ring(&[[179.99, 0.0], [180.0, 0.0], [180.0, 0.01], [179.99, 0.01]])],
+            vec![ring(&[[-180.0, 0.0], [-179.99, 0.0], [-179.99, 0.01], [-180.0, 0.01]])],
+        ];
+        assert!(rules(Value::MultiPolygon(split)).is_empty());
+    }
+
+    #[test]
+    fn area_subtracts_holes() {
+        let solid = Geometry::new(Value::Polygon(vec![square(0.0, 0.0, 0.01)]));
+        let holed = Geometry::new(Value::Polygon(vec![
+            square(0.0, 0.0, 0.01),
+            clockwise(square(0.0025, 0.0025, 0.005)),
+        ]));
+        let ratio = spherical_area_m2(&holed) / spherical_area_m2(&solid);
+        assert!((ratio - 0.75).abs() < 1e-6, "{ratio}");
+    }
+}
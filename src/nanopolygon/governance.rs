@@ -1,12 +1,14 @@
+use crate::audit::event::AuditSubject;
 use crate::policy::{
     ethics::EthicsProfile,
-    governance_metadata::GovernanceMetadata,
-    nist_ai_rmf::AIRmfFunction,
-    nist_80053::Sp80053Family,
+    governance_metadata::{GovernanceMetadata, HasGovernanceMetadata},
 };
 use super::types::NanopolygonSafetyObject;
+use super::validation::ValidatedNanopolygon;
 
+#[allow(non_camel_case_types)]
 pub trait HasAI_RMFFunction {}
+#[allow(non_camel_case_types)]
 pub trait HasNIST800_53Controls {}
 pub trait HasEthicalGuardrails {}
 
@@ -24,24 +26,26 @@ impl NanopolygonSafetyObject {
     }
 
     pub fn assert_invariant_governance(&self) -> Result<(), &'static str> {
-        // NIST AI RMF must include GOVERN and MANAGE for critical eco‑infra. [file:1][file:5]
-        let ai_f = self.metadata.ai_rmf_profile.functions;
-        if !ai_f.contains(AIRmfFunction::GOVERN | AIRmfFunction::MANAGE) {
-            return Err("NIST AI RMF GOVERN|MANAGE missing");
-        }
-
-        // 800‑53 controls must include AC, AU, and SC families. [file:1][file:5]
-        let sp = self.metadata.sp80053_profile.families;
-        if !sp.contains(Sp80053Family::AC | Sp80053Family::AU | Sp80053Family::SC) {
-            return Err("SP 800‑53 AC|AU|SC families required");
-        }
-
-        let ethics = &self.metadata.ethics_profile;
-        if !(ethics.human_primacy && ethics.equal_power_thresholds && ethics.appeal_paths_available)
-        {
-            return Err("Ethical guardrails not fully satisfied");
-        }
-
-        Ok(())
+        self.metadata.assert_invariants()
+    }
+}
+
+impl HasGovernanceMetadata for NanopolygonSafetyObject {
+    fn governance_metadata(&self) -> &GovernanceMetadata {
+        &self.metadata
+    }
+
+    fn audit_subject(&self) -> AuditSubject {
+        AuditSubject::polygon(self.polygon_id)
+    }
+}
+
+impl HasGovernanceMetadata for ValidatedNanopolygon {
+    fn governance_metadata(&self) -> &GovernanceMetadata {
+        &self.inner().metadata
+    }
+
+    fn audit_subject(&self) -> AuditSubject {
+        AuditSubject::polygon(self.polygon_id)
     }
 }
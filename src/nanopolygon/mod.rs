@@ -1,10 +1,38 @@
 pub mod types;
 pub mod neurorights;
 mod governance;
+mod consent;
+#[cfg(test)]
+pub(crate) mod fixtures;
+mod freshness;
+mod geometry;
+mod registry;
+mod rights_history;
+mod risk_of_harm;
 mod validation;
 
 pub use types::*;
 pub use governance::*;
+pub use consent::{
+    CommunityApproval,
+    ConsentAction,
+    ConsentDenial,
+    ConsentLayer,
+    ConsentLedger,
+    ConsentLedgerError,
+    ConsentRecord,
+    DEFAULT_CONSENT_CLOCK_SKEW_SECS,
+};
+pub use freshness::*;
+pub use geometry::*;
+pub use registry::NanopolygonRegistry;
+pub use rights_history::{
+    RightsHistory,
+    RightsHistoryError,
+    RightsRevision,
+    DEFAULT_RIGHTS_CLOCK_SKEW_SECS,
+};
+pub use risk_of_harm::{RiskOfHarm, RohFactors, RohWeights};
 pub use validation::*;
 pub use types::{
     NanopolygonSafetyObject,
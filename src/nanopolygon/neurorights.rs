@@ -10,7 +10,7 @@ pub enum SpeciesClass {
     Other(String),
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 pub enum TerritoryProtectionLevel {
     /// No special sanctuary status.
     None,
@@ -22,7 +22,7 @@ pub enum TerritoryProtectionLevel {
     RegulatedExperimentZone,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum ConsentRequirement {
     /// Normal ALN governance; no extra consent.
     StandardGovernance,
@@ -34,7 +34,37 @@ pub enum ConsentRequirement {
     CommunityAndIndividual,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl ConsentRequirement {
+    pub fn requires_community(&self) -> bool {
+        matches!(
+            self,
+            ConsentRequirement::CommunityMultisigRequired | ConsentRequirement::CommunityAndIndividual
+        )
+    }
+
+    pub fn requires_individual(&self) -> bool {
+        matches!(
+            self,
+            ConsentRequirement::IndividualConsentRequired | ConsentRequirement::CommunityAndIndividual
+        )
+    }
+
+    /// The weakest requirement that satisfies both `self` and `other`,
+    /// e.g. community multisig + individual consent = both.
+    pub fn strictest(self, other: Self) -> Self {
+        match (
+            self.requires_community() || other.requires_community(),
+            self.requires_individual() || other.requires_individual(),
+        ) {
+            (false, false) => ConsentRequirement::StandardGovernance,
+            (true, false) => ConsentRequirement::CommunityMultisigRequired,
+            (false, true) => ConsentRequirement::IndividualConsentRequired,
+            (true, true) => ConsentRequirement::CommunityAndIndividual,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum AppealPathType {
     /// Routed to Eco‑Infra Council / CAIO board.
     EcoInfraCouncil,
@@ -44,6 +74,29 @@ pub enum AppealPathType {
     CommunityCouncil,
 }
 
+impl AppealPathType {
+    /// Next body up when an appeal is escalated: community council, then
+    /// Eco‑Infra Council, then the independent ethics panel, which is final.
+    pub fn escalates_to(&self) -> Option<AppealPathType> {
+        match self {
+            AppealPathType::CommunityCouncil => Some(AppealPathType::EcoInfraCouncil),
+            AppealPathType::EcoInfraCouncil => Some(AppealPathType::EthicsPanel),
+            AppealPathType::EthicsPanel => None,
+        }
+    }
+
+    /// The higher of two bodies on the escalation ladder, e.g. community
+    /// council + ethics panel = ethics panel.
+    pub fn strictest(self, other: Self) -> Self {
+        let rank = |path: AppealPathType| match path {
+            AppealPathType::CommunityCouncil => 0,
+            AppealPathType::EcoInfraCouncil => 1,
+            AppealPathType::EthicsPanel => 2,
+        };
+        if rank(other) > rank(self) { other } else { self }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SpeciesRightsProfile {
     pub primary_species: SpeciesClass,
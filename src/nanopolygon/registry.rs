@@ -0,0 +1,250 @@
+use std::collections::{HashMap, HashSet};
+
+use rstar::{primitives::GeomWithData, primitives::Rectangle, RTree, AABB};
+use uuid::Uuid;
+
+use super::geometry::{bounding_box, contains_point, intersects_box, segment_entry};
+use super::validation::ValidatedNanopolygon;
+
+type Entry = GeomWithData<Rectangle<[f64; 2]>, Uuid>;
+
+/// Many validated nanopolygons indexed by bounding box in an R-tree.
+/// Candidates from the tree are confirmed against the exact geometry, so
+/// results are real containments / intersections, not just bbox overlaps.
+/// Coordinates are `[lon, lat]` in WGS84 degrees.
+#[derive(Debug, Default)]
+pub struct NanopolygonRegistry {
+    tree: RTree<Entry>,
+    polygons: HashMap<Uuid, (Entry, ValidatedNanopolygon)>,
+}
+
+impl NanopolygonRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Bulk-loads a registry; later duplicates of a `polygon_id` win.
+    pub fn from_polygons(polygons: impl IntoIterator<Item = ValidatedNanopolygon>) -> Self {
+        let mut by_id = HashMap::new();
+        for np in polygons {
+            by_id.insert(np.polygon_id, (entry_for(&np), np));
+        }
+        let tree = RTree::bulk_load(by_id.values().map(|(entry, _)| *entry).collect());
+        Self {
+            tree,
+            polygons: by_id,
+        }
+    }
+
+    /// Inserts or replaces by `polygon_id`, returning the replaced polygon.
+    pub fn insert(&mut self, np: ValidatedNanopolygon) -> Option<ValidatedNanopolygon> {
+        let previous = self.remove(np.polygon_id);
+        let entry = entry_for(&np);
+        self.tree.insert(entry);
+        self.polygons.insert(np.polygon_id, (entry, np));
+        previous
+    }
+
+    pub fn remove(&mut self, polygon_id: Uuid) -> Option<ValidatedNanopolygon> {
+        let (entry, np) = self.polygons.remove(&polygon_id)?;
+        self.tree.remove(&entry);
+        Some(np)
+    }
+
+    pub fn get(&self, polygon_id: Uuid) -> Option<&ValidatedNanopolygon> {
+        self.polygons.get(&polygon_id).map(|(_, np)| np)
+    }
+
+    pub fn len(&self) -> usize {
+        self.polygons.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.polygons.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &ValidatedNanopolygon> {
+        self.polygons.values().map(|(_, np)| np)
+    }
+
+    /// Polygons containing `point` (boundary included, holes excluded).
+    pub fn containing_point(&self, point: [f64; 2]) -> Vec<&ValidatedNanopolygon> {
+        self.tree
+            .locate_all_at_point(&point)
+            .filter_map(|entry| self.get(entry.data))
+            .filter(|np| contains_point(&np.geometry, point))
+            .collect()
+    }
+
+    /// Polygons overlapping the box with corners `min` and `max`.
+    pub fn intersecting_bbox(&self, min: [f64; 2], max: [f64; 2]) -> Vec<&ValidatedNanopolygon> {
+        let envelope = AABB::from_corners(min, max);
+        let (min, max) = (envelope.lower(), envelope.upper());
+        self.tree
+            .locate_in_envelope_intersecting(&envelope)
+            .filter_map(|entry| self.get(entry.data))
+            .filter(|np| intersects_box(&np.geometry, min, max))
+            .collect()
+    }
+
+    /// Polygons touched by a route polyline, in the order the route first
+    /// reaches them (ties keep no particular order). A single-position route
+    /// is treated as a point.
+    pub fn intersecting_route(&self, route: &[[f64; 2]]) -> Vec<&ValidatedNanopolygon> {
+        if let [point] = route {
+            return self.containing_point(*point);
+        }
+
+        let mut seen = HashSet::new();
+        let mut out = Vec::new();
+        for segment in route.windows(2) {
+            let (a, b) = (segment[0], segment[1]);
+            let mut hits: Vec<(f64, &ValidatedNanopolygon)> = self
+                .tree
+                .locate_in_envelope_intersecting(&AABB::from_corners(a, b))
+                .filter(|entry| !seen.contains(&entry.data))
+                .filter_map(|entry| self.get(entry.data))
+                                .filter_map(|np| Some((segment_entry(&np.geometry, a, b)?, np)))
+                .collect();
+            hits.sort_by(|x, y| x.0.total_cmp(&y.0));
+            for (_, np) in hits {
+                seen.insert(np.polygon_id);
+                out.push(np);
+            }
+        }
+        out
+    }
+}
+
+fn entry_for(np: &ValidatedNanopolygon) -> Entry {
+    // Validation rejects anything but a Polygon/MultiPolygon whose every
+    // member has a closed exterior ring (`geometry_type`, `polygon_empty`,
+    // `ring_too_short`), so there is always a first position to box.
+    let (min, max) = bounding_box(&np.geometry).expect("validated geometry has a bounding box");
+    GeomWithData::new(Rectangle::from_corners(min, max), np.polygon_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use geojson::{Geometry, Value};
+
+    use super::*;
+    use crate::nanopolygon::fixtures::nanopolygon;
+    use crate::nanopolygon::types::NanopolygonSafetyObject;
+
+    const ORIGIN: [f64; 2] = [-112.074, 33.448];
+    const UNIT: f64 = 0.001;
+
+    /// Grid units east/north of `ORIGIN` as `[lon, lat]`.
+    fn at(x: f64, y: f64) -> [f64; 2] {
+        [ORIGIN[0] + x * UNIT, ORIGIN[1] + y * UNIT]
+    }
+
+    /// Counter-clockwise polygon through grid `points`, closed for you.
+    fn polygon(id: u128, points: &[(f64, f64)]) -> ValidatedNanopolygon {
+        let mut ring: Vec<Vec<f64>> = points.iter().map(|&(x, y)| at(x, y).to_vec()).collect();
+        ring.push(ring[0].clone());
+        ValidatedNanopolygon::try_from(NanopolygonSafetyObject {
+            polygon_id: Uuid::from_u128(id),
+            geometry: Geometry::new(Value::Polygon(vec![ring])),
+            ..nanopolygon()
+        })
+        .unwrap()
+    }
+
+    fn square(id: u128, x: f64, y: f64, d: f64) -> ValidatedNanopolygon {
+        polygon(id, &[(x, y), (x + d, y), (x + d, y + d), (x, y + d)])
+    }
+
+    fn ids(found: Vec<&ValidatedNanopolygon>) -> Vec<u128> {
+        found.iter().map(|np| np.polygon_id.as_u128()).collect()
+    }
+
+    fn sorted(mut ids: Vec<u128>) -> Vec<u128> {
+        ids.sort_unstable();
+        ids
+    }
+
+    #[test]
+    fn point_queries_use_the_exact_geometry() {
+        let registry = NanopolygonRegistry::from_polygons([
+            square(1, 0.0, 0.0, 2.0),
+            square(2, 1.0, 1.0, 2.0),
+            // The bbox covers (4.5, 1.5); the triangle does not.
+            polygon(3, &[(4.0, 0.0), (6.0, 0.0), (6.0, 2.0)]),
+        ]);
+
+        assert_eq!(sorted(ids(registry.containing_point(at(1.5, 1.5)))), [1, 2]);
+        assert_eq!(ids(registry.containing_point(at(0.5, 0.5))), [1]);
+        assert_eq!(ids(registry.containing_point(at(2.0, 0.5))), [1], "boundary counts");
+        assert!(registry.containing_point(at(4.5, 1.5)).is_empty());
+        assert_eq!(ids(registry.containing_point(at(5.5, 0.5))), [3]);
+    }
+
+    #[test]
+    fn box_queries_use_the_exact_geometry() {
+        let registry = NanopolygonRegistry::from_polygons([
+            square(1, 0.0, 0.0, 1.0),
+            polygon(3, &[(4.0, 0.0), (6.0, 0.0), (6.0, 2.0)]),
+        ]);
+
+        // Corners may come in either order.
+        assert_eq!(ids(registry.intersecting_bbox(at(0.5, 0.5), at(-1.0, -1.0))), [1]);
+        assert!(registry.intersecting_bbox(at(4.1, 1.5), at(4.5, 1.9)).is_empty());
+        assert_eq!(ids(registry.intersecting_bbox(at(5.0, 0.5), at(5.5, 3.0))), [3]);
+        assert_eq!(sorted(ids(registry.intersecting_bbox(at(-1.0, -1.0), at(7.0, 3.0)))), [1, 3]);
+    }
+
+    #[test]
+    fn insert_replaces_by_id_and_remove_unindexes() {
+        let mut registry = NanopolygonRegistry::new();
+        assert!(registry.insert(square(1, 0.0, 0.0, 1.0)).is_none());
+
+        let previous = registry.insert(square(1, 5.0, 5.0, 1.0)).unwrap();
+        assert_eq!(previous.geometry, square(1, 0.0, 0.0, 1.0).geometry);
+        assert_eq!(registry.len(), 1);
+        assert!(registry.containing_point(at(0.5, 0.5)).is_empty());
+        assert_eq!(ids(registry.containing_point(at(5.5, 5.5))), [1]);
+
+        assert!(registry.remove(Uuid::from_u128(1)).is_some());
+        assert!(registry.remove(Uuid::from_u128(1)).is_none());
+        assert!(registry.is_empty());
+        assert!(registry.containing_point(at(5.5, 5.5)).is_empty());
+    }
+
+    #[test]
+    fn bulk_load_keeps_the_last_duplicate() {
+        let registry = NanopolygonRegistry::from_polygons([
+            square(1, 0.0, 0.0, 1.0),
+            square(2, 3.0, 0.0, 1.0),
+            square(1, 5.0, 5.0, 1.0),
+        ]);
+
+        assert_eq!(registry.len(), 2);
+        assert_eq!(registry.iter().count(), 2);
+        assert!(registry.containing_point(at(0.5, 0.5)).is_empty());
+        assert_eq!(ids(registry.containing_point(at(5.5, 5.5))), [1]);
+        assert_eq!(registry.get(Uuid::from_u128(1)).unwrap().geometry, square(1, 5.0, 5.0, 1.0).geometry);
+    }
+
+    #[test]
+    fn routes_list_polygons_in_the_order_they_are_entered() {
+        let registry = NanopolygonRegistry::from_polygons([
+            // Its box starts at x = 1, but y = 0 only enters it at x = 3.5.
+            polygon(1, &[(1.0, 1.0), (6.0, -1.0), (6.0, 1.0)]),
+            square(2, 2.0, -0.5, 1.0),
+            // Reached only by the second leg.
+            square(3, 8.0, 2.0, 1.0),
+            square(4, 20.0, 20.0, 1.0),
+        ]);
+
+        let route = [at(0.0, 0.0), at(8.5, 0.0), at(8.5, 4.0)];
+        assert_eq!(ids(registry.intersecting_route(&route)), [2, 1, 3]);
+
+        let reversed: Vec<_> = route.iter().rev().copied().collect();
+        assert_eq!(ids(registry.intersecting_route(&reversed)), [3, 1, 2]);
+
+        assert_eq!(ids(registry.intersecting_route(&[at(2.5, 0.0)])), [2]);
+        assert!(registry.intersecting_route(&[]).is_empty());
+    }
+}
@@ -0,0 +1,455 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::audit::{
+    context::AuditContext,
+    event::{AuditEvent, AuditKind, AuditSubject},
+    sink::AuditSink,
+};
+use crate::canonical::{to_canonical_bytes, CanonicalError};
+use crate::web5_integration::multisig::EligibleSigner;
+use crate::web5_integration::pqc::{SignatureBlock, SignatureError, Signer, Verifier};
+use crate::web5_integration::vc::OperatorCredential;
+use super::types::RightsMetadata;
+
+/// One signed change to a polygon's species / neurorights profiles. The
+/// revision number is `rights.version`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RightsRevision {
+    pub polygon_id: Uuid,
+    pub rights: RightsMetadata,
+    /// When these rights start to apply (may be back- or forward-dated
+    /// relative to `recorded_at`).
+    pub effective_from: DateTime<Utc>,
+    pub recorded_at: DateTime<Utc>,
+    pub rationale: String,
+    pub signature: SignatureBlock,
+}
+
+#[derive(Serialize)]
+struct SignedRevision<'a> {
+    polygon_id: Uuid,
+    rights: &'a RightsMetadata,
+    effective_from: DateTime<Utc>,
+    recorded_at: DateTime<Utc>,
+    rationale: &'a str,
+}
+
+impl RightsRevision {
+    /// Signs a new revision; `signer.did()` becomes the changing DID.
+    pub fn sign<S: Signer>(
+        polygon_id: Uuid,
+        rights: RightsMetadata,
+        effective_from: DateTime<Utc>,
+        rationale: &str,
+        signer: &S,
+    ) -> Result<Self, SignatureError> {
+        let recorded_at = Utc::now();
+        let bytes = signing_payload(polygon_id, &rights, effective_from, recorded_at, rationale)?;
+        Ok(Self {
+            signature: SignatureBlock::sign(signer, &bytes)?,
+            polygon_id,
+            rights,
+            effective_from,
+            recorded_at,
+            rationale: rationale.to_string(),
+        })
+    }
+
+    pub fn version(&self) -> u64 {
+        self.rights.version
+    }
+
+    pub fn changed_by_did(&self) -> &str {
+        &self.signature.signer_did
+    }
+
+    pub fn verify<V: Verifier>(&self, verifier: &V) -> Result<(), SignatureError> {
+        let bytes = signing_payload(
+            self.polygon_id,
+            &self.rights,
+            self.effective_from,
+            self.recorded_at,
+            &self.rationale,
+        )?;
+        self.signature.verify(verifier, &bytes)
+    }
+}
+
+fn signing_payload(
+    polygon_id: Uuid,
+    rights: &RightsMetadata,
+    effective_from: DateTime<Utc>,
+    recorded_at: DateTime<Utc>,
+    rationale: &str,
+) -> Result<Vec<u8>, CanonicalError> {
+    to_canonical_bytes(&SignedRevision {
+        polygon_id,
+        rights,
+        effective_from,
+        recorded_at,
+        rationale,
+    })
+}
+
+#[derive(Debug, Error)]
+pub enum RightsHistoryError {
+    #[error("rights revision signature rejected: {0}")]
+    Signature(#[from] SignatureError),
+    #[error("`{0}` is not authorized to revise rights")]
+    Unauthorized(String),
+    #[error("expected rights version {expected}, got {got}")]
+    VersionConflict { expected: u64, got: u64 },
+    #[error("revision effective {effective_from} precedes the previous revision ({previous})")]
+    EffectiveDateRegression {
+        previous: DateTime<Utc>,
+        effective_from: DateTime<Utc>,
+    },
+    #[error("rights revision rationale must not be empty")]
+    MissingRationale,
+    #[error("revision recorded at {recorded_at}, after the history's time {now} plus skew")]
+    RecordedInFuture {
+        recorded_at: DateTime<Utc>,
+        now: DateTime<Utc>,
+    },
+}
+
+impl RightsHistoryError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            RightsHistoryError::Signature(_) => "signature_invalid",
+            RightsHistoryError::Unauthorized(_) => "unauthorized_signer",
+            RightsHistoryError::VersionConflict { .. } => "version_conflict",
+            RightsHistoryError::EffectiveDateRegression { .. } => "effective_date_regression",
+            RightsHistoryError::MissingRationale => "missing_rationale",
+            RightsHistoryError::RecordedInFuture { .. } => "recorded_in_future",
+        }
+    }
+}
+
+/// Append-only, per-polygon history of signed rights revisions, so that a
+/// tribunal can see which rules applied when a decision was made.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RightsHistory {
+    /// Who may publish revisions; an empty list admits no one.
+    #[serde(default)]
+    authority: Vec<EligibleSigner>,
+    revisions: HashMap<Uuid, Vec<RightsRevision>>,
+    #[serde(default = "default_clock_skew_secs")]
+    clock_skew_secs: i64,
+}
+
+/// Tolerated lead of a revision's `recorded_at` over the history's clock.
+pub const DEFAULT_RIGHTS_CLOCK_SKEW_SECS: i64 = 2 * 60;
+
+fn default_clock_skew_secs() -> i64 {
+    DEFAULT_RIGHTS_CLOCK_SKEW_SECS
+}
+
+impl RightsHistory {
+    pub fn new(authority: Vec<EligibleSigner>) -> Self {
+        Self {
+            authority,
+            revisions: HashMap::new(),
+            clock_skew_secs: DEFAULT_RIGHTS_CLOCK_SKEW_SECS,
+        }
+    }
+
+    pub fn with_clock_skew(mut self, clock_skew: Duration) -> Self {
+        self.clock_skew_secs = clock_skew.num_seconds();
+        self
+    }
+
+    pub fn authority(&self) -> &[EligibleSigner] {
+        &self.authority
+    }
+
+    pub fn clock_skew(&self) -> Duration {
+        Duration::try_seconds(self.clock_skew_secs).unwrap_or(Duration::MAX)
+    }
+
+    /// Verifies and appends a revision. The signer must be admitted by the
+    /// history's authority at `now` (`credentials` must already be
+    /// verified and are only used to resolve role entries). Versions must
+    /// increase by exactly one (the first may start anywhere above 0),
+    /// effective dates may not go backwards, and the signer-chosen
+    /// `recorded_at` may not lead `now` by more than the clock skew. Emits `RightsRevised`,
+    /// `SignatureVerificationFailed` for a bad signature, or
+    /// `RightsRevisionRejected` for any other refusal.
+    pub fn record<V: Verifier, P: AuditSink>(
+        &mut self,
+        revision: RightsRevision,
+        verifier: &V,
+        credentials: &[OperatorCredential],
+        now: DateTime<Utc>,
+        ctx: &AuditContext,
+        audit_sink: &mut P,
+    ) -> Result<(), RightsHistoryError> {
+        let subject = AuditSubject::polygon(revision.polygon_id);
+
+        if let Err(e) = revision.verify(verifier) {
+            audit_sink.record(
+                AuditEvent::signature_verification_failed(ctx, revision.changed_by_did(), &e.to_string())
+                    .with_subject(subject),
+            );
+            return Err(e.into());
+        }
+        let previous_version = match self.check(&revision, credentials, now) {
+            Ok(previous_version) => previous_version,
+            Err(e) => {
+                audit_sink.record(
+                    AuditEvent::new(ctx, AuditKind::RightsRevisionRejected {
+                        version: revision.version(),
+                        changed_by_did: revision.changed_by_did().to_string(),
+                        reason: e.code().to_string(),
+                    })
+                    .with_subject(subject),
+                );
+                return Err(e);
+            }
+        };
+
+        audit_sink.record(
+            AuditEvent::new(ctx, AuditKind::RightsRevised {
+                version: revision.version(),
+                previous_version,
+                changed_by_did: revision.changed_by_did().to_string(),
+                effective_from: revision.effective_from,
+            })
+            .with_subject(subject),
+        );
+        self.revisions
+            .entry(revision.polygon_id)
+            .or_default()
+            .push(revision);
+        Ok(())
+    }
+
+    /// Everything but the signature; returns the previous version, if any.
+    fn check(
+        &self,
+        revision: &RightsRevision,
+        credentials: &[OperatorCredential],
+        now: DateTime<Utc>,
+    ) -> Result<Option<u64>, RightsHistoryError> {
+        let signer = revision.changed_by_did();
+        if !self.authority.iter().any(|a| a.admits(signer, credentials, now)) {
+            return Err(RightsHistoryError::Unauthorized(signer.to_string()));
+        }
+        if revision.rationale.trim().is_empty() {
+            return Err(RightsHistoryError::MissingRationale);
+        }
+        if revision.recorded_at - now > self.clock_skew() {
+            return Err(RightsHistoryError::RecordedInFuture {
+                recorded_at: revision.recorded_at,
+                now,
+            });
+        }
+
+        let previous = self.current(revision.polygon_id);
+        let expected = previous.map_or(revision.version().max(1), |p| p.version() + 1);
+        if revision.version() != expected {
+            return Err(RightsHistoryError::VersionConflict {
+                expected,
+                got: revision.version(),
+            });
+        }
+        if let Some(previous) = previous {
+            if revision.effective_from < previous.effective_from {
+                return Err(RightsHistoryError::EffectiveDateRegression {
+                    previous: previous.effective_from,
+                    effective_from: revision.effective_from,
+                });
+            }
+        }
+        Ok(previous.map(|p| p.version()))
+    }
+
+    /// Every revision for the polygon, oldest first.
+    pub fn revisions(&self, polygon_id: Uuid) -> &[RightsRevision] {
+        self.revisions.get(&polygon_id).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn current(&self, polygon_id: Uuid) -> Option<&RightsRevision> {
+        self.revisions(polygon_id).last()
+    }
+
+    /// The revision in effect at `at`: the latest one whose
+    /// `effective_from` is not after `at`.
+    pub fn rights_at(&self, polygon_id: Uuid, at: DateTime<Utc>) -> Option<&RightsRevision> {
+        self.revisions(polygon_id)
+            .iter()
+            .rev()
+            .find(|r| r.effective_from <= at)
+    }
+}
+
+#[cfg(all(test, feature = "ml-dsa"))]
+mod tests {
+    use chrono::Duration;
+
+    use super::*;
+    use crate::audit::event::AuditOutcome;
+    use crate::audit::sink::InMemoryAuditSink;
+    use crate::nanopolygon::fixtures::nanopolygon;
+    use crate::web5_integration::did::{DidKeyPair, DidKeyVerifier};
+    use crate::web5_integration::pqc::SignatureBlock;
+    use crate::web5_integration::vc::OperatorRole;
+
+    fn council() -> DidKeyPair {
+        DidKeyPair::from_seed(&[0x31; 32])
+    }
+
+    fn revision(version: u64, effective_from: DateTime<Utc>, rationale: &str, signer: &DidKeyPair) -> RightsRevision {
+        let np = nanopolygon();
+        let mut rights = np.rights;
+        rights.version = version;
+        RightsRevision::sign(np.polygon_id, rights, effective_from, rationale, signer).unwrap()
+    }
+
+    /// `revision` with `recorded_at` replaced and signed again.
+    fn rerecorded(mut revision: RightsRevision, recorded_at: DateTime<Utc>, signer: &DidKeyPair) -> RightsRevision {
+        revision.recorded_at = recorded_at;
+        let bytes = signing_payload(
+            revision.polygon_id,
+            &revision.rights,
+            revision.effective_from,
+            recorded_at,
+            &revision.rationale,
+        )
+        .unwrap();
+        revision.signature = SignatureBlock::sign(signer, &bytes).unwrap();
+        revision
+    }
+
+    fn history() -> RightsHistory {
+        RightsHistory::new(vec![EligibleSigner::Did(council().did)])
+    }
+
+    fn record(history: &mut RightsHistory, revision: RightsRevision, sink: &mut InMemoryAuditSink) -> Result<(), RightsHistoryError> {
+        let ctx = AuditContext::new(Uuid::nil());
+        history.record(revision, &DidKeyVerifier, &[], Utc::now(), &ctx, sink)
+    }
+
+    fn rejected_reason(sink: &InMemoryAuditSink) -> &str {
+        let event = sink.events.last().unwrap();
+        assert_eq!(event.outcome, AuditOutcome::Failure);
+        match &event.kind {
+            AuditKind::RightsRevisionRejected { reason, .. } => reason,
+            other => panic!("unexpected {other:?}"),
+        }
+    }
+
+    #[test]
+    fn authorized_revisions_are_appended_and_audited() {
+        let key = council();
+        let t0 = Utc::now();
+        let mut history = history();
+        let mut sink = InMemoryAuditSink::new();
+        record(&mut history, revision(1, t0, "initial", &key), &mut sink).unwrap();
+        record(&mut history, revision(2, t0 + Duration::days(1), "corridor widened", &key), &mut sink).unwrap();
+
+        let polygon_id = nanopolygon().polygon_id;
+        assert_eq!(history.revisions(polygon_id).len(), 2);
+        assert_eq!(history.rights_at(polygon_id, t0).unwrap().version(), 1);
+        assert!(matches!(
+            sink.events[1].kind,
+            AuditKind::RightsRevised { version: 2, previous_version: Some(1), .. }
+        ));
+    }
+
+    #[test]
+    fn unauthorized_signers_are_rejected_and_audited() {
+        let mut history = history();
+        let mut sink = InMemoryAuditSink::new();
+        let outsider = DidKeyPair::from_seed(&[0x32; 32]);
+        let err = record(&mut history, revision(1, Utc::now(), "takeover", &outsider), &mut sink).unwrap_err();
+        assert!(matches!(err, RightsHistoryError::Unauthorized(ref did) if *did == outsider.did));
+        assert_eq!(rejected_reason(&sink), "unauthorized_signer");
+        assert!(history.current(nanopolygon().polygon_id).is_none());
+    }
+
+    #[test]
+    fn role_authority_needs_a_credential_valid_now() {
+        let key = council();
+        let mut history = RightsHistory::new(vec![EligibleSigner::Role(OperatorRole::EcoCouncil)]);
+        let now = Utc::now();
+        let mut credential = OperatorCredential {
+            did: key.did.clone(),
+            issuer: "did:web:issuer.example".to_string(),
+            roles: vec![OperatorRole::EcoCouncil],
+            valid_from: None,
+            valid_until: Some(now - Duration::hours(1)),
+        };
+        let ctx = AuditContext::new(Uuid::nil());
+        let mut sink = InMemoryAuditSink::new();
+
+        let err = history
+            .record(revision(1, now, "initial", &key), &DidKeyVerifier, &[credential.clone()], now, &ctx, &mut sink)
+            .unwrap_err();
+        assert_eq!(err.code(), "unauthorized_signer");
+
+        credential.valid_until = Some(now + Duration::hours(1));
+        history
+            .record(revision(1, now, "initial", &key), &DidKeyVerifier, &[credential], now, &ctx, &mut sink)
+            .unwrap();
+    }
+
+    #[test]
+    fn every_refusal_emits_a_failure_event() {
+        let key = council();
+        let t0 = Utc::now();
+        let mut history = history();
+        let mut sink = InMemoryAuditSink::new();
+
+        let err = record(&mut history, revision(1, t0, "  ", &key), &mut sink).unwrap_err();
+        assert!(matches!(err, RightsHistoryError::MissingRationale));
+        assert_eq!(rejected_reason(&sink), "missing_rationale");
+
+        record(&mut history, revision(1, t0, "initial", &key), &mut sink).unwrap();
+
+        let err = record(&mut history, revision(3, t0, "skip", &key), &mut sink).unwrap_err();
+        assert!(matches!(err, RightsHistoryError::VersionConflict { expected: 2, got: 3 }));
+        assert_eq!(rejected_reason(&sink), "version_conflict");
+
+        let err = record(&mut history, revision(2, t0 - Duration::days(1), "backdated", &key), &mut sink).unwrap_err();
+        assert!(matches!(err, RightsHistoryError::EffectiveDateRegression { .. }));
+        assert_eq!(rejected_reason(&sink), "effective_date_regression");
+
+        let mut forged = revision(2, t0, "forged", &key);
+        forged.rationale = "tampered".to_string();
+        let err = record(&mut history, forged, &mut sink).unwrap_err();
+        assert_eq!(err.code(), "signature_invalid");
+        assert!(matches!(sink.events.last().unwrap().kind, AuditKind::SignatureVerificationFailed { .. }));
+        assert_eq!(history.revisions(nanopolygon().polygon_id).len(), 1);
+    }
+
+    #[test]
+    fn recorded_at_may_not_lead_the_clock_beyond_skew() {
+        let key = council();
+        let now = Utc::now();
+        let mut history = history();
+        let mut sink = InMemoryAuditSink::new();
+
+        let future = rerecorded(revision(1, now, "initial", &key), now + Duration::days(30), &key);
+        let err = record(&mut history, future, &mut sink).unwrap_err();
+        assert!(matches!(err, RightsHistoryError::RecordedInFuture { .. }));
+        assert_eq!(rejected_reason(&sink), "recorded_in_future");
+        assert!(history.current(nanopolygon().polygon_id).is_none());
+
+        let within_skew = rerecorded(revision(1, now, "initial", &key), now + Duration::seconds(30), &key);
+        record(&mut history, within_skew, &mut sink).unwrap();
+
+        let mut strict = RightsHistory::new(vec![EligibleSigner::Did(key.did.clone())])
+            .with_clock_skew(Duration::zero());
+        assert_eq!(strict.clock_skew(), Duration::zero());
+        let ahead = rerecorded(revision(1, now, "initial", &key), now + Duration::seconds(30), &key);
+        let ctx = AuditContext::new(Uuid::nil());
+        let err = strict.record(ahead, &DidKeyVerifier, &[], now, &ctx, &mut sink).unwrap_err();
+        assert_eq!(err.code(), "recorded_in_future");
+    }
+}
@@ -0,0 +1,177 @@
+use serde::{Deserialize, Serialize};
+
+use super::types::{
+    BiospatialTelemetry,
+    GeoIntelligence,
+    HazardLevel,
+    IntelligenceIndex,
+    NanopolygonSafetyObject,
+};
+
+/// Relative weight of each factor in the RoH score. Only the ratios matter;
+/// the score is the weighted mean of the factors.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RohWeights {
+    pub hazard: f64,
+    pub infrastructure_criticality: f64,
+    pub resource_stress: f64,
+    pub heat_stress: f64,
+    pub pollution_exposure: f64,
+    pub safety_index: f64,
+}
+
+impl Default for RohWeights {
+    fn default() -> Self {
+        Self {
+            hazard: 0.30,
+            infrastructure_criticality: 0.15,
+            resource_stress: 0.10,
+            heat_stress: 0.15,
+            pollution_exposure: 0.15,
+            safety_index: 0.15,
+        }
+    }
+}
+
+/// Each input normalised to 0.0 (no harm) – 1.0 (worst). Missing or
+/// non‑finite telemetry counts as worst.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RohFactors {
+    pub hazard: f64,
+    pub infrastructure_criticality: f64,
+    pub resource_stress: f64,
+    pub heat_stress: f64,
+    pub pollution_exposure: f64,
+    /// From `quantified_safety_index`: 1.0 (safest) maps to 0.0.
+    pub safety_index: f64,
+}
+
+/// Risk of harm of a nanopolygon state, 0.0–1.0, with the factors it was
+/// computed from so guards can explain it.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RiskOfHarm {
+    pub value: f64,
+    pub factors: RohFactors,
+}
+
+impl RiskOfHarm {
+    pub fn assess(np: &NanopolygonSafetyObject, weights: &RohWeights) -> Self {
+        Self::from_parts(&np.geo, &np.biospatial, &np.intelligence, weights)
+    }
+
+    pub fn from_parts(
+        geo: &GeoIntelligence,
+        biospatial: &BiospatialTelemetry,
+        intelligence: &IntelligenceIndex,
+        weights: &RohWeights,
+    ) -> Self {
+        let factors = RohFactors {
+            hazard: hazard_severity(geo.hazard_level),
+            infrastructure_criticality: unit((f64::from(geo.infrastructure_criticality) - 1.0) / 9.0),
+            resource_stress: unit(geo.resource_stress),
+            heat_stress: unit(biospatial.heat_stress),
+            pollution_exposure: unit(biospatial.pollution_exposure),
+            safety_index: unit((1.0 - intelligence.quantified_safety_index) / 2.0),
+        };
+
+        let weighted = [
+            (weights.hazard, factors.hazard),
+            (weights.infrastructure_criticality, factors.infrastructure_criticality),
+            (weights.resource_stress, factors.resource_stress),
+            (weights.heat_stress, factors.heat_stress),
+            (weights.pollution_exposure, factors.pollution_exposure),
+            (weights.safety_index, factors.safety_index),
+        ];
+        // Negative or non‑finite weights are ignored; with nothing left to
+        // weigh, assume the worst.
+        let (sum, total) = weighted
+            .iter()
+            .filter(|(w, _)| w.is_finite() && *w > 0.0)
+            .fold((0.0, 0.0), |(sum, total), (w, f)| (sum + w * f, total + w));
+        let value = if total > 0.0 { unit(sum / total) } else { 1.0 };
+
+        Self { value, factors }
+    }
+}
+
+fn hazard_severity(level: HazardLevel) -> f64 {
+    match level {
+        HazardLevel::Low => 0.1,
+        HazardLevel::Moderate => 0.4,
+        HazardLevel::High => 0.7,
+        HazardLevel::Extreme => 1.0,
+    }
+}
+
+fn unit(v: f64) -> f64 {
+    if v.is_finite() {
+        v.clamp(0.0, 1.0)
+    } else {
+        1.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nanopolygon::fixtures::nanopolygon;
+
+    #[test]
+    fn factors_are_normalised_to_the_unit_interval() {
+        let mut np = nanopolygon();
+        np.geo.hazard_level = HazardLevel::Extreme;
+        np.geo.infrastructure_criticality = 1;
+        np.geo.resource_stress = 1.5;
+        np.biospatial.heat_stress = -0.2;
+        np.intelligence.quantified_safety_index = 1.0;
+        let f = RiskOfHarm::assess(&np, &RohWeights::default()).factors;
+        assert_eq!(
+            (f.hazard, f.infrastructure_criticality, f.resource_stress, f.heat_stress, f.safety_index),
+            (1.0, 0.0, 1.0, 0.0, 0.0)
+        );
+
+        np.geo.infrastructure_criticality = 10;
+        np.intelligence.quantified_safety_index = -1.0;
+        let f = RiskOfHarm::assess(&np, &RohWeights::default()).factors;
+        assert_eq!((f.infrastructure_criticality, f.safety_index), (1.0, 1.0));
+    }
+
+    #[test]
+    fn non_finite_telemetry_counts_as_worst() {
+        let mut np = nanopolygon();
+        np.biospatial.heat_stress = f64::NAN;
+        np.geo.resource_stress = f64::NEG_INFINITY;
+        let roh = RiskOfHarm::assess(&np, &RohWeights::default());
+        assert_eq!(roh.factors.heat_stress, 1.0);
+        assert_eq!(roh.factors.resource_stress, 1.0);
+        assert!((0.0..=1.0).contains(&roh.value));
+    }
+
+    #[test]
+    fn only_weight_ratios_matter() {
+        let np = nanopolygon();
+        let weights = RohWeights::default();
+        let doubled = RohWeights {
+            hazard: weights.hazard * 2.0,
+            infrastructure_criticality: weights.infrastructure_criticality * 2.0,
+            resource_stress: weights.resource_stress * 2.0,
+            heat_stress: weights.heat_stress * 2.0,
+            pollution_exposure: weights.pollution_exposure * 2.0,
+            safety_index: weights.safety_index * 2.0,
+        };
+        let a = RiskOfHarm::assess(&np, &weights).value;
+        let b = RiskOfHarm::assess(&np, &doubled).value;
+        assert!((a - b).abs() < 1e-12);
+
+        let none = RohWeights {
+            hazard: 0.0,
+            infrastructure_criticality: 0.0,
+            resource_stress: 0.0,
+            heat_stress: 0.0,
+            pollution_exposure: 0.0,
+            safety_index: 0.0,
+        };
+        assert_eq!(RiskOfHarm::assess(&np, &none).value, 1.0);
+    }
+}
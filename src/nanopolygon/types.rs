@@ -4,8 +4,9 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::nanopolygon::neurorights::{SpeciesRightsProfile, NeurorightsProfile};
+use crate::policy::governance_metadata::GovernanceMetadata;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 pub enum HazardLevel {
     Low,
     Moderate,
@@ -46,6 +47,10 @@ pub struct IntelligenceIndex {
 /// New: explicit rights metadata for this nanopolygon.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RightsMetadata {
+    /// Revision number in the polygon's `RightsHistory`; 0 for rights that
+    /// predate versioning.
+    #[serde(default)]
+    pub version: u64,
     /// Species‑rights envelope for this polygon.
     pub species_rights: SpeciesRightsProfile,
     /// Neurorights and cognitive safety envelope.
@@ -62,5 +67,7 @@ pub struct NanopolygonSafetyObject {
     pub intelligence: IntelligenceIndex,
     /// Rights + neurorights overlays, versioned and auditable.
     pub rights: RightsMetadata,
+    /// AI RMF / SP 800‑53 / ethics envelope this polygon is governed under.
+    pub metadata: GovernanceMetadata,
     pub timestamp_utc: DateTime<Utc>,
 }
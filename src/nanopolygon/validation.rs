@@ -1,33 +1,261 @@
+use super::freshness::FreshnessPolicy;
+use super::geometry::{geometry_violations, GeometryBounds};
 use super::types::{NanopolygonSafetyObject, GeoIntelligence, BiospatialTelemetry, LearningSignal, IntelligenceIndex};
-use crate::audit::event::AuditEvent;
+use chrono::{DateTime, Utc};
+use crate::audit::context::AuditContext;
+use crate::audit::event::{AuditEvent, AuditSubject};
 use crate::audit::sink::AuditSink;
+use serde::{de::{DeserializeSeed, Error as _}, Deserialize, Deserializer, Serialize};
+use std::marker::PhantomData;
+use std::ops::Deref;
 use thiserror::Error;
 
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ViolationKind {
+    /// Numeric value outside the inclusive range `[min, max]`.
+    OutOfRange { min: f64, max: f64 },
+    /// A `GovernanceMetadata` invariant, identified by rule code.
+    Governance { rule: &'static str },
+    /// A GeoJSON geometry rule, e.g. `ring_not_closed`.
+    Geometry { rule: &'static str },
+    /// `stale_telemetry` or `future_timestamp` under a `FreshnessPolicy`.
+    Freshness { rule: &'static str },
+}
+
+/// One offending field, e.g. `biospatial.heat_stress`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Violation {
+    pub field: String,
+    pub value: serde_json::Value,
+    #[serde(flatten)]
+    pub kind: ViolationKind,
+    pub message: String,
+}
+
+/// Every violation found in one pass over a nanopolygon.
+#[derive(Debug, Clone, PartialEq, Serialize, Error)]
+#[error("{} violation(s): {}", violations.len(), summary(violations))]
+pub struct ValidationError {
+    pub violations: Vec<Violation>,
+}
+
+impl ValidationError {
+    /// Reason recorded on the `ValidationFailed` audit event: governance
+    /// violations outrank geometry, then freshness (reported by its own rule,
+    /// `stale_telemetry` or `future_timestamp`), then range violations.
+    pub fn code(&self) -> &'static str {
+        let any = |f: fn(&ViolationKind) -> bool| self.violations.iter().any(|v| f(&v.kind));
+        let freshness = self.violations.iter().find_map(|v| match v.kind {
+            ViolationKind::Freshness { rule } => Some(rule),
+            _ => None,
+        });
+        if any(|k| matches!(k, ViolationKind::Governance { .. })) {
+            "governance_invariant_violation"
+        } else if any(|k| matches!(k, ViolationKind::Geometry { .. })) {
+            "geometry_violation"
+        } else if let Some(rule) = freshness {
+            rule
+        } else {
+            "range_violation"
+        }
+    }
+
+    pub fn fields(&self) -> impl Iterator<Item = &str> {
+        self.violations.iter().map(|v| v.field.as_str())
+    }
+}
+
+fn summary(violations: &[Violation]) -> String {
+    violations
+        .iter()
+        .map(|v| format!("{} = {} ({})", v.field, v.value, v.message))
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// Failure to decode a validated wrapper off the wire.
 #[derive(Debug, Error)]
-pub enum ValidationError {
-    #[error("numeric field out of allowed range")]
-    RangeError,
-    #[error("governance invariants not satisfied: {0}")]
-    Governance(&'static str),
+pub enum DecodeError {
+    #[error("malformed JSON: {0}")]
+    Malformed(#[from] serde_json::Error),
+    #[error("decoded nanopolygon failed validation: {0}")]
+    Invalid(#[from] ValidationError),
 }
 
+/// Tunables for `validate_with` and `ValidationSeed`; `Default` is what
+/// `validate` and `TryFrom` use.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValidationConfig {
+    pub geometry: GeometryBounds,
+    /// On by default (and when omitted from a config file), so stale
+    /// telemetry fails closed. `None` only via `archival` or explicitly.
+    #[serde(default = "default_freshness")]
+    pub freshness: Option<FreshnessPolicy>,
+}
+
+impl Default for ValidationConfig {
+    fn default() -> Self {
+        Self {
+            geometry: GeometryBounds::default(),
+            freshness: default_freshness(),
+        }
+    }
+}
+
+impl ValidationConfig {
+    /// Default checks without freshness, for re-validating stored records
+    /// (queued HITL tickets, shards under appeal) whose age is expected and
+    /// must not depend on the wall clock.
+    pub fn archival() -> Self {
+        Self {
+            freshness: None,
+            ..Self::default()
+        }
+    }
+}
+
+fn default_freshness() -> Option<FreshnessPolicy> {
+    Some(FreshnessPolicy::default())
+}
+
+/// Can only be built by `validate`, `TryFrom` or a `ValidationSeed`, all of
+/// which run `check_nanopolygon_with`; a wire payload cannot smuggle in an
+/// unvalidated polygon. There is deliberately no plain `Deserialize`: it
+/// could only re-check against the default config, so polygons accepted
+/// under a custom one would not round-trip.
+#[derive(Debug, Clone, Serialize)]
+#[serde(transparent)]
 pub struct ValidatedNanopolygon(NanopolygonSafetyObject);
 
 impl ValidatedNanopolygon {
     pub fn inner(&self) -> &NanopolygonSafetyObject {
         &self.0
     }
+
+    pub fn into_inner(self) -> NanopolygonSafetyObject {
+        self.0
+    }
+
+    /// Same checks as `validate_with`, without audit records.
+    pub fn try_from_with(
+        np: NanopolygonSafetyObject,
+        config: &ValidationConfig,
+    ) -> Result<Self, ValidationError> {
+        check_nanopolygon_with(&np, config)?;
+        Ok(ValidatedNanopolygon(np))
+    }
+
+    /// Decodes and validates, keeping the validation failure typed.
+    pub fn from_json_slice(bytes: &[u8]) -> Result<Self, DecodeError> {
+        Self::from_json_slice_with(bytes, &ValidationConfig::default())
+    }
+
+    pub fn from_json_slice_with(bytes: &[u8], config: &ValidationConfig) -> Result<Self, DecodeError> {
+        let np: NanopolygonSafetyObject = serde_json::from_slice(bytes)?;
+        Ok(Self::try_from_with(np, config)?)
+    }
+}
+
+impl TryFrom<NanopolygonSafetyObject> for ValidatedNanopolygon {
+    type Error = ValidationError;
+
+    /// Same checks as `validate`, without audit records.
+    fn try_from(np: NanopolygonSafetyObject) -> Result<Self, Self::Error> {
+        check_nanopolygon(&np)?;
+        Ok(ValidatedNanopolygon(np))
+    }
+}
+
+/// Deserializes a `T` holding a nanopolygon (`ValidatedNanopolygon`,
+/// `AlnShard`, `HitlQueueItem`) and validates it under `config`.
+pub struct ValidationSeed<'a, T> {
+    config: &'a ValidationConfig,
+    target: PhantomData<fn() -> T>,
 }
 
-// Basic numeric range enforcement + governance invariants.
+impl<'a, T> ValidationSeed<'a, T> {
+    pub fn new(config: &'a ValidationConfig) -> Self {
+        Self {
+            config,
+            target: PhantomData,
+        }
+    }
+
+    pub fn config(&self) -> &'a ValidationConfig {
+        self.config
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for ValidationSeed<'_, ValidatedNanopolygon> {
+    type Value = ValidatedNanopolygon;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        let np = NanopolygonSafetyObject::deserialize(deserializer)?;
+        ValidatedNanopolygon::try_from_with(np, self.config).map_err(D::Error::custom)
+    }
+}
+
+impl Deref for ValidatedNanopolygon {
+    type Target = NanopolygonSafetyObject;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+// Basic numeric range enforcement + geometry + governance invariants.
 pub fn validate<P: AuditSink>(
     np: NanopolygonSafetyObject,
+    ctx: &AuditContext,
+    audit_sink: &mut P,
+) -> Result<ValidatedNanopolygon, ValidationError> {
+    validate_with(np, &ValidationConfig::default(), ctx, audit_sink)
+}
+
+pub fn validate_with<P: AuditSink>(
+    np: NanopolygonSafetyObject,
+    config: &ValidationConfig,
+    ctx: &AuditContext,
     audit_sink: &mut P,
 ) -> Result<ValidatedNanopolygon, ValidationError> {
-    fn in_01(v: f64) -> bool {
-        (0.0..=1.0).contains(&v)
+    let subject = AuditSubject::polygon(np.polygon_id);
+    let now = Utc::now();
+
+    if let Err(e) = check_nanopolygon_at(&np, config, now) {
+        if let Some(Err(staleness)) = config.freshness.map(|f| f.check(&np, now)) {
+            audit_sink.record(
+                AuditEvent::telemetry_not_fresh(ctx, np.timestamp_utc, &staleness)
+                    .with_subject(subject),
+            );
+        }
+        audit_sink.record(AuditEvent::validation_failed(ctx, e.code()).with_subject(subject));
+        return Err(e);
     }
 
+    audit_sink.record(AuditEvent::validation_success(ctx).with_subject(subject));
+    Ok(ValidatedNanopolygon(np))
+}
+
+/// The checks behind `validate`, with no side effects. All violations are
+/// collected rather than stopping at the first.
+pub fn check_nanopolygon(np: &NanopolygonSafetyObject) -> Result<(), ValidationError> {
+    check_nanopolygon_with(np, &ValidationConfig::default())
+}
+
+pub fn check_nanopolygon_with(
+    np: &NanopolygonSafetyObject,
+    config: &ValidationConfig,
+) -> Result<(), ValidationError> {
+    check_nanopolygon_at(np, config, Utc::now())
+}
+
+/// As `check_nanopolygon_with`, judging freshness as of `now`.
+pub fn check_nanopolygon_at(
+    np: &NanopolygonSafetyObject,
+    config: &ValidationConfig,
+    now: DateTime<Utc>,
+) -> Result<(), ValidationError> {
     let GeoIntelligence {
         resource_stress,
         infrastructure_criticality,
@@ -44,22 +272,216 @@ pub fn validate<P: AuditSink>(
         quantified_safety_index,
     } = np.intelligence;
 
-    if !in_01(resource_stress)
-        || !(1..=10).contains(&infrastructure_criticality)
-        || !in_01(heat_stress)
-        || !in_01(pollution_exposure)
-        || !in_01(gradient_weight)
-        || !(-1.0..=1.0).contains(&quantified_safety_index)
-    {
-        audit_sink.record(AuditEvent::validation_failed("range_violation"));
-        return Err(ValidationError::RangeError);
+    let ranges = [
+        ("geo.resource_stress", resource_stress, 0.0, 1.0),
+        ("geo.infrastructure_criticality", f64::from(infrastructure_criticality), 1.0, 10.0),
+        ("biospatial.heat_stress", heat_stress, 0.0, 1.0),
+        ("biospatial.pollution_exposure", pollution_exposure, 0.0, 1.0),
+        ("learning.gradient_weight", gradient_weight, 0.0, 1.0),
+        ("intelligence.quantified_safety_index", quantified_safety_index, -1.0, 1.0),
+    ];
+
+    let mut violations = geometry_violations(&np.geometry, &config.geometry);
+    for (field, value, min, max) in ranges {
+        if !(min..=max).contains(&value) {
+            // Whole numbers are reported as JSON integers, as in the canonical
+            // encoding; NaN becomes null.
+            let value = if value.fract() == 0.0 && value.abs() < 1e15 {
+                serde_json::Value::from(value as i64)
+            } else {
+                serde_json::to_value(value).unwrap_or_default()
+            };
+            violations.push(Violation {
+                field: field.to_string(),
+                value,
+                kind: ViolationKind::OutOfRange { min, max },
+                message: format!("must be within [{min}, {max}]"),
+            });
+        }
     }
 
-    if let Err(e) = np.assert_invariant_governance() {
-        audit_sink.record(AuditEvent::validation_failed("governance_invariant_violation"));
-        return Err(ValidationError::Governance(e));
+    if let Some(Err(staleness)) = config.freshness.map(|f| f.check(np, now)) {
+        violations.push(Violation {
+            field: "timestamp_utc".to_string(),
+            value: serde_json::to_value(np.timestamp_utc).unwrap_or_default(),
+            kind: ViolationKind::Freshness {
+                rule: staleness.code(),
+            },
+            message: staleness.to_string(),
+        });
     }
 
-    audit_sink.record(AuditEvent::validation_success());
-    Ok(ValidatedNanopolygon(np))
+    for v in np.metadata.invariant_violations() {
+        violations.push(Violation {
+            field: format!("metadata.{}", v.field),
+            value: v.value,
+            kind: ViolationKind::Governance { rule: v.rule },
+            message: v.message.to_string(),
+        });
+    }
+
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(ValidationError { violations })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use geojson::{Geometry, Value};
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::aln::AlnShard;
+    use crate::audit::sink::InMemoryAuditSink;
+    use crate::nanopolygon::fixtures::{nanopolygon, shard};
+    use crate::policy::nist_80053::Sp80053Family;
+
+    /// About 0.25 m²: below the default minimum area.
+    fn tiny() -> NanopolygonSafetyObject {
+        let (x, y, d) = (-112.074, 33.448, 0.000005);
+        NanopolygonSafetyObject {
+            geometry: Geometry::new(Value::Polygon(vec![vec![
+                vec![x, y],
+                vec![x + d, y],
+                vec![x + d, y + d],
+                vec![x, y + d],
+                vec![x, y],
+            ]])),
+            ..nanopolygon()
+        }
+    }
+
+    fn permissive() -> ValidationConfig {
+        ValidationConfig {
+            geometry: GeometryBounds {
+                min_area_m2: 0.01,
+                ..GeometryBounds::default()
+            },
+            ..ValidationConfig::default()
+        }
+    }
+
+    #[test]
+    fn custom_config_polygons_round_trip_through_the_seed() {
+        let config = permissive();
+        let ctx = AuditContext::new(Uuid::nil());
+        let np = validate_with(tiny(), &config, &ctx, &mut InMemoryAuditSink::new()).unwrap();
+        let json = serde_json::to_vec(&np).unwrap();
+
+        let mut de = serde_json::Deserializer::from_slice(&json);
+        let decoded = ValidationSeed::<ValidatedNanopolygon>::new(&config)
+            .deserialize(&mut de)
+            .unwrap();
+        assert_eq!(serde_json::to_vec(&decoded).unwrap(), json);
+        ValidatedNanopolygon::from_json_slice_with(&json, &config).unwrap();
+
+        // The default config still rejects it.
+        assert!(matches!(
+            ValidatedNanopolygon::from_json_slice(&json),
+            Err(DecodeError::Invalid(e)) if e.fields().eq(["geometry"])
+        ));
+    }
+
+    #[test]
+    fn shards_round_trip_under_their_config() {
+        let config = permissive();
+        let np = ValidatedNanopolygon::try_from_with(tiny(), &config).unwrap();
+        let json = serde_json::to_vec(&AlnShard::new(shard().header, np)).unwrap();
+
+        let decoded = AlnShard::from_json_slice_with(&json, &config).unwrap();
+        assert_eq!(serde_json::to_vec(&decoded).unwrap(), json);
+        assert!(matches!(AlnShard::from_json_slice(&json), Err(DecodeError::Invalid(_))));
+
+        let mut de = serde_json::Deserializer::from_slice(&json);
+        let err = ValidationSeed::<AlnShard>::new(&ValidationConfig::default())
+            .deserialize(&mut de)
+            .unwrap_err();
+        assert!(err.to_string().contains("geometry"), "{err}");
+    }
+
+    #[test]
+    fn freshness_is_checked_unless_archival() {
+        let mut old = nanopolygon();
+        old.timestamp_utc -= chrono::Duration::days(2);
+
+        let err = ValidatedNanopolygon::try_from(old.clone()).unwrap_err();
+        assert_eq!(err.code(), "stale_telemetry");
+
+        let mut ahead = nanopolygon();
+        ahead.timestamp_utc += chrono::Duration::hours(1);
+        let err = ValidatedNanopolygon::try_from(ahead).unwrap_err();
+        assert_eq!(err.code(), "future_timestamp");
+        assert_eq!(err.violations[0].kind, ViolationKind::Freshness { rule: "future_timestamp" });
+        ValidatedNanopolygon::try_from_with(old, &ValidationConfig::archival()).unwrap();
+
+        // A config file that omits `freshness` still gets the default policy.
+        let parsed: ValidationConfig =
+            serde_json::from_str(r#"{"geometry": {"min_area_m2": 1.0, "max_area_m2": 1e9}}"#).unwrap();
+        assert_eq!(parsed.freshness, Some(FreshnessPolicy::default()));
+    }
+
+    #[test]
+    fn every_violation_is_reported_with_its_field_and_rule() {
+        let mut np = nanopolygon();
+        np.geo.infrastructure_criticality = 0;
+        np.biospatial.heat_stress = 1.5;
+        np.metadata.sp80053_profile.families = Sp80053Family::AC;
+        np.metadata.ethics_profile.human_primacy = false;
+
+        let err = check_nanopolygon_at(&np, &ValidationConfig::default(), np.timestamp_utc)
+            .unwrap_err();
+        assert!(err.fields().eq([
+            "geo.infrastructure_criticality",
+            "biospatial.heat_stress",
+            "metadata.sp80053_profile.families",
+            "metadata.ethics_profile.human_primacy",
+        ]));
+        let found: Vec<_> = err.violations.iter().map(|v| (&v.value, &v.kind)).collect();
+        assert_eq!(
+            found,
+            [
+                (&serde_json::json!(0), &ViolationKind::OutOfRange { min: 1.0, max: 10.0 }),
+                (&serde_json::json!(1.5), &ViolationKind::OutOfRange { min: 0.0, max: 1.0 }),
+                (
+                    &serde_json::to_value(Sp80053Family::AC).unwrap(),
+                    &ViolationKind::Governance { rule: "sp80053_ac_au_sc" },
+                ),
+                (&serde_json::json!(false), &ViolationKind::Governance { rule: "ethical_guardrails" }),
+            ]
+        );
+        assert_eq!(err.code(), "governance_invariant_violation");
+    }
+
+    #[test]
+    fn range_only_failures_report_each_bound() {
+        let mut np = nanopolygon();
+        np.geo.resource_stress = -0.25;
+        np.learning.gradient_weight = f64::NAN;
+        np.intelligence.quantified_safety_index = 2.0;
+
+        let err = check_nanopolygon_at(&np, &ValidationConfig::default(), np.timestamp_utc)
+            .unwrap_err();
+        assert_eq!(err.code(), "range_violation");
+        assert!(err.fields().eq([
+            "geo.resource_stress",
+            "learning.gradient_weight",
+            "intelligence.quantified_safety_index",
+        ]));
+        // NaN is reported as null; whole numbers as integers.
+        let values: Vec<_> = err.violations.iter().map(|v| v.value.clone()).collect();
+        assert_eq!(values, [serde_json::json!(-0.25), serde_json::Value::Null, serde_json::json!(2)]);
+        assert_eq!(
+            err.violations[2].kind,
+            ViolationKind::OutOfRange { min: -1.0, max: 1.0 }
+        );
+    }
+
+    #[test]
+    fn try_from_uses_the_default_config() {
+        assert!(ValidatedNanopolygon::try_from(nanopolygon()).is_ok());
+        assert!(ValidatedNanopolygon::try_from(tiny()).is_err());
+        assert!(ValidatedNanopolygon::try_from_with(tiny(), &permissive()).is_ok());
+    }
 }
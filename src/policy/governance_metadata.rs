@@ -1,7 +1,13 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use super::{nist_ai_rmf::AIRmfProfile, nist_80053::Sp80053Profile, ethics::EthicsProfile};
+use crate::audit::event::AuditSubject;
+
+use super::{
+    nist_ai_rmf::{AIRmfFunction, AIRmfProfile},
+    nist_80053::{Sp80053Family, Sp80053Profile},
+    ethics::EthicsProfile,
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GovernanceMetadata {
@@ -13,3 +19,78 @@ pub struct GovernanceMetadata {
     pub created_by_agent_id: Uuid,
     pub last_modified_by_agent_id: Uuid,
 }
+
+/// One broken governance invariant, with the offending field relative to
+/// the metadata root.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvariantViolation {
+    pub field: &'static str,
+    pub value: serde_json::Value,
+    pub rule: &'static str,
+    pub message: &'static str,
+}
+
+impl GovernanceMetadata {
+    pub fn assert_invariants(&self) -> Result<(), &'static str> {
+        match self.invariant_violations().first() {
+            Some(v) => Err(v.message),
+            None => Ok(()),
+        }
+    }
+
+    /// Every invariant this metadata breaks, in check order.
+    pub fn invariant_violations(&self) -> Vec<InvariantViolation> {
+        let mut out = Vec::new();
+
+        // NIST AI RMF must include GOVERN and MANAGE for critical eco‑infra. [file:1][file:5]
+        let ai_f = self.ai_rmf_profile.functions;
+        if !ai_f.contains(AIRmfFunction::GOVERN | AIRmfFunction::MANAGE) {
+            out.push(InvariantViolation {
+                field: "ai_rmf_profile.functions",
+                value: serde_json::to_value(ai_f).unwrap_or_default(),
+                rule: "ai_rmf_govern_manage",
+                message: "NIST AI RMF GOVERN|MANAGE missing",
+            });
+        }
+
+        // 800‑53 controls must include AC, AU, and SC families. [file:1][file:5]
+        let sp = self.sp80053_profile.families;
+        if !sp.contains(Sp80053Family::AC | Sp80053Family::AU | Sp80053Family::SC) {
+            out.push(InvariantViolation {
+                field: "sp80053_profile.families",
+                value: serde_json::to_value(sp).unwrap_or_default(),
+                rule: "sp80053_ac_au_sc",
+                message: "SP 800‑53 AC|AU|SC families required",
+            });
+        }
+
+        let ethics = &self.ethics_profile;
+        for (field, holds) in [
+            ("ethics_profile.human_primacy", ethics.human_primacy),
+            ("ethics_profile.equal_power_thresholds", ethics.equal_power_thresholds),
+            ("ethics_profile.appeal_paths_available", ethics.appeal_paths_available),
+        ] {
+            if !holds {
+                out.push(InvariantViolation {
+                    field,
+                    value: serde_json::Value::Bool(holds),
+                    rule: "ethical_guardrails",
+                    message: "Ethical guardrails not fully satisfied",
+                });
+            }
+        }
+
+        out
+    }
+}
+
+/// Any domain type (nanopolygon, ALN shard, route spec) that carries
+/// governance metadata can be evaluated by the `GovernanceGuard`.
+pub trait HasGovernanceMetadata {
+    fn governance_metadata(&self) -> &GovernanceMetadata;
+
+    /// Identifiers recorded as the subject of governance audit events.
+    fn audit_subject(&self) -> AuditSubject {
+        AuditSubject::default()
+    }
+}
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 bitflags::bitflags! {
-    #[derive(Serialize, Deserialize)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
     pub struct Sp80053Family: u32 {
         const AC = 0b0001; // Access Control
         const AU = 0b0010; // Audit and Accountability
@@ -10,7 +10,58 @@ bitflags::bitflags! {
     }
 }
 
+/// FIPS 199 style impact level for the system the shard belongs to.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Sp80053Impact {
+    Low,
+    Moderate,
+    High,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Sp80053Profile {
     pub families: Sp80053Family,
+    pub impact: Sp80053Impact,
+}
+
+/// Individual SP 800‑53 Rev. 5 controls referenced by audit records.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum Sp80053Control {
+    #[serde(rename = "AC-3")]
+    Ac3, // Access Enforcement
+    #[serde(rename = "AC-4")]
+    Ac4, // Information Flow Enforcement
+    #[serde(rename = "AU-10")]
+    Au10, // Non-repudiation
+    #[serde(rename = "CM-3")]
+    Cm3, // Configuration Change Control
+    #[serde(rename = "IA-2")]
+    Ia2, // Identification and Authentication (Organizational Users)
+    #[serde(rename = "PT-4")]
+    Pt4, // Consent
+    #[serde(rename = "RA-3")]
+    Ra3, // Risk Assessment
+    #[serde(rename = "SC-7")]
+    Sc7, // Boundary Protection
+    #[serde(rename = "SI-7")]
+    Si7, // Software, Firmware, and Information Integrity
+    #[serde(rename = "SI-10")]
+    Si10, // Information Input Validation
+}
+
+impl Sp80053Control {
+    pub fn id(&self) -> &'static str {
+        match self {
+            Sp80053Control::Ac3 => "AC-3",
+            Sp80053Control::Ac4 => "AC-4",
+            Sp80053Control::Au10 => "AU-10",
+            Sp80053Control::Cm3 => "CM-3",
+            Sp80053Control::Ia2 => "IA-2",
+            Sp80053Control::Pt4 => "PT-4",
+            Sp80053Control::Ra3 => "RA-3",
+            Sp80053Control::Sc7 => "SC-7",
+            Sp80053Control::Si7 => "SI-7",
+            Sp80053Control::Si10 => "SI-10",
+        }
+    }
 }
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 bitflags::bitflags! {
-    #[derive(Serialize, Deserialize)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
     pub struct AIRmfFunction: u32 {
         const GOVERN = 0b0001;
         const MAP    = 0b0010;
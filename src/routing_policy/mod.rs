@@ -0,0 +1,3 @@
+pub mod rules;
+
+pub use rules::{RouteDecision, RoutingContext, HITL_ENQUEUE_FAILED, POWER_THRESHOLD};
@@ -1,9 +1,19 @@
-use crate::aln_shard::AlnShard;
-use crate::nanopolygon::{ImpactRating, NanopolygonSafetyObject};
+use crate::aln::AlnShard;
+use crate::audit::{
+    context::AuditContext,
+    event::AuditEvent,
+    sink::AuditSink,
+    trace::{DecisionTrace, TraceStep},
+};
 use crate::governance_hooks::{GovernanceHooks, HitlTicket};
+use crate::policy::{governance_metadata::HasGovernanceMetadata, nist_80053::Sp80053Impact};
 
 pub const POWER_THRESHOLD: f64 = 0.92;
 
+/// `RouteDecision::Reject` code when the HITL hook could not queue a shard.
+pub const HITL_ENQUEUE_FAILED: &str = "hitl_enqueue_failed";
+
+#[derive(Debug)]
 pub enum RouteDecision {
     Execute,
     QueueHitl(HitlTicket),
@@ -15,28 +25,210 @@ pub struct RoutingContext<'a, H: GovernanceHooks> {
 }
 
 impl<'a, H: GovernanceHooks> RoutingContext<'a, H> {
-    pub fn decide(
+    pub fn decide<P: AuditSink>(
         &self,
         shard: &AlnShard,
         model_confidence: f64,
+        ctx: &AuditContext,
+        audit_sink: &mut P,
     ) -> RouteDecision {
-        let np: &NanopolygonSafetyObject = &shard.payload;
+        self.decide_explained(shard, model_confidence, ctx, audit_sink).0
+    }
+
+    /// Like `decide`, also returning the trace that is handed to the HITL
+    /// queue so reviewers can see why the shard reached them. Queued shards
+    /// are recorded as `HitlEnqueued`, executed ones as `RoutingAllowed`;
+    /// both events carry the trace.
+    pub fn decide_explained<P: AuditSink>(
+        &self,
+        shard: &AlnShard,
+        model_confidence: f64,
+        ctx: &AuditContext,
+        audit_sink: &mut P,
+    ) -> (RouteDecision, DecisionTrace) {
+        let meta = shard.governance_metadata();
+        let impact = meta.sp80053_profile.impact;
+        let hitl_required = meta.ai_rmf_profile.requires_human_in_loop;
+        let mut trace = DecisionTrace::new("routing_context");
 
         // Example: equal power-threshold + impact gating.
-        let is_high_impact = matches!(np.impact_rating, ImpactRating::High(_) | ImpactRating::Critical(_));
-        let is_manage = np.ai_rmf_function == "MANAGE" || np.ai_rmf_function == "GOVERN";
+        let is_high_impact = impact == Sp80053Impact::High;
 
-        if is_high_impact && model_confidence >= POWER_THRESHOLD {
+        let power = is_high_impact && model_confidence >= POWER_THRESHOLD;
+        trace.push(
+            TraceStep::new(
+                "equal_power_threshold",
+                power,
+                if power {
+                    "high-impact shard at or above the power threshold"
+                } else {
+                    "not a high-impact shard at or above the power threshold"
+                },
+            )
+            .with_input("impact", impact)
+            .with_input("confidence", model_confidence)
+            .with_input("power_threshold", POWER_THRESHOLD),
+        );
+        if power {
             // Route to HITL queue, not direct execution.
-            let ticket = self.hooks.enqueue_hitl(shard.clone(), model_confidence);
-            return RouteDecision::QueueHitl(ticket);
+            let trace = trace.finish("equal_power_threshold");
+            return self.queue(shard, model_confidence, trace, ctx, audit_sink);
+        }
+
+        let gated = hitl_required && is_high_impact;
+        trace.push(
+            TraceStep::new(
+                "hitl_required_high_impact",
+                gated,
+                match (hitl_required, is_high_impact) {
+                    (true, true) => "profile requires a human in the loop for high-impact shards",
+                    (true, false) => "HITL flag set, but the shard is not high impact",
+                    (false, true) => "high-impact shard without a HITL flag",
+                    (false, false) => "no HITL flag and not a high-impact shard",
+                },
+            )
+            .with_input("impact", impact)
+            .with_input("requires_human_in_loop", hitl_required),
+        );
+        if gated {
+            let trace = trace.finish("hitl_required_high_impact");
+            return self.queue(shard, model_confidence, trace, ctx, audit_sink);
         }
 
-        if np.governance_meta.hitl_required && is_high_impact {
-            let ticket = self.hooks.enqueue_hitl(shard.clone(), model_confidence);
-            return RouteDecision::QueueHitl(ticket);
+        let trace = trace.finish("execute");
+        audit_sink.record(
+            AuditEvent::routing_allowed(ctx, &trace.outcome)
+                .with_subject(shard.audit_subject())
+                .with_trace(trace.clone()),
+        );
+        (RouteDecision::Execute, trace)
+    }
+
+    fn queue<P: AuditSink>(
+        &self,
+        shard: &AlnShard,
+        model_confidence: f64,
+        trace: DecisionTrace,
+        ctx: &AuditContext,
+        audit_sink: &mut P,
+    ) -> (RouteDecision, DecisionTrace) {
+        let ticket = match self.hooks.enqueue_hitl(shard.clone(), model_confidence, trace.clone()) {
+            Ok(ticket) => ticket,
+            Err(e) => {
+                // No ticket means no human will see the shard: fail closed.
+                let mut trace = trace;
+                trace.push(TraceStep::new(HITL_ENQUEUE_FAILED, true, e.to_string()));
+                let trace = trace.finish(HITL_ENQUEUE_FAILED);
+                audit_sink.record(
+                    AuditEvent::routing_denied(ctx, HITL_ENQUEUE_FAILED)
+                        .with_subject(shard.audit_subject())
+                        .with_trace(trace.clone()),
+                );
+                return (RouteDecision::Reject(HITL_ENQUEUE_FAILED), trace);
+            }
+        };
+        audit_sink.record(
+            AuditEvent::hitl_enqueued(ctx, &ticket.ticket_id, &trace.outcome)
+                .with_subject(shard.audit_subject())
+                .with_trace(trace.clone()),
+        );
+        (RouteDecision::QueueHitl(ticket), trace)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::audit::{event::AuditKind, sink::InMemoryAuditSink};
+    use crate::hitl::{HitlQueue, InMemoryHitlQueue};
+    use crate::aln::AlnShard;
+    use crate::nanopolygon::fixtures::{nanopolygon, shard};
+    use crate::nanopolygon::ValidatedNanopolygon;
+
+    fn shard_with(hitl_required: bool, impact: Sp80053Impact) -> AlnShard {
+        let mut np = nanopolygon();
+        np.metadata.ai_rmf_profile.requires_human_in_loop = hitl_required;
+        np.metadata.sp80053_profile.impact = impact;
+        AlnShard::new(shard().header, ValidatedNanopolygon::try_from(np).unwrap())
+    }
+
+    #[test]
+    fn queued_shards_are_audited_with_their_ticket() {
+        let queue = InMemoryHitlQueue::default();
+        let ctx = AuditContext::new(Uuid::nil());
+        let mut sink = InMemoryAuditSink::new();
+        let shard = shard();
+
+        let decision = RoutingContext { hooks: &queue }.decide(&shard, 0.5, &ctx, &mut sink);
+        let RouteDecision::QueueHitl(ticket) = decision else {
+            panic!("expected a HITL ticket, got {decision:?}");
+        };
+        assert!(queue.get(&ticket.ticket_id).unwrap().is_some());
+        let enqueued = sink
+            .events
+            .iter()
+            .find(|e| matches!(e.kind, AuditKind::HitlEnqueued { .. }))
+            .expect("HitlEnqueued recorded");
+        assert!(matches!(
+            &enqueued.kind,
+            AuditKind::HitlEnqueued { ticket_id, reason }
+                if *ticket_id == ticket.ticket_id && reason == "hitl_required_high_impact"
+        ));
+        assert_eq!(enqueued.subject, shard.audit_subject());
+    }
+
+    #[test]
+    fn hitl_reason_reflects_flag_and_impact() {
+        let queue = InMemoryHitlQueue::default();
+        let ctx = AuditContext::new(Uuid::nil());
+        let cases = [
+            (true, Sp80053Impact::High, "profile requires a human in the loop for high-impact shards"),
+            (true, Sp80053Impact::Moderate, "HITL flag set, but the shard is not high impact"),
+            (false, Sp80053Impact::High, "high-impact shard without a HITL flag"),
+            (false, Sp80053Impact::Low, "no HITL flag and not a high-impact shard"),
+        ];
+        for (hitl_required, impact, expected) in cases {
+            let shard = shard_with(hitl_required, impact);
+            let (_, trace) = RoutingContext { hooks: &queue }.decide_explained(
+                &shard,
+                0.5,
+                &ctx,
+                &mut InMemoryAuditSink::new(),
+            );
+            let step = trace.steps.iter().find(|s| s.rule == "hitl_required_high_impact").unwrap();
+            assert_eq!(step.reason, expected, "{hitl_required} / {impact:?}");
         }
+    }
+
+    #[test]
+    fn executed_shards_are_audited_with_the_trace() {
+        let queue = InMemoryHitlQueue::default();
+        let ctx = AuditContext::new(Uuid::nil());
+        let mut sink = InMemoryAuditSink::new();
+        let shard = shard_with(false, Sp80053Impact::Moderate);
+
+        let (decision, trace) =
+            RoutingContext { hooks: &queue }.decide_explained(&shard, 0.99, &ctx, &mut sink);
+        assert!(matches!(decision, RouteDecision::Execute));
+        let [event] = sink.events.as_slice() else {
+            panic!("expected one event, got {:?}", sink.events);
+        };
+        assert!(matches!(&event.kind, AuditKind::RoutingAllowed { label } if label == "execute"));
+        assert_eq!(event.subject, shard.audit_subject());
+        assert_eq!(event.trace.as_ref(), Some(&trace));
+    }
+
+    #[test]
+    fn enqueued_events_carry_the_trace() {
+        let queue = InMemoryHitlQueue::default();
+        let ctx = AuditContext::new(Uuid::nil());
+        let mut sink = InMemoryAuditSink::new();
 
-        RouteDecision::Execute
+        let (_, trace) =
+            RoutingContext { hooks: &queue }.decide_explained(&shard(), 0.95, &ctx, &mut sink);
+        assert_eq!(trace.outcome, "equal_power_threshold");
+        assert_eq!(sink.events[0].trace.as_ref(), Some(&trace));
     }
 }
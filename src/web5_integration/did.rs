@@ -1,17 +1,193 @@
-#[derive(Clone, Debug)]
+#[cfg(feature = "ml-dsa")]
+use super::pqc::Signer;
+use super::pqc::{verify_with_public_key, SignatureAlgorithm, SignatureError, Verifier};
+
+/// Multicodec code for an ML‑DSA‑65 public key (`mldsa-65-pub`).
+pub const MLDSA65_PUB_MULTICODEC: u64 = 0x1211;
+
+/// Encode a public key as a base58btc Multikey (`z` + multicodec varint + key).
+pub fn encode_multikey(codec: u64, public_key: &[u8]) -> String {
+    let mut bytes = Vec::with_capacity(public_key.len() + 3);
+    let mut code = codec;
+    loop {
+        let byte = (code & 0x7f) as u8;
+        code >>= 7;
+        if code == 0 {
+            bytes.push(byte);
+            break;
+        }
+        bytes.push(byte | 0x80);
+    }
+    bytes.extend_from_slice(public_key);
+    format!("z{}", bs58::encode(bytes).into_string())
+}
+
+/// Split a base58btc Multikey into its multicodec code and raw public key.
+pub fn decode_multikey(multikey: &str) -> Result<(u64, Vec<u8>), SignatureError> {
+    let encoded = multikey
+        .strip_prefix('z')
+        .ok_or(SignatureError::MalformedKey("not a base58btc multikey"))?;
+    let bytes = bs58::decode(encoded)
+        .into_vec()
+        .map_err(|_| SignatureError::MalformedKey("invalid base58btc"))?;
+
+    let mut codec = 0u64;
+    for (i, byte) in bytes.iter().enumerate().take(9) {
+        codec |= u64::from(byte & 0x7f) << (7 * i);
+        if byte & 0x80 == 0 {
+            return Ok((codec, bytes[i + 1..].to_vec()));
+        }
+    }
+    Err(SignatureError::MalformedKey("invalid multicodec prefix"))
+}
+
+/// Encode a public key as a `did:key`.
+pub fn did_key_from_public_key(codec: u64, public_key: &[u8]) -> String {
+    format!("did:key:{}", encode_multikey(codec, public_key))
+}
+
+/// Split a `did:key` into its multicodec code and raw public key.
+pub fn decode_did_key(did: &str) -> Result<(u64, Vec<u8>), SignatureError> {
+    let multikey = did
+        .strip_prefix("did:key:")
+        .ok_or(SignatureError::MalformedKey("not a did:key"))?;
+    decode_multikey(multikey.split('#').next().unwrap_or(multikey))
+}
+
+/// Algorithm implied by a `did:key` multicodec, if supported.
+pub fn did_key_algorithm(codec: u64) -> Option<SignatureAlgorithm> {
+    match codec {
+        MLDSA65_PUB_MULTICODEC => Some(SignatureAlgorithm::MlDsa65),
+        _ => None,
+    }
+}
+
+/// ML‑DSA‑65 identity whose DID is the `did:key` of its public key. The
+/// secret key is zeroized on drop (ml-dsa `zeroize` feature) and never
+/// printed by `Debug`.
+#[cfg(feature = "ml-dsa")]
+#[derive(Clone)]
 pub struct DidKeyPair {
     pub did: String,
-    // opaque handles to ML-DSA / ML-KEM keys
+    signing_key: ml_dsa::SigningKey<ml_dsa::MlDsa65>,
 }
 
+#[cfg(feature = "ml-dsa")]
 impl DidKeyPair {
+    pub fn generate() -> Self {
+        use ml_dsa::Generate;
+        Self::from_signing_key(ml_dsa::SigningKey::generate())
+    }
+
+    /// Deterministic key from a 32‑byte FIPS 204 seed (keep it secret).
+    pub fn from_seed(seed: &[u8; 32]) -> Self {
+        Self::from_signing_key(ml_dsa::SigningKey::from_seed(&(*seed).into()))
+    }
+
+    fn from_signing_key(signing_key: ml_dsa::SigningKey<ml_dsa::MlDsa65>) -> Self {
+        use ml_dsa::Keypair;
+        let public_key = signing_key.verifying_key().encode();
+        DidKeyPair {
+            did: did_key_from_public_key(MLDSA65_PUB_MULTICODEC, &public_key),
+            signing_key,
+        }
+    }
+
+    pub fn public_key(&self) -> Vec<u8> {
+        use ml_dsa::Keypair;
+        self.signing_key.verifying_key().encode().to_vec()
+    }
+
     pub fn sign_ml_dsa(&self, msg: &[u8]) -> Vec<u8> {
-        // call into ML-DSA library
-        unimplemented!()
+        use ml_dsa::Signer as _;
+        self.signing_key.sign(msg).encode().to_vec()
+    }
+}
+
+#[cfg(feature = "ml-dsa")]
+impl std::fmt::Debug for DidKeyPair {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DidKeyPair")
+            .field("did", &self.did)
+            .field("public_key", &hex::encode(self.public_key()))
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "ml-dsa")]
+impl Signer for DidKeyPair {
+    fn did(&self) -> &str {
+        &self.did
+    }
+
+    fn algorithm(&self) -> SignatureAlgorithm {
+        SignatureAlgorithm::MlDsa65
+    }
+
+    fn sign(&self, msg: &[u8]) -> Result<Vec<u8>, SignatureError> {
+        Ok(self.sign_ml_dsa(msg))
+    }
+}
+
+/// Verifies signatures from self‑describing `did:key` identities.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DidKeyVerifier;
+
+impl Verifier for DidKeyVerifier {
+    fn verify(
+        &self,
+        did: &str,
+        algorithm: SignatureAlgorithm,
+        msg: &[u8],
+        sig: &[u8],
+    ) -> Result<(), SignatureError> {
+        let (codec, public_key) = decode_did_key(did)?;
+        if did_key_algorithm(codec) != Some(algorithm) {
+            return Err(SignatureError::MalformedKey("key type does not match algorithm"));
+        }
+        verify_with_public_key(algorithm, &public_key, msg, sig)
     }
 }
 
 pub fn verify_ml_dsa(did: &str, msg: &[u8], sig: &[u8]) -> bool {
-    // resolve DID Document, extract ML-DSA pubkey, verify
-    unimplemented!()
+    DidKeyVerifier
+        .verify(did, SignatureAlgorithm::MlDsa65, msg, sig)
+        .is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn multikey_round_trips() {
+        let did = did_key_from_public_key(MLDSA65_PUB_MULTICODEC, &[1, 2, 3]);
+        assert_eq!(decode_did_key(&did).unwrap(), (MLDSA65_PUB_MULTICODEC, vec![1, 2, 3]));
+        assert_eq!(decode_did_key(&format!("{did}#key-1")).unwrap().1, vec![1, 2, 3]);
+        assert!(decode_did_key("did:web:example.org").is_err());
+        assert!(decode_multikey("m123").is_err());
+    }
+
+    #[cfg(feature = "ml-dsa")]
+    #[test]
+    fn debug_shows_identity_but_not_the_secret() {
+        let seed = [0xa5; 32];
+        let key = DidKeyPair::from_seed(&seed);
+        let debug = format!("{key:?}");
+        assert!(debug.contains(&key.did));
+        assert!(debug.contains(&hex::encode(key.public_key())));
+        assert!(!debug.contains("signing_key"));
+        assert!(!debug.contains(&hex::encode(seed)));
+    }
+
+    #[cfg(feature = "ml-dsa")]
+    #[test]
+    fn signatures_verify_only_for_the_signing_did() {
+        let key = DidKeyPair::from_seed(&[1; 32]);
+        let other = DidKeyPair::from_seed(&[2; 32]);
+        let sig = key.sign_ml_dsa(b"msg");
+        assert!(verify_ml_dsa(&key.did, b"msg", &sig));
+        assert!(!verify_ml_dsa(&key.did, b"other", &sig));
+        assert!(!verify_ml_dsa(&other.did, b"msg", &sig));
+    }
 }
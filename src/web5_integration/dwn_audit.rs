@@ -1,5 +1,5 @@
 use crate::governance_hooks::{AppealDecision, HitlTicket};
-use crate::aln_shard::AlnShard;
+use crate::aln::AlnShard;
 
 pub trait DwnAudit {
     fn append_hitl_event(&self, ticket: &HitlTicket, shard: &AlnShard, confidence: f64);
@@ -0,0 +1,6 @@
+pub mod did;
+pub mod dwn_audit;
+pub mod multisig;
+pub mod pqc;
+pub mod resolver;
+pub mod vc;
@@ -0,0 +1,382 @@
+use std::collections::BTreeSet;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use super::pqc::{SignatureBlock, SignatureError, Signer, Verifier};
+use super::vc::{OperatorCredential, OperatorRole};
+use crate::canonical::{canonical_sha256, to_canonical_bytes, CanonicalError};
+
+/// Who may contribute a signature to a multisig approval.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+pub enum EligibleSigner {
+    /// A specific DID.
+    Did(String),
+    /// Any DID holding a currently valid operator credential with this role.
+    Role(OperatorRole),
+}
+
+impl EligibleSigner {
+    /// Whether `did` matches this entry at `now`; role entries need a
+    /// credential for `did` with the role that is valid at `now`.
+    pub fn admits(&self, did: &str, credentials: &[OperatorCredential], now: DateTime<Utc>) -> bool {
+        match self {
+            EligibleSigner::Did(d) => d == did,
+            EligibleSigner::Role(role) => credentials
+                .iter()
+                .any(|c| c.did == did && c.has_role(role) && c.is_valid_at(now)),
+        }
+    }
+}
+
+/// k‑of‑n approval policy: at least `threshold` distinct eligible DIDs must
+/// sign. `ConsentLedger` requires an approval under the polygon's policy
+/// for community consent (see `CommunityApproval`).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct MultisigPolicy {
+    pub policy_id: String,
+    pub signers: Vec<EligibleSigner>,
+    pub threshold: usize,
+}
+
+/// Partial signatures collected over one action. Every signature covers the
+/// canonical bytes of `{policy_id, action_digest, expires_at}`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MultisigApproval {
+    pub policy_id: String,
+    /// Hex SHA‑256 of the RFC 8785 canonical JSON of the approved action.
+    pub action_digest: String,
+    pub expires_at: DateTime<Utc>,
+    #[serde(default)]
+    pub signatures: Vec<SignatureBlock>,
+}
+
+#[derive(Serialize)]
+struct SignedApproval<'a> {
+    policy_id: &'a str,
+    action_digest: &'a str,
+    expires_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Error)]
+pub enum MultisigError {
+    #[error("multisig policy `{0}` is unsatisfiable: {1}")]
+    InvalidPolicy(String, &'static str),
+    #[error("approval is for policy `{got}`, expected `{expected}`")]
+    PolicyMismatch { expected: String, got: String },
+    #[error("approval does not cover this action")]
+    DigestMismatch,
+    #[error("approval expired at {0}")]
+    Expired(DateTime<Utc>),
+    #[error("{required} approval(s) required, {} valid: {approved:?}", approved.len())]
+    ThresholdNotMet {
+        required: usize,
+        approved: Vec<String>,
+    },
+    #[error("could not encode action: {0}")]
+    Encoding(#[from] CanonicalError),
+    #[error(transparent)]
+    Signature(#[from] SignatureError),
+}
+
+impl MultisigError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            MultisigError::InvalidPolicy(..) => "invalid_multisig_policy",
+            MultisigError::PolicyMismatch { .. } => "multisig_policy_mismatch",
+            MultisigError::DigestMismatch => "multisig_digest_mismatch",
+            MultisigError::Expired(_) => "multisig_expired",
+            MultisigError::ThresholdNotMet { .. } => "multisig_threshold_not_met",
+            MultisigError::Encoding(_) => "multisig_encoding_failed",
+            MultisigError::Signature(_) => "signature_invalid",
+        }
+    }
+}
+
+/// Hex SHA‑256 over the canonical JSON of `action`: what signers approve.
+pub fn action_digest<T: Serialize + ?Sized>(action: &T) -> Result<String, CanonicalError> {
+    canonical_sha256(action)
+}
+
+impl MultisigPolicy {
+    /// Rejects policies that can never be met.
+    pub fn validate(&self) -> Result<(), MultisigError> {
+        let invalid = |why| Err(MultisigError::InvalidPolicy(self.policy_id.clone(), why));
+        if self.threshold == 0 {
+            return invalid("threshold must be at least 1");
+        }
+        if self.signers.is_empty() {
+            return invalid("no eligible signers");
+        }
+        let only_dids = self
+            .signers
+            .iter()
+            .all(|s| matches!(s, EligibleSigner::Did(_)));
+        let distinct_dids = self
+            .signers
+            .iter()
+            .filter_map(|s| match s {
+                EligibleSigner::Did(did) => Some(did.as_str()),
+                EligibleSigner::Role(_) => None,
+            })
+            .collect::<BTreeSet<_>>()
+            .len();
+        if only_dids && distinct_dids < self.threshold {
+            return invalid("threshold exceeds the number of eligible DIDs");
+        }
+        Ok(())
+    }
+
+    /// Starts an unsigned approval for `action`.
+    pub fn request<T: Serialize + ?Sized>(
+        &self,
+        action: &T,
+        expires_at: DateTime<Utc>,
+    ) -> Result<MultisigApproval, MultisigError> {
+        self.validate()?;
+        Ok(MultisigApproval {
+            policy_id: self.policy_id.clone(),
+            action_digest: action_digest(action)?,
+            expires_at,
+            signatures: Vec::new(),
+        })
+    }
+
+    fn is_eligible(&self, did: &str, credentials: &[OperatorCredential], now: DateTime<Utc>) -> bool {
+        self.signers
+            .iter()
+            .any(|signer| signer.admits(did, credentials, now))
+    }
+
+    /// Checks `approval` against `action` at `now` and returns the distinct
+    /// DIDs whose signatures verified and who are eligible, sorted.
+    /// `credentials` must already be verified (see `verify_operator_vc`);
+    /// they are only used to resolve role signers. Signatures that fail
+    /// verification or come from ineligible DIDs do not count.
+    pub fn verify<T, V>(
+        &self,
+        approval: &MultisigApproval,
+        action: &T,
+        verifier: &V,
+        credentials: &[OperatorCredential],
+        now: DateTime<Utc>,
+    ) -> Result<Vec<String>, MultisigError>
+    where
+        T: Serialize + ?Sized,
+        V: Verifier,
+    {
+        self.validate()?;
+        if approval.policy_id != self.policy_id {
+            return Err(MultisigError::PolicyMismatch {
+                expected: self.policy_id.clone(),
+                got: approval.policy_id.clone(),
+            });
+        }
+        if approval.action_digest != action_digest(action)? {
+            return Err(MultisigError::DigestMismatch);
+        }
+        if now >= approval.expires_at {
+            return Err(MultisigError::Expired(approval.expires_at));
+        }
+
+        let payload = approval.signing_payload()?;
+        let approved: Vec<String> = approval
+            .signatures
+            .iter()
+            .filter(|block| self.is_eligible(&block.signer_did, credentials, now))
+            .filter(|block| block.verify(verifier, &payload).is_ok())
+            .map(|block| block.signer_did.clone())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+
+        if approved.len() < self.threshold {
+            return Err(MultisigError::ThresholdNotMet {
+                required: self.threshold,
+                approved,
+            });
+        }
+        Ok(approved)
+    }
+}
+
+impl MultisigApproval {
+    pub fn signing_payload(&self) -> Result<Vec<u8>, CanonicalError> {
+        to_canonical_bytes(&SignedApproval {
+            policy_id: &self.policy_id,
+            action_digest: &self.action_digest,
+            expires_at: self.expires_at,
+        })
+    }
+
+    /// Adds `signer`'s partial signature, replacing any earlier one from
+    /// the same DID.
+    pub fn sign<S: Signer>(&mut self, signer: &S) -> Result<(), MultisigError> {
+        let block = SignatureBlock::sign(signer, &self.signing_payload()?)?;
+        self.add_signature(block);
+        Ok(())
+    }
+
+    /// Adds a partial signature collected elsewhere. It is not checked
+    /// until `MultisigPolicy::verify`.
+    pub fn add_signature(&mut self, block: SignatureBlock) {
+        self.signatures.retain(|s| s.signer_did != block.signer_did);
+        self.signatures.push(block);
+    }
+
+    pub fn signer_dids(&self) -> impl Iterator<Item = &str> {
+        self.signatures.iter().map(|s| s.signer_did.as_str())
+    }
+}
+
+#[cfg(all(test, feature = "ml-dsa"))]
+mod tests {
+    use chrono::Duration;
+    use serde_json::json;
+
+    use super::*;
+    use crate::web5_integration::did::{DidKeyPair, DidKeyVerifier};
+
+    fn key(n: u8) -> DidKeyPair {
+        DidKeyPair::from_seed(&[n; 32])
+    }
+
+    fn policy(signers: Vec<EligibleSigner>, threshold: usize) -> MultisigPolicy {
+        MultisigPolicy {
+            policy_id: "p".to_string(),
+            signers,
+            threshold,
+        }
+    }
+
+    fn did_policy(threshold: usize) -> MultisigPolicy {
+        policy((1..=3).map(|n| EligibleSigner::Did(key(n).did)).collect(), threshold)
+    }
+
+    fn action() -> serde_json::Value {
+        json!({"polygon_id": "0f1e", "scope": "aln_routing"})
+    }
+
+    fn approval(policy: &MultisigPolicy, signers: &[u8], now: DateTime<Utc>) -> MultisigApproval {
+        let mut approval = policy.request(&action(), now + Duration::hours(1)).unwrap();
+        for &n in signers {
+            approval.sign(&key(n)).unwrap();
+        }
+        approval
+    }
+
+    #[test]
+    fn unsatisfiable_policies_are_rejected() {
+        assert!(did_policy(0).validate().is_err());
+        assert!(did_policy(4).validate().is_err());
+        assert!(policy(vec![], 1).validate().is_err());
+        let repeated = policy(vec![EligibleSigner::Did(key(1).did); 2], 2);
+        assert_eq!(repeated.validate().unwrap_err().code(), "invalid_multisig_policy");
+        assert!(policy(vec![EligibleSigner::Role(OperatorRole::EcoCouncil)], 5).validate().is_ok());
+    }
+
+    #[test]
+    fn threshold_counts_distinct_eligible_signers() {
+        let now = Utc::now();
+        let policy = did_policy(2);
+
+        let err = policy
+            .verify(&approval(&policy, &[1, 4], now), &action(), &DidKeyVerifier, &[], now)
+            .unwrap_err();
+        assert!(matches!(err, MultisigError::ThresholdNotMet { required: 2, ref approved } if approved.len() == 1));
+
+        let approved = policy
+            .verify(&approval(&policy, &[3, 1], now), &action(), &DidKeyVerifier, &[], now)
+            .unwrap();
+        let mut expected = vec![key(1).did, key(3).did];
+        expected.sort();
+        assert_eq!(approved, expected);
+    }
+
+    #[test]
+    fn duplicate_signatures_count_once() {
+        let now = Utc::now();
+        let policy = did_policy(2);
+        let mut approval = approval(&policy, &[1, 1], now);
+        assert_eq!(approval.signatures.len(), 1);
+
+        let copy = approval.signatures[0].clone();
+        approval.signatures.push(copy);
+        let err = policy
+            .verify(&approval, &action(), &DidKeyVerifier, &[], now)
+            .unwrap_err();
+        assert!(matches!(err, MultisigError::ThresholdNotMet { ref approved, .. } if approved.len() == 1));
+    }
+
+    #[test]
+    fn expired_approvals_are_rejected() {
+        let now = Utc::now();
+        let policy = did_policy(1);
+        let approval = approval(&policy, &[1], now);
+        let err = policy
+            .verify(&approval, &action(), &DidKeyVerifier, &[], approval.expires_at)
+            .unwrap_err();
+        assert_eq!(err.code(), "multisig_expired");
+    }
+
+    #[test]
+    fn approvals_only_cover_their_action_and_policy() {
+        let now = Utc::now();
+        let policy = did_policy(1);
+        let approval = approval(&policy, &[1], now);
+        let other = json!({"polygon_id": "0f1e", "scope": "actuation:cooling"});
+        let err = policy
+            .verify(&approval, &other, &DidKeyVerifier, &[], now)
+            .unwrap_err();
+        assert!(matches!(err, MultisigError::DigestMismatch));
+
+        let mut renamed = did_policy(1);
+        renamed.policy_id = "q".to_string();
+        let err = renamed
+            .verify(&approval, &action(), &DidKeyVerifier, &[], now)
+            .unwrap_err();
+        assert_eq!(err.code(), "multisig_policy_mismatch");
+
+        let mut tampered = approval.clone();
+        tampered.expires_at += Duration::days(30);
+        let err = policy
+            .verify(&tampered, &action(), &DidKeyVerifier, &[], now)
+            .unwrap_err();
+        assert!(matches!(err, MultisigError::ThresholdNotMet { ref approved, .. } if approved.is_empty()));
+    }
+
+    #[test]
+    fn role_signers_need_a_credential_valid_now() {
+        let now = Utc::now();
+        let policy = policy(vec![EligibleSigner::Role(OperatorRole::EcoCouncil)], 2);
+        let credential = |n: u8, role, valid_until| OperatorCredential {
+            did: key(n).did,
+            issuer: "did:web:issuer.example".to_string(),
+            roles: vec![role],
+            valid_from: None,
+            valid_until,
+        };
+        let approval = approval(&policy, &[1, 2, 3], now);
+
+        let credentials = [
+            credential(1, OperatorRole::EcoCouncil, None),
+            credential(2, OperatorRole::EthicsPanel, None),
+            credential(3, OperatorRole::EcoCouncil, Some(now - Duration::minutes(1))),
+        ];
+        let err = policy
+            .verify(&approval, &action(), &DidKeyVerifier, &credentials, now)
+            .unwrap_err();
+        assert!(matches!(err, MultisigError::ThresholdNotMet { ref approved, .. } if *approved == [key(1).did]));
+
+        let credentials = [
+            credential(1, OperatorRole::EcoCouncil, None),
+            credential(3, OperatorRole::EcoCouncil, Some(now + Duration::minutes(1))),
+        ];
+        let approved = policy
+            .verify(&approval, &action(), &DidKeyVerifier, &credentials, now)
+            .unwrap();
+        assert_eq!(approved.len(), 2);
+    }
+}
@@ -0,0 +1,127 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::canonical::CanonicalError;
+
+/// Signature suites understood by the crate's verifiers.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum SignatureAlgorithm {
+    /// FIPS 204 ML‑DSA‑65, deterministic pure mode, empty context string.
+    #[serde(rename = "ML-DSA-65")]
+    MlDsa65,
+}
+
+impl SignatureAlgorithm {
+    pub fn name(&self) -> &'static str {
+        match self {
+            SignatureAlgorithm::MlDsa65 => "ML-DSA-65",
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum SignatureError {
+    #[error("signature algorithm {0} is not available in this build")]
+    UnsupportedAlgorithm(&'static str),
+    #[error("no verification key known for `{0}`")]
+    UnknownSigner(String),
+    #[error("malformed public key: {0}")]
+    MalformedKey(&'static str),
+    #[error("no signature present")]
+    Unsigned,
+    #[error("malformed signature")]
+    MalformedSignature,
+    #[error("signature does not verify")]
+    InvalidSignature,
+    #[error("signing backend failed: {0}")]
+    Backend(String),
+    #[error("signed payload has no canonical encoding: {0}")]
+    Payload(#[from] CanonicalError),
+}
+
+/// Detached signature carried next to the data it covers (shards, rights
+/// revisions, consent records).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SignatureBlock {
+    pub signer_did: String,
+    pub algorithm: SignatureAlgorithm,
+    /// Raw signature bytes, base64url (no padding) on the wire.
+    #[serde(with = "base64url")]
+    pub signature: Vec<u8>,
+}
+
+impl SignatureBlock {
+    pub fn sign<S: Signer>(signer: &S, msg: &[u8]) -> Result<Self, SignatureError> {
+        Ok(Self {
+            signer_did: signer.did().to_string(),
+            algorithm: signer.algorithm(),
+            signature: signer.sign(msg)?,
+        })
+    }
+
+    pub fn verify<V: Verifier>(&self, verifier: &V, msg: &[u8]) -> Result<(), SignatureError> {
+        verifier.verify(&self.signer_did, self.algorithm, msg, &self.signature)
+    }
+}
+
+/// Produces signatures on behalf of a DID. Implement this for HSM or
+/// remote‑KMS backed keys; `DidKeyPair` is the in‑process implementation.
+pub trait Signer {
+    fn did(&self) -> &str;
+    fn algorithm(&self) -> SignatureAlgorithm;
+    fn sign(&self, msg: &[u8]) -> Result<Vec<u8>, SignatureError>;
+}
+
+/// Checks a signature attributed to a DID.
+pub trait Verifier {
+    fn verify(
+        &self,
+        did: &str,
+        algorithm: SignatureAlgorithm,
+        msg: &[u8],
+        sig: &[u8],
+    ) -> Result<(), SignatureError>;
+}
+
+/// Verify `sig` over `msg` with a raw encoded public key.
+pub fn verify_with_public_key(
+    algorithm: SignatureAlgorithm,
+    public_key: &[u8],
+    msg: &[u8],
+    sig: &[u8],
+) -> Result<(), SignatureError> {
+    match algorithm {
+        #[cfg(feature = "ml-dsa")]
+        SignatureAlgorithm::MlDsa65 => {
+            use ml_dsa::{EncodedVerifyingKey, MlDsa65, Signature, Verifier as _, VerifyingKey};
+
+            let encoded = EncodedVerifyingKey::<MlDsa65>::try_from(public_key)
+                .map_err(|_| SignatureError::MalformedKey("wrong ML-DSA-65 key length"))?;
+            let key = VerifyingKey::<MlDsa65>::decode(&encoded);
+            let sig = Signature::<MlDsa65>::try_from(sig)
+                .map_err(|_| SignatureError::MalformedSignature)?;
+            key.verify(msg, &sig)
+                .map_err(|_| SignatureError::InvalidSignature)
+        }
+        #[cfg(not(feature = "ml-dsa"))]
+        _ => {
+            let _ = (public_key, msg, sig);
+            Err(SignatureError::UnsupportedAlgorithm(algorithm.name()))
+        }
+    }
+}
+
+mod base64url {
+    use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+    use base64::Engine;
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&URL_SAFE_NO_PAD.encode(bytes))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        URL_SAFE_NO_PAD.decode(s).map_err(D::Error::custom)
+    }
+}
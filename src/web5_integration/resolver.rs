@@ -0,0 +1,520 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use super::did::{decode_multikey, did_key_algorithm};
+use super::pqc::{verify_with_public_key, SignatureAlgorithm, SignatureError, Verifier};
+
+#[derive(Debug, Error)]
+pub enum ResolveError {
+    #[error("`{0}` is not a valid DID")]
+    InvalidDid(String),
+    #[error("DID method `{0}` is not supported by this resolver")]
+    MethodNotSupported(String),
+    #[error("no DID document found for `{0}`")]
+    NotFound(String),
+    #[error("DID document for `{did}` is malformed: {reason}")]
+    Malformed { did: String, reason: String },
+    #[error("DID document store I/O failed: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// Multikey verification method from a DID Core document.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct VerificationMethod {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub method_type: String,
+    pub controller: String,
+    pub public_key_multibase: String,
+}
+
+impl VerificationMethod {
+    /// Algorithm and raw public key encoded in `publicKeyMultibase`.
+    pub fn public_key(&self) -> Result<(SignatureAlgorithm, Vec<u8>), SignatureError> {
+        let (codec, key) = decode_multikey(&self.public_key_multibase)?;
+        let algorithm = did_key_algorithm(codec)
+            .ok_or(SignatureError::MalformedKey("unsupported multikey codec"))?;
+        Ok((algorithm, key))
+    }
+}
+
+/// The subset of a DID Core document the crate's verifiers need.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DidDocument {
+    pub id: String,
+    #[serde(default)]
+    pub verification_method: Vec<VerificationMethod>,
+    /// References (by id) to methods allowed to sign assertions such as
+    /// shards and credentials.
+    #[serde(default)]
+    pub assertion_method: Vec<String>,
+}
+
+impl DidDocument {
+    /// Looks up a verification method by id. Both `id` and the methods'
+    /// own ids may be relative (`#key-1`) to the document id.
+    pub fn method(&self, id: &str) -> Option<&VerificationMethod> {
+        let id = self.absolute_id(id);
+        self.verification_method
+            .iter()
+            .find(|vm| self.absolute_id(&vm.id) == id)
+    }
+
+    /// Verification methods listed under `assertionMethod`.
+    pub fn assertion_methods(&self) -> impl Iterator<Item = &VerificationMethod> {
+        self.assertion_method
+            .iter()
+            .filter_map(move |id| self.method(id))
+    }
+
+    /// `id` resolved against the document id if it is a bare fragment.
+    pub fn absolute_id(&self, id: &str) -> String {
+        if id.starts_with('#') {
+            format!("{}{id}", self.id)
+        } else {
+            id.to_string()
+        }
+    }
+}
+
+pub trait DidResolver {
+    fn resolve(&self, did: &str) -> Result<DidDocument, ResolveError>;
+}
+
+/// Split `did:<method>:<id>[#fragment]` into method and method‑specific id.
+fn parse_did(did: &str) -> Result<(&str, &str), ResolveError> {
+    let did = did.split('#').next().unwrap_or(did);
+    let rest = did
+        .strip_prefix("did:")
+        .ok_or_else(|| ResolveError::InvalidDid(did.to_string()))?;
+    match rest.split_once(':') {
+        Some((method, id)) if !method.is_empty() && !id.is_empty() => Ok((method, id)),
+        _ => Err(ResolveError::InvalidDid(did.to_string())),
+    }
+}
+
+/// Self‑contained `did:key` resolution; no I/O.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct KeyDidResolver;
+
+impl DidResolver for KeyDidResolver {
+    fn resolve(&self, did: &str) -> Result<DidDocument, ResolveError> {
+        let (method, multikey) = parse_did(did)?;
+        if method != "key" {
+            return Err(ResolveError::MethodNotSupported(method.to_string()));
+        }
+        let did = format!("did:key:{multikey}");
+        decode_multikey(multikey).map_err(|e| ResolveError::Malformed {
+            did: did.clone(),
+            reason: e.to_string(),
+        })?;
+
+        let vm_id = format!("{did}#{multikey}");
+        Ok(DidDocument {
+            verification_method: vec![VerificationMethod {
+                id: vm_id.clone(),
+                method_type: "Multikey".to_string(),
+                controller: did.clone(),
+                public_key_multibase: multikey.to_string(),
+            }],
+            assertion_method: vec![vm_id],
+            id: did,
+        })
+    }
+}
+
+/// Offline stand‑in for `did:web`: documents are read from a directory that
+/// mirrors the HTTPS layout, e.g. `did:web:example.com:ops` →
+/// `<root>/example.com/ops/did.json` and `did:web:example.com` →
+/// `<root>/example.com/.well-known/did.json`.
+#[derive(Debug, Clone)]
+pub struct DirectoryWebDidResolver {
+    root: PathBuf,
+}
+
+impl DirectoryWebDidResolver {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    fn document_path(&self, did: &str, id: &str) -> Result<PathBuf, ResolveError> {
+        let mut path = self.root.clone();
+        let segments: Vec<String> = id.split(':').map(|s| s.replace("%3A", ":")).collect();
+        for segment in &segments {
+            if segment.is_empty()
+                || segment == "."
+                || segment == ".."
+                || segment.contains(['/', '\\'])
+            {
+                return Err(ResolveError::InvalidDid(did.to_string()));
+            }
+            path.push(segment);
+        }
+        if segments.len() == 1 {
+            path.push(".well-known");
+        }
+        path.push("did.json");
+        Ok(path)
+    }
+}
+
+impl DidResolver for DirectoryWebDidResolver {
+    fn resolve(&self, did: &str) -> Result<DidDocument, ResolveError> {
+        let (method, id) = parse_did(did)?;
+        if method != "web" {
+            return Err(ResolveError::MethodNotSupported(method.to_string()));
+        }
+        let did = format!("did:web:{id}");
+        let path = self.document_path(&did, id)?;
+        let bytes = match fs::read(&path) {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                return Err(ResolveError::NotFound(did));
+            }
+            Err(e) => return Err(e.into()),
+        };
+        let doc: DidDocument =
+            serde_json::from_slice(&bytes).map_err(|e| ResolveError::Malformed {
+                did: did.clone(),
+                reason: e.to_string(),
+            })?;
+        if doc.id != did {
+            return Err(ResolveError::Malformed {
+                reason: format!("document id `{}` does not match", doc.id),
+                did,
+            });
+        }
+        Ok(doc)
+    }
+}
+
+/// `did:key` plus directory‑backed `did:web`, for air‑gapped deployments.
+#[derive(Debug, Clone)]
+pub struct LocalDidResolver {
+    pub key: KeyDidResolver,
+    pub web: DirectoryWebDidResolver,
+}
+
+impl LocalDidResolver {
+    pub fn new(web_root: impl Into<PathBuf>) -> Self {
+        Self {
+            key: KeyDidResolver,
+            web: DirectoryWebDidResolver::new(web_root),
+        }
+    }
+}
+
+impl DidResolver for LocalDidResolver {
+    fn resolve(&self, did: &str) -> Result<DidDocument, ResolveError> {
+        match parse_did(did)?.0 {
+            "key" => self.key.resolve(did),
+            "web" => self.web.resolve(did),
+            other => Err(ResolveError::MethodNotSupported(other.to_string())),
+        }
+    }
+}
+
+/// Caches successful resolutions for `ttl`.
+pub struct CachingResolver<R: DidResolver> {
+    inner: R,
+    ttl: Duration,
+    cache: Mutex<HashMap<String, (Instant, DidDocument)>>,
+}
+
+impl<R: DidResolver> CachingResolver<R> {
+    pub fn new(inner: R, ttl: Duration) -> Self {
+        Self {
+            inner,
+            ttl,
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Drop a cached document, e.g. after a key rotation notice.
+    pub fn invalidate(&self, did: &str) {
+        self.cache.lock().expect("resolver cache poisoned").remove(did);
+    }
+
+    pub fn clear(&self) {
+        self.cache.lock().expect("resolver cache poisoned").clear();
+    }
+}
+
+impl<R: DidResolver> DidResolver for CachingResolver<R> {
+    fn resolve(&self, did: &str) -> Result<DidDocument, ResolveError> {
+        let did = did.split('#').next().unwrap_or(did);
+        if let Some((fetched_at, doc)) = self.cache.lock().expect("resolver cache poisoned").get(did) {
+            if fetched_at.elapsed() < self.ttl {
+                return Ok(doc.clone());
+            }
+        }
+
+        let doc = self.inner.resolve(did)?;
+        self.cache
+            .lock()
+            .expect("resolver cache poisoned")
+            .insert(did.to_string(), (Instant::now(), doc.clone()));
+        Ok(doc)
+    }
+}
+
+/// `Verifier` that looks up the signer's assertion keys through a resolver.
+/// `did` may be a bare DID (any assertion key) or a DID URL naming one
+/// verification method.
+pub struct ResolverVerifier<R: DidResolver> {
+    resolver: R,
+}
+
+impl<R: DidResolver> ResolverVerifier<R> {
+    pub fn new(resolver: R) -> Self {
+        Self { resolver }
+    }
+
+    pub fn resolver(&self) -> &R {
+        &self.resolver
+    }
+}
+
+impl<R: DidResolver> Verifier for ResolverVerifier<R> {
+    fn verify(
+        &self,
+        did: &str,
+        algorithm: SignatureAlgorithm,
+        msg: &[u8],
+        sig: &[u8],
+    ) -> Result<(), SignatureError> {
+        let doc = self
+            .resolver
+            .resolve(did)
+            .map_err(|_| SignatureError::UnknownSigner(did.to_string()))?;
+
+        let candidates: Vec<&VerificationMethod> = if did.contains('#') {
+            doc.assertion_methods()
+                .filter(|vm| doc.absolute_id(&vm.id) == did)
+                .collect()
+        } else {
+            doc.assertion_methods().collect()
+        };
+
+        // Methods with a key this build cannot decode, or of another
+        // algorithm, are skipped rather than failing the whole document.
+        let mut result = Err(SignatureError::UnknownSigner(did.to_string()));
+        for vm in candidates {
+            let Ok((vm_algorithm, key)) = vm.public_key() else {
+                continue;
+            };
+            if vm_algorithm != algorithm {
+                continue;
+            }
+            result = verify_with_public_key(algorithm, &key, msg, sig);
+            if result.is_ok() {
+                break;
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::web5_integration::did::{encode_multikey, MLDSA65_PUB_MULTICODEC};
+
+    /// Multicodec of an Ed25519 public key; not supported by this crate.
+    const ED25519_PUB_MULTICODEC: u64 = 0xed;
+
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new() -> Self {
+            Self(std::env::temp_dir().join(format!("ecoinfra-did-{}", Uuid::new_v4())))
+        }
+
+        fn write(&self, relative: &str, doc: &DidDocument) {
+            let path = self.0.join(relative);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, serde_json::to_vec(doc).unwrap()).unwrap();
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn method(id: &str, controller: &str, codec: u64, key: &[u8]) -> VerificationMethod {
+        VerificationMethod {
+            id: id.to_string(),
+            method_type: "Multikey".to_string(),
+            controller: controller.to_string(),
+            public_key_multibase: encode_multikey(codec, key),
+        }
+    }
+
+    /// `did:web:council.example` with an unsupported Ed25519 key listed
+    /// before `key`, all ids relative.
+    fn mixed_document(key: &[u8]) -> DidDocument {
+        let did = "did:web:council.example";
+        DidDocument {
+            id: did.to_string(),
+            verification_method: vec![
+                method("#ed", did, ED25519_PUB_MULTICODEC, &[7; 32]),
+                VerificationMethod {
+                    public_key_multibase: "not-a-multikey".to_string(),
+                    ..method("#broken", did, MLDSA65_PUB_MULTICODEC, &[])
+                },
+                method("#key-1", did, MLDSA65_PUB_MULTICODEC, key),
+            ],
+            assertion_method: vec!["#ed".to_string(), "#broken".to_string(), "#key-1".to_string()],
+        }
+    }
+
+    struct CountingResolver {
+        calls: Cell<usize>,
+    }
+
+    impl DidResolver for CountingResolver {
+        fn resolve(&self, did: &str) -> Result<DidDocument, ResolveError> {
+            self.calls.set(self.calls.get() + 1);
+            Ok(DidDocument {
+                id: did.to_string(),
+                verification_method: Vec::new(),
+                assertion_method: Vec::new(),
+            })
+        }
+    }
+
+    #[test]
+    fn relative_method_ids_resolve_against_the_document() {
+        let doc = mixed_document(&[1, 2, 3]);
+        let absolute = "did:web:council.example#key-1";
+        assert_eq!(doc.method("#key-1").unwrap().id, "#key-1");
+        assert_eq!(doc.method(absolute).unwrap().id, "#key-1");
+        assert!(doc.method("did:web:other.example#key-1").is_none());
+        assert_eq!(doc.assertion_methods().count(), 3);
+    }
+
+    #[test]
+    fn did_key_resolves_to_its_own_key() {
+        let multikey = encode_multikey(MLDSA65_PUB_MULTICODEC, &[9; 16]);
+        let did = format!("did:key:{multikey}");
+        let doc = KeyDidResolver.resolve(&format!("{did}#{multikey}")).unwrap();
+        assert_eq!(doc.id, did);
+        let vm = doc.assertion_methods().next().unwrap();
+        assert_eq!(vm.public_key().unwrap(), (SignatureAlgorithm::MlDsa65, vec![9; 16]));
+        assert!(matches!(
+            KeyDidResolver.resolve("did:web:council.example"),
+            Err(ResolveError::MethodNotSupported(_))
+        ));
+        assert!(matches!(
+            KeyDidResolver.resolve("did:key:m123"),
+            Err(ResolveError::Malformed { .. })
+        ));
+    }
+
+    #[test]
+    fn did_web_reads_the_https_layout_from_a_directory() {
+        let dir = TempDir::new();
+        let root = mixed_document(&[1]);
+        let mut ops = mixed_document(&[2]);
+        ops.id = "did:web:council.example:ops".to_string();
+        dir.write("council.example/.well-known/did.json", &root);
+        dir.write("council.example/ops/did.json", &ops);
+        let mut wrong = mixed_document(&[3]);
+        wrong.id = "did:web:elsewhere.example".to_string();
+        dir.write("council.example/spoof/did.json", &wrong);
+
+        let resolver = LocalDidResolver::new(&dir.0);
+        assert_eq!(resolver.resolve("did:web:council.example").unwrap(), root);
+        assert_eq!(resolver.resolve("did:web:council.example:ops#key-1").unwrap(), ops);
+        assert!(matches!(
+            resolver.resolve("did:web:council.example:missing"),
+            Err(ResolveError::NotFound(_))
+        ));
+        assert!(matches!(
+            resolver.resolve("did:web:council.example:spoof"),
+            Err(ResolveError::Malformed { .. })
+        ));
+        assert!(matches!(
+            resolver.resolve("did:web:council.example:.."),
+            Err(ResolveError::InvalidDid(_))
+        ));
+        assert!(matches!(
+            resolver.resolve("did:plc:abc"),
+            Err(ResolveError::MethodNotSupported(_))
+        ));
+    }
+
+    #[test]
+    fn cache_expires_after_ttl_and_on_invalidate() {
+        let counting = || CountingResolver { calls: Cell::new(0) };
+
+        let cached = CachingResolver::new(counting(), Duration::from_secs(3600));
+        cached.resolve("did:web:a.example").unwrap();
+        cached.resolve("did:web:a.example#key-1").unwrap();
+        assert_eq!(cached.inner.calls.get(), 1);
+        cached.invalidate("did:web:a.example");
+        cached.resolve("did:web:a.example").unwrap();
+        assert_eq!(cached.inner.calls.get(), 2);
+
+        let expired = CachingResolver::new(counting(), Duration::ZERO);
+        expired.resolve("did:web:a.example").unwrap();
+        expired.resolve("did:web:a.example").unwrap();
+        assert_eq!(expired.inner.calls.get(), 2);
+    }
+
+    #[cfg(feature = "ml-dsa")]
+    #[test]
+    fn did_key_signatures_verify_through_the_resolver() {
+        use crate::web5_integration::did::DidKeyPair;
+
+        let key = DidKeyPair::from_seed(&[3; 32]);
+        let sig = key.sign_ml_dsa(b"msg");
+        let verifier = ResolverVerifier::new(KeyDidResolver);
+        verifier.verify(&key.did, SignatureAlgorithm::MlDsa65, b"msg", &sig).unwrap();
+        assert!(matches!(
+            verifier.verify(&key.did, SignatureAlgorithm::MlDsa65, b"other", &sig),
+            Err(SignatureError::InvalidSignature)
+        ));
+    }
+
+    #[cfg(feature = "ml-dsa")]
+    #[test]
+    fn mixed_key_documents_skip_unusable_methods() {
+        use crate::web5_integration::did::DidKeyPair;
+
+        let key = DidKeyPair::from_seed(&[4; 32]);
+        let dir = TempDir::new();
+        dir.write("council.example/.well-known/did.json", &mixed_document(&key.public_key()));
+        let verifier = ResolverVerifier::new(LocalDidResolver::new(&dir.0));
+        let sig = key.sign_ml_dsa(b"msg");
+
+        let did = "did:web:council.example";
+        verifier.verify(did, SignatureAlgorithm::MlDsa65, b"msg", &sig).unwrap();
+        verifier
+            .verify(&format!("{did}#key-1"), SignatureAlgorithm::MlDsa65, b"msg", &sig)
+            .unwrap();
+        // Naming only the unsupported key finds no usable candidate.
+        assert!(matches!(
+            verifier.verify(&format!("{did}#ed"), SignatureAlgorithm::MlDsa65, b"msg", &sig),
+            Err(SignatureError::UnknownSigner(_))
+        ));
+        assert!(matches!(
+            verifier.verify(did, SignatureAlgorithm::MlDsa65, b"other", &sig),
+            Err(SignatureError::InvalidSignature)
+        ));
+    }
+}
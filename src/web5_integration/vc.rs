@@ -1,10 +1,723 @@
-#[derive(Clone, Debug)]
+use std::collections::HashMap;
+use std::fmt;
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+use super::pqc::{SignatureAlgorithm, SignatureError, Signer, Verifier};
+use crate::canonical::to_canonical_bytes;
+use crate::governance_guard::CallerRole;
+
+/// Data Integrity cryptosuite: ML‑DSA‑65 over RFC 8785 canonical JSON.
+pub const MLDSA65_JCS_CRYPTOSUITE: &str = "mldsa65-jcs-2024";
+
+/// Roles an operator credential can grant.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum OperatorRole {
+    OtOperator,
+    EcoCouncil,
+    EthicsPanel,
+    CommunityCouncil,
+    HitlReviewer,
+    Other(String),
+}
+
+impl From<String> for OperatorRole {
+    fn from(s: String) -> Self {
+        match s.as_str() {
+            "OTOperator" => OperatorRole::OtOperator,
+            "EcoCouncil" => OperatorRole::EcoCouncil,
+            "EthicsPanel" => OperatorRole::EthicsPanel,
+            "CommunityCouncil" => OperatorRole::CommunityCouncil,
+            "HITLReviewer" => OperatorRole::HitlReviewer,
+            _ => OperatorRole::Other(s),
+        }
+    }
+}
+
+impl From<OperatorRole> for String {
+    fn from(role: OperatorRole) -> Self {
+        role.to_string()
+    }
+}
+
+impl fmt::Display for OperatorRole {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            OperatorRole::OtOperator => "OTOperator",
+            OperatorRole::EcoCouncil => "EcoCouncil",
+            OperatorRole::EthicsPanel => "EthicsPanel",
+            OperatorRole::CommunityCouncil => "CommunityCouncil",
+            OperatorRole::HitlReviewer => "HITLReviewer",
+            OperatorRole::Other(s) => s,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct OperatorCredential {
     pub did: String,
-    pub roles: Vec<String>, // "OTOperator","EcoCouncil"
+    pub issuer: String,
+    pub roles: Vec<OperatorRole>,
+    pub valid_from: Option<DateTime<Utc>>,
+    pub valid_until: Option<DateTime<Utc>>,
 }
 
-pub fn verify_operator_vc(vc_bytes: &[u8]) -> Option<OperatorCredential> {
-    // parse VC, verify ML-DSA signature, extract claims
-    unimplemented!()
+impl OperatorCredential {
+    pub fn has_role(&self, role: &OperatorRole) -> bool {
+        self.roles.contains(role)
+    }
+
+    /// Whether `now` falls inside `[valid_from, valid_until)`.
+    pub fn is_valid_at(&self, now: DateTime<Utc>) -> bool {
+        self.valid_from.is_none_or(|t| t <= now) && self.valid_until.is_none_or(|t| now < t)
+    }
+
+    /// Roles in the form the `GovernanceGuard` checks constraints against.
+    pub fn caller_roles(&self) -> Vec<CallerRole> {
+        self.roles
+            .iter()
+            .map(|role| CallerRole {
+                did: self.did.clone(),
+                role: role.to_string(),
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum VcError {
+    #[error("malformed credential: {0}")]
+    Malformed(String),
+    #[error("credential is not an OperatorCredential")]
+    WrongType,
+    #[error("unsupported proof: {0}")]
+    UnsupportedProof(String),
+    #[error("proof key `{key}` is not controlled by issuer `{issuer}`")]
+    IssuerMismatch { key: String, issuer: String },
+    #[error("issuer signature invalid: {0}")]
+    Signature(#[from] SignatureError),
+    #[error("credential not valid before {0}")]
+    NotYetValid(DateTime<Utc>),
+    #[error("credential expired at {0}")]
+    Expired(DateTime<Utc>),
+    #[error("credential revoked via {list}[{index}]")]
+    Revoked { list: String, index: u64 },
+    #[error("status list `{0}` unavailable")]
+    StatusUnavailable(String),
+    #[error("JWT claim `{claim}` disagrees with the credential's `{field}`")]
+    ClaimMismatch {
+        claim: &'static str,
+        field: &'static str,
+    },
+}
+
+/// Source of W3C Bitstring Status List bits.
+pub trait StatusListProvider {
+    /// `true` if bit `index` of the list at `list_url` is set.
+    fn is_set(&self, list_url: &str, index: u64) -> Result<bool, VcError>;
+}
+
+/// In‑memory status lists (uncompressed bitstrings, index 0 is the most
+/// significant bit of the first byte).
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryStatusLists {
+    lists: HashMap<String, Vec<u8>>,
+}
+
+impl InMemoryStatusLists {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, list_url: &str, bitstring: Vec<u8>) {
+        self.lists.insert(list_url.to_string(), bitstring);
+    }
+
+    pub fn set(&mut self, list_url: &str, index: u64) {
+        let bits = self.lists.entry(list_url.to_string()).or_default();
+        let byte = (index / 8) as usize;
+        if bits.len() <= byte {
+            bits.resize(byte + 1, 0);
+        }
+        bits[byte] |= 0x80 >> (index % 8);
+    }
+}
+
+impl StatusListProvider for InMemoryStatusLists {
+    fn is_set(&self, list_url: &str, index: u64) -> Result<bool, VcError> {
+        let bits = self
+            .lists
+            .get(list_url)
+            .ok_or_else(|| VcError::StatusUnavailable(list_url.to_string()))?;
+        let byte = bits
+            .get((index / 8) as usize)
+            .ok_or_else(|| VcError::StatusUnavailable(list_url.to_string()))?;
+        Ok(byte & (0x80 >> (index % 8)) != 0)
+    }
+}
+
+/// Verify a VC Data Model 2.0 operator credential, either as JSON secured
+/// with a Data Integrity proof or as a compact JWT, and extract its claims.
+pub fn verify_operator_vc<V, S>(
+    vc_bytes: &[u8],
+    verifier: &V,
+    status_lists: &S,
+    now: DateTime<Utc>,
+) -> Result<OperatorCredential, VcError>
+where
+    V: Verifier,
+    S: StatusListProvider,
+{
+    let text = std::str::from_utf8(vc_bytes)
+        .map_err(|_| VcError::Malformed("not UTF-8".to_string()))?
+        .trim();
+
+    let credential = if text.starts_with('{') {
+        let document: Map<String, Value> = serde_json::from_str(text)
+            .map_err(|e| VcError::Malformed(e.to_string()))?;
+        verify_data_integrity(document, verifier)?
+    } else {
+        verify_jwt(text, verifier)?
+    };
+
+    let credential = parse_credential(&credential)?;
+    if let Some(valid_from) = credential.operator.valid_from {
+        if now < valid_from {
+            return Err(VcError::NotYetValid(valid_from));
+        }
+    }
+    if let Some(valid_until) = credential.operator.valid_until {
+        if now >= valid_until {
+            return Err(VcError::Expired(valid_until));
+        }
+    }
+    for (list, index) in &credential.status {
+        if status_lists.is_set(list, *index)? {
+            return Err(VcError::Revoked {
+                list: list.clone(),
+                index: *index,
+            });
+        }
+    }
+
+    Ok(credential.operator)
+}
+
+/// Attach an `mldsa65-jcs-2024` Data Integrity proof to an unsecured
+/// credential. `verification_method` must be one of the signer's assertion
+/// keys.
+pub fn add_data_integrity_proof<S: Signer>(
+    mut credential: Map<String, Value>,
+    signer: &S,
+    verification_method: &str,
+    created: DateTime<Utc>,
+) -> Result<Map<String, Value>, VcError> {
+    credential.remove("proof");
+    let mut proof = Map::new();
+    proof.insert("type".into(), "DataIntegrityProof".into());
+    proof.insert("cryptosuite".into(), MLDSA65_JCS_CRYPTOSUITE.into());
+    proof.insert("verificationMethod".into(), verification_method.into());
+    proof.insert("proofPurpose".into(), "assertionMethod".into());
+    proof.insert(
+        "created".into(),
+        created.to_rfc3339_opts(chrono::SecondsFormat::Secs, true).into(),
+    );
+
+    let signature = signer.sign(&proof_input(&proof, &credential)?)?;
+    proof.insert(
+        "proofValue".into(),
+        format!("z{}", bs58::encode(signature).into_string()).into(),
+    );
+    credential.insert("proof".into(), Value::Object(proof));
+    Ok(credential)
+}
+
+// hash(canonical(proof config)) || hash(canonical(unsecured document))
+fn proof_input(
+    proof_config: &Map<String, Value>,
+    document: &Map<String, Value>,
+) -> Result<Vec<u8>, VcError> {
+    let canonical = |v: &Map<String, Value>| {
+        to_canonical_bytes(v).map_err(|e| VcError::Malformed(e.to_string()))
+    };
+    let mut input = Sha256::digest(canonical(proof_config)?).to_vec();
+    input.extend_from_slice(&Sha256::digest(canonical(document)?));
+    Ok(input)
+}
+
+fn verify_data_integrity<V: Verifier>(
+    mut document: Map<String, Value>,
+    verifier: &V,
+) -> Result<Map<String, Value>, VcError> {
+    let mut proof = match document.remove("proof") {
+        Some(Value::Object(proof)) => proof,
+        Some(_) => return Err(VcError::UnsupportedProof("proof sets are not supported".into())),
+        None => return Err(VcError::UnsupportedProof("missing proof".into())),
+    };
+
+    let cryptosuite = proof.get("cryptosuite").and_then(Value::as_str);
+    if proof.get("type").and_then(Value::as_str) != Some("DataIntegrityProof")
+        || cryptosuite != Some(MLDSA65_JCS_CRYPTOSUITE)
+    {
+        return Err(VcError::UnsupportedProof(format!(
+            "cryptosuite {}",
+            cryptosuite.unwrap_or("<none>")
+        )));
+    }
+    if proof.get("proofPurpose").and_then(Value::as_str) != Some("assertionMethod") {
+        return Err(VcError::UnsupportedProof("proofPurpose must be assertionMethod".into()));
+    }
+
+    let proof_value = match proof.remove("proofValue") {
+        Some(Value::String(v)) => v,
+        _ => return Err(VcError::Malformed("missing proofValue".into())),
+    };
+    let signature = proof_value
+        .strip_prefix('z')
+        .and_then(|v| bs58::decode(v).into_vec().ok())
+        .ok_or_else(|| VcError::Malformed("proofValue is not base58btc".into()))?;
+    let key = proof
+        .get("verificationMethod")
+        .and_then(Value::as_str)
+        .ok_or_else(|| VcError::Malformed("missing verificationMethod".into()))?
+        .to_string();
+
+    check_issuer_key(&document, &key)?;
+    verifier.verify(
+        &key,
+        SignatureAlgorithm::MlDsa65,
+        &proof_input(&proof, &document)?,
+        &signature,
+    )?;
+    Ok(document)
+}
+
+fn verify_jwt<V: Verifier>(token: &str, verifier: &V) -> Result<Map<String, Value>, VcError> {
+    let mut parts = token.split('.');
+    let (Some(header_b64), Some(payload_b64), Some(sig_b64), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return Err(VcError::Malformed("not a compact JWS".into()));
+    };
+
+    let decode = |part: &str| {
+        URL_SAFE_NO_PAD
+            .decode(part)
+            .map_err(|e| VcError::Malformed(format!("base64url: {e}")))
+    };
+    let json = |bytes: Vec<u8>| {
+        serde_json::from_slice::<Map<String, Value>>(&bytes)
+            .map_err(|e| VcError::Malformed(e.to_string()))
+    };
+    let header = json(decode(header_b64)?)?;
+    let mut payload = json(decode(payload_b64)?)?;
+    let signature = decode(sig_b64)?;
+
+    let alg = header.get("alg").and_then(Value::as_str).unwrap_or("<none>");
+    if alg != SignatureAlgorithm::MlDsa65.name() {
+        return Err(VcError::UnsupportedProof(format!("JWS alg {alg}")));
+    }
+    let kid = header
+        .get("kid")
+        .and_then(Value::as_str)
+        .ok_or_else(|| VcError::Malformed("missing kid".into()))?
+        .to_string();
+
+    // VC 1.1 JWTs wrap the credential in a `vc` claim; VC‑JOSE tokens are
+    // the credential itself. Either way the registered claims are checked
+    // against the credential.
+    let (mut credential, mut claims) = match payload.remove("vc") {
+        Some(Value::Object(vc)) => (vc, payload),
+        Some(_) => return Err(VcError::Malformed("vc claim is not an object".into())),
+        None => {
+            let claims = REGISTERED_CLAIMS
+                .iter()
+                .filter_map(|claim| payload.remove(*claim).map(|v| (claim.to_string(), v)))
+                .collect();
+            (payload, claims)
+        }
+    };
+    merge_registered_claims(&mut claims, &mut credential)?;
+
+    check_issuer_key(&credential, &kid)?;
+    let signing_input = format!("{header_b64}.{payload_b64}");
+    verifier.verify(
+        &kid,
+        SignatureAlgorithm::MlDsa65,
+        signing_input.as_bytes(),
+        &signature,
+    )?;
+    Ok(credential)
+}
+
+const REGISTERED_CLAIMS: [&str; 4] = ["iss", "sub", "nbf", "exp"];
+
+/// Issuer / subject / validity from registered JWT claims. A claim fills a
+/// missing field; one that disagrees with the embedded field is rejected,
+/// so neither side can widen the other.
+fn merge_registered_claims(
+    claims: &mut Map<String, Value>,
+    vc: &mut Map<String, Value>,
+) -> Result<(), VcError> {
+    let mismatch = |claim, field| VcError::ClaimMismatch { claim, field };
+    if let Some(iss) = claims.remove("iss") {
+        if vc.contains_key("issuer") {
+            if iss.as_str() != Some(issuer_of(vc)?.as_str()) {
+                return Err(mismatch("iss", "issuer"));
+            }
+        } else {
+            vc.insert("issuer".into(), iss);
+        }
+    }
+    if let (Some(sub), Some(Value::Object(subject))) =
+        (claims.remove("sub"), vc.get_mut("credentialSubject"))
+    {
+        match subject.get("id") {
+            Some(id) if *id != sub => return Err(mismatch("sub", "credentialSubject.id")),
+            Some(_) => {}
+            None => {
+                subject.insert("id".into(), sub);
+            }
+        }
+    }
+    for (claim, field) in [("nbf", "validFrom"), ("exp", "validUntil")] {
+        let Some(ts) = claims.get(claim) else {
+            continue;
+        };
+        let ts = ts
+            .as_i64()
+            .and_then(|ts| Utc.timestamp_opt(ts, 0).single())
+            .ok_or_else(|| VcError::Malformed(format!("invalid {claim}")))?;
+        match vc.get(field) {
+            Some(embedded) => {
+                let same = embedded
+                    .as_str()
+                    .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+                    .is_some_and(|t| t == ts);
+                if !same {
+                    return Err(mismatch(claim, field));
+                }
+            }
+            None => {
+                vc.insert(field.into(), ts.to_rfc3339().into());
+            }
+        }
+    }
+    Ok(())
+}
+
+fn issuer_of(credential: &Map<String, Value>) -> Result<String, VcError> {
+    match credential.get("issuer") {
+        Some(Value::String(issuer)) => Ok(issuer.clone()),
+        Some(Value::Object(issuer)) => issuer
+            .get("id")
+            .and_then(Value::as_str)
+            .map(str::to_string)
+            .ok_or_else(|| VcError::Malformed("issuer without id".into())),
+        _ => Err(VcError::Malformed("missing issuer".into())),
+    }
+}
+
+fn check_issuer_key(credential: &Map<String, Value>, key: &str) -> Result<(), VcError> {
+    let issuer = issuer_of(credential)?;
+    if key == issuer || key.strip_prefix(issuer.as_str()).is_some_and(|f| f.starts_with('#')) {
+        Ok(())
+    } else {
+        Err(VcError::IssuerMismatch {
+            key: key.to_string(),
+            issuer,
+        })
+    }
+}
+
+struct ParsedCredential {
+    operator: OperatorCredential,
+    status: Vec<(String, u64)>,
+}
+
+fn parse_credential(credential: &Map<String, Value>) -> Result<ParsedCredential, VcError> {
+    let types: Vec<&str> = match credential.get("type") {
+        Some(Value::String(t)) => vec![t.as_str()],
+        Some(Value::Array(ts)) => ts.iter().filter_map(Value::as_str).collect(),
+        _ => Vec::new(),
+    };
+    if !types.contains(&"VerifiableCredential") || !types.contains(&"OperatorCredential") {
+        return Err(VcError::WrongType);
+    }
+
+    let timestamp = |field: &str| -> Result<Option<DateTime<Utc>>, VcError> {
+        credential
+            .get(field)
+            .map(|v| {
+                v.as_str()
+                    .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+                    .map(|t| t.with_timezone(&Utc))
+                    .ok_or_else(|| VcError::Malformed(format!("invalid {field}")))
+            })
+            .transpose()
+    };
+    let valid_from = timestamp("validFrom")?;
+    let valid_until = timestamp("validUntil")?;
+
+    let subject = credential
+        .get("credentialSubject")
+        .and_then(Value::as_object)
+        .ok_or_else(|| VcError::Malformed("missing credentialSubject".into()))?;
+    let did = subject
+        .get("id")
+        .and_then(Value::as_str)
+        .ok_or_else(|| VcError::Malformed("credentialSubject without id".into()))?
+        .to_string();
+    let roles = match subject.get("roles") {
+        Some(Value::Array(roles)) => roles
+            .iter()
+            .map(|r| {
+                r.as_str()
+                    .map(|s| OperatorRole::from(s.to_string()))
+                    .ok_or_else(|| VcError::Malformed("role is not a string".into()))
+            })
+            .collect::<Result<_, _>>()?,
+        None => Vec::new(),
+        Some(_) => return Err(VcError::Malformed("roles must be an array".into())),
+    };
+
+    let entries = match credential.get("credentialStatus") {
+        Some(Value::Array(entries)) => entries.iter().collect(),
+        Some(entry) => vec![entry],
+        None => Vec::new(),
+    };
+    let mut status = Vec::new();
+    for entry in entries {
+        if entry.get("type").and_then(Value::as_str) != Some("BitstringStatusListEntry") {
+            return Err(VcError::Malformed("unsupported credentialStatus type".into()));
+        }
+        // Revocation and suspension both make the credential unusable.
+        let list = entry
+            .get("statusListCredential")
+            .and_then(Value::as_str)
+            .ok_or_else(|| VcError::Malformed("missing statusListCredential".into()))?;
+        let index = entry
+            .get("statusListIndex")
+            .and_then(|i| i.as_str().and_then(|s| s.parse().ok()).or_else(|| i.as_u64()))
+            .ok_or_else(|| VcError::Malformed("invalid statusListIndex".into()))?;
+        status.push((list.to_string(), index));
+    }
+
+    Ok(ParsedCredential {
+        operator: OperatorCredential {
+            did,
+            issuer: issuer_of(credential)?,
+            roles,
+            valid_from,
+            valid_until,
+        },
+        status,
+    })
+}
+
+#[cfg(all(test, feature = "ml-dsa"))]
+mod tests {
+    use chrono::Duration;
+    use serde_json::json;
+
+    use super::*;
+    use crate::web5_integration::did::{DidKeyPair, DidKeyVerifier};
+
+    const LIST: &str = "https://council.example/status/1";
+
+    fn issuer() -> DidKeyPair {
+        DidKeyPair::from_seed(&[0x11; 32])
+    }
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 6, 1, 0, 0, 0).unwrap()
+    }
+
+    fn rfc3339(t: DateTime<Utc>) -> String {
+        t.to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+    }
+
+    fn credential(issuer: &str) -> Map<String, Value> {
+        let value = json!({
+            "@context": ["https://www.w3.org/ns/credentials/v2"],
+            "type": ["VerifiableCredential", "OperatorCredential"],
+            "issuer": issuer,
+            "validFrom": rfc3339(now() - Duration::days(1)),
+            "validUntil": rfc3339(now() + Duration::days(1)),
+            "credentialSubject": {"id": "did:web:operator.example", "roles": ["OTOperator"]},
+            "credentialStatus": {
+                "type": "BitstringStatusListEntry",
+                "statusPurpose": "revocation",
+                "statusListCredential": LIST,
+                "statusListIndex": "42",
+            },
+        });
+        value.as_object().unwrap().clone()
+    }
+
+    fn signed(credential: Map<String, Value>) -> Vec<u8> {
+        let key = issuer();
+        let secured = add_data_integrity_proof(credential, &key, &key.did, now()).unwrap();
+        serde_json::to_vec(&secured).unwrap()
+    }
+
+    fn jwt(payload: Value) -> Vec<u8> {
+        let key = issuer();
+        let encode = |v: &Value| URL_SAFE_NO_PAD.encode(serde_json::to_vec(v).unwrap());
+        let header = encode(&json!({"alg": "ML-DSA-65", "kid": key.did}));
+        let input = format!("{header}.{}", encode(&payload));
+        let sig = key.sign(input.as_bytes()).unwrap();
+        format!("{input}.{}", URL_SAFE_NO_PAD.encode(sig)).into_bytes()
+    }
+
+    fn lists() -> InMemoryStatusLists {
+        let mut lists = InMemoryStatusLists::new();
+        lists.insert(LIST, vec![0; 16]);
+        lists
+    }
+
+    fn verify(bytes: &[u8]) -> Result<OperatorCredential, VcError> {
+        verify_operator_vc(bytes, &DidKeyVerifier, &lists(), now())
+    }
+
+    #[test]
+    fn data_integrity_credential_verifies() {
+        let cred = verify(&signed(credential(&issuer().did))).unwrap();
+        assert_eq!(cred.did, "did:web:operator.example");
+        assert_eq!(cred.issuer, issuer().did);
+        assert_eq!(cred.roles, vec![OperatorRole::OtOperator]);
+    }
+
+    #[test]
+    fn tampered_credentials_fail_signature_checks() {
+        let mut secured: Map<String, Value> =
+            serde_json::from_slice(&signed(credential(&issuer().did))).unwrap();
+        secured["credentialSubject"]["roles"] = json!(["OTOperator", "EcoCouncil"]);
+        assert!(matches!(
+            verify(&serde_json::to_vec(&secured).unwrap()),
+            Err(VcError::Signature(SignatureError::InvalidSignature))
+        ));
+
+        let token = String::from_utf8(jwt(json!({"vc": credential(&issuer().did)}))).unwrap();
+        let (input, _) = token.rsplit_once('.').unwrap();
+        let forged = format!("{input}.{}", URL_SAFE_NO_PAD.encode([0u8; 16]));
+        assert!(matches!(verify(forged.as_bytes()), Err(VcError::Signature(_))));
+
+        // A key outside the issuer's DID is rejected before any signature check.
+        let other = DidKeyPair::from_seed(&[0x22; 32]);
+        assert!(matches!(
+            verify(&signed(credential(&other.did))),
+            Err(VcError::IssuerMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn validity_window_is_enforced() {
+        let mut early = credential(&issuer().did);
+        early.insert("validFrom".into(), rfc3339(now() + Duration::hours(1)).into());
+        assert!(matches!(verify(&signed(early)), Err(VcError::NotYetValid(_))));
+
+        let mut late = credential(&issuer().did);
+        late.insert("validUntil".into(), rfc3339(now()).into());
+        assert!(matches!(verify(&signed(late)), Err(VcError::Expired(_))));
+
+        let mut vc = credential(&issuer().did);
+        vc.remove("validFrom");
+        vc.remove("validUntil");
+        let nbf = (now() + Duration::hours(1)).timestamp();
+        assert!(matches!(
+            verify(&jwt(json!({"vc": vc.clone(), "nbf": nbf}))),
+            Err(VcError::NotYetValid(_))
+        ));
+        let exp = (now() - Duration::hours(1)).timestamp();
+        assert!(matches!(verify(&jwt(json!({"vc": vc, "exp": exp}))), Err(VcError::Expired(_))));
+    }
+
+    #[test]
+    fn unwrapped_jwt_credentials_honour_registered_claims() {
+        let mut vc = credential(&issuer().did);
+        vc.remove("validUntil");
+        let mut expired = vc.clone();
+        expired.insert("exp".into(), (now() - Duration::hours(1)).timestamp().into());
+        assert!(matches!(verify(&jwt(Value::Object(expired))), Err(VcError::Expired(_))));
+
+        let mut other_issuer = vc.clone();
+        other_issuer.insert("iss".into(), "did:web:other.example".into());
+        assert!(matches!(
+            verify(&jwt(Value::Object(other_issuer))),
+            Err(VcError::ClaimMismatch { claim: "iss", .. })
+        ));
+
+        let exp = (now() + Duration::hours(1)).timestamp();
+        vc.insert("exp".into(), exp.into());
+        let cred = verify(&jwt(Value::Object(vc))).unwrap();
+        assert_eq!(cred.valid_until.unwrap().timestamp(), exp);
+
+        assert!(matches!(verify(&jwt(json!({"vc": "x"}))), Err(VcError::Malformed(_))));
+    }
+
+    #[test]
+    fn revoked_credentials_are_rejected() {
+        let bytes = signed(credential(&issuer().did));
+        let mut revoked = lists();
+        revoked.set(LIST, 42);
+        assert!(matches!(
+            verify_operator_vc(&bytes, &DidKeyVerifier, &revoked, now()),
+            Err(VcError::Revoked { index: 42, .. })
+        ));
+        assert!(matches!(
+            verify_operator_vc(&bytes, &DidKeyVerifier, &InMemoryStatusLists::new(), now()),
+            Err(VcError::StatusUnavailable(_))
+        ));
+    }
+
+    #[test]
+    fn jwt_claims_fill_missing_fields() {
+        let mut vc = credential(&issuer().did);
+        vc.remove("issuer");
+        vc.remove("validUntil");
+        vc["credentialSubject"].as_object_mut().unwrap().remove("id");
+        let exp = (now() + Duration::hours(1)).timestamp();
+        let cred = verify(&jwt(json!({
+            "vc": vc,
+            "iss": issuer().did,
+            "sub": "did:web:operator.example",
+            "exp": exp,
+        })))
+        .unwrap();
+        assert_eq!(cred.issuer, issuer().did);
+        assert_eq!(cred.did, "did:web:operator.example");
+        assert_eq!(cred.valid_until.unwrap().timestamp(), exp);
+    }
+
+    #[test]
+    fn jwt_claims_must_agree_with_the_credential() {
+        let vc = credential(&issuer().did);
+        let mismatch = |payload: Value| match verify(&jwt(payload)) {
+            Err(VcError::ClaimMismatch { claim, .. }) => claim,
+            other => panic!("expected ClaimMismatch, got {other:?}"),
+        };
+        // A later `exp` must not extend the embedded validUntil.
+        let exp = (now() + Duration::days(30)).timestamp();
+        assert_eq!(mismatch(json!({"vc": vc.clone(), "exp": exp})), "exp");
+        let nbf = (now() - Duration::days(30)).timestamp();
+        assert_eq!(mismatch(json!({"vc": vc.clone(), "nbf": nbf})), "nbf");
+        assert_eq!(mismatch(json!({"vc": vc.clone(), "iss": "did:web:other.example"})), "iss");
+        assert_eq!(mismatch(json!({"vc": vc.clone(), "sub": "did:web:someone.else"})), "sub");
+
+        let matching = json!({
+            "vc": vc,
+            "iss": issuer().did,
+            "sub": "did:web:operator.example",
+            "nbf": (now() - Duration::days(1)).timestamp(),
+        });
+        verify(&jwt(matching)).unwrap();
+    }
 }
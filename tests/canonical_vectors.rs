@@ -0,0 +1,263 @@
+//! Golden vectors for the canonical encoding in `ecoinfra_governance::canonical`.
+//! Other components must reproduce these bytes and digests exactly.
+
+use chrono::{TimeZone, Utc};
+use geojson::{Geometry, Value};
+use serde_json::json;
+use uuid::Uuid;
+
+use ecoinfra_governance::canonical::{
+    canonical_sha256, format_es_number, to_canonical_bytes, CanonicalError, MAX_SAFE_INTEGER,
+};
+use ecoinfra_governance::nanopolygon::*;
+use ecoinfra_governance::policy::{
+    ethics::EthicsProfile,
+    governance_metadata::GovernanceMetadata,
+    nist_ai_rmf::{AIRmfFunction, AIRmfProfile},
+    nist_80053::{Sp80053Family, Sp80053Impact, Sp80053Profile},
+};
+
+fn fixture() -> NanopolygonSafetyObject {
+    NanopolygonSafetyObject {
+        polygon_id: Uuid::from_u128(0x0f1e2d3c_4b5a_6978_8796_a5b4c3d2e1f0),
+        geometry: Geometry::new(Value::Polygon(vec![vec![
+            vec![-112.074, 33.448],
+            vec![-112.072, 33.448],
+            vec![-112.072, 33.4495],
+            vec![-112.074, 33.448],
+        ]])),
+        geo: GeoIntelligence {
+            location_band: "urban-core".to_string(),
+            hazard_level: HazardLevel::Moderate,
+            resource_stress: 0.7,
+            infrastructure_criticality: 8,
+        },
+        biospatial: BiospatialTelemetry {
+            heat_stress: 0.3,
+            pollution_exposure: 1.0,
+        },
+        learning: LearningSignal {
+            gradient_weight: 0.1,
+        },
+        intelligence: IntelligenceIndex {
+            quantified_safety_index: -0.2,
+        },
+        rights: RightsMetadata {
+            version: 1,
+            species_rights: SpeciesRightsProfile {
+                primary_species: SpeciesClass::Human,
+                recognizes_cybernetic_personhood: true,
+                territory_protection: TerritoryProtectionLevel::SanctuaryCorridor,
+                anti_discrimination_hard_floor: true,
+            },
+            neurorights: NeurorightsProfile {
+                allows_direct_neural_interfaces: false,
+                neurorights_sanctuary: true,
+                consent_requirement: ConsentRequirement::CommunityAndIndividual,
+                appeal_path: AppealPathType::EcoInfraCouncil,
+                hitl_trigger_threshold: 0.0,
+            },
+        },
+        metadata: GovernanceMetadata {
+            jurisdiction_code: "US-AZ".to_string(),
+            ai_rmf_profile: AIRmfProfile {
+                functions: AIRmfFunction::GOVERN | AIRmfFunction::MANAGE,
+                requires_human_in_loop: true,
+                requires_appeal_path: true,
+            },
+            sp80053_profile: Sp80053Profile {
+                families: Sp80053Family::AC | Sp80053Family::AU | Sp80053Family::SC,
+                impact: Sp80053Impact::High,
+            },
+            ethics_profile: EthicsProfile {
+                human_primacy: true,
+                equal_power_thresholds: true,
+                appeal_paths_available: true,
+            },
+            data_owner_did: "did:web:council.example".to_string(),
+            created_by_agent_id: Uuid::from_u128(1),
+            last_modified_by_agent_id: Uuid::from_u128(2),
+        },
+        timestamp_utc: Utc.with_ymd_and_hms(2026, 1, 2, 3, 4, 5).unwrap(),
+    }
+}
+
+#[test]
+#[allow(clippy::excessive_precision)]
+fn numbers_follow_ecmascript_formatting() {
+    let vectors: &[(f64, &str)] = &[
+        (0.0, "0"),
+        (-0.0, "0"),
+        (1.0, "1"),
+        (-0.2, "-0.2"),
+        (4.50, "4.5"),
+        (0.002, "0.002"),
+        (0.000001, "0.000001"),
+        (1e-7, "1e-7"),
+        (1e21, "1e+21"),
+        (1e30, "1e+30"),
+        (123456789012345680000.0, "123456789012345680000"),
+        (333333333.33333329, "333333333.3333333"),
+        (5e-324, "5e-324"),
+        (1.7976931348623157e308, "1.7976931348623157e+308"),
+        (-34476410671085.8125, "-34476410671085.812"),
+    ];
+    for (value, expected) in vectors {
+        assert_eq!(format_es_number(*value), *expected, "formatting {value:e}");
+    }
+}
+
+#[test]
+fn safe_integers_are_written_exactly() {
+    let value = json!([MAX_SAFE_INTEGER, -(MAX_SAFE_INTEGER as i64), 0u64, 42i64]);
+    assert_eq!(
+        String::from_utf8(to_canonical_bytes(&value).unwrap()).unwrap(),
+        "[9007199254740991,-9007199254740991,0,42]"
+    );
+}
+
+fn rejected_integer<T: serde::Serialize>(value: T) -> String {
+    match to_canonical_bytes(&value) {
+        Err(CanonicalError::UnsafeInteger(n)) => n,
+        other => panic!("expected UnsafeInteger, got {other:?}"),
+    }
+}
+
+#[test]
+fn integers_outside_the_safe_range_are_rejected() {
+    assert_eq!(rejected_integer(MAX_SAFE_INTEGER + 1), "9007199254740992");
+    assert_eq!(rejected_integer(-(MAX_SAFE_INTEGER as i64) - 1), "-9007199254740992");
+    assert_eq!(rejected_integer(u64::MAX), "18446744073709551615");
+    assert_eq!(rejected_integer(i64::MIN), "-9223372036854775808");
+    assert_eq!(rejected_integer(u128::MAX), "340282366920938463463374607431768211455");
+    // Nested values are checked too.
+    assert_eq!(rejected_integer(json!({"a": [1, {"b": MAX_SAFE_INTEGER + 1}]})), "9007199254740992");
+}
+
+#[test]
+fn non_finite_floats_are_rejected() {
+    for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+        assert!(
+            matches!(to_canonical_bytes(&value), Err(CanonicalError::NonFinite(_))),
+            "{value} must not encode"
+        );
+        assert!(matches!(canonical_sha256(&[1.0, value]), Err(CanonicalError::NonFinite(_))));
+    }
+    assert!(matches!(to_canonical_bytes(&Some(f32::NAN)), Err(CanonicalError::NonFinite(_))));
+
+    // A NaN telemetry field no longer signs as `null`.
+    let mut nanopolygon = fixture();
+    nanopolygon.biospatial.heat_stress = f64::NAN;
+    assert!(matches!(to_canonical_bytes(&nanopolygon), Err(CanonicalError::NonFinite(_))));
+}
+
+#[test]
+fn objects_sort_keys_by_utf16_code_units() {
+    let value = json!({
+        "\u{20ac}": "euro",
+        "\r": "cr",
+        "\u{1f600}": "emoji",
+        "\u{fb33}": "hebrew",
+        "1": "one",
+        "b": [true, null, 1.50, "\u{0007}/\""],
+        "a": {"z": 1, "y": -0.0},
+    });
+    let expected = "{\"\\r\":\"cr\",\"1\":\"one\",\"a\":{\"y\":0,\"z\":1},\
+                    \"b\":[true,null,1.5,\"\\u0007/\\\"\"],\
+                    \"\u{20ac}\":\"euro\",\"\u{1f600}\":\"emoji\",\"\u{fb33}\":\"hebrew\"}";
+    assert_eq!(String::from_utf8(to_canonical_bytes(&value).unwrap()).unwrap(), expected);
+}
+
+#[test]
+fn nanopolygon_canonical_bytes_are_stable() {
+    let bytes = to_canonical_bytes(&fixture()).unwrap();
+    assert_eq!(String::from_utf8(bytes).unwrap(), GOLDEN_NANOPOLYGON);
+    assert_eq!(canonical_sha256(&fixture()).unwrap(), GOLDEN_NANOPOLYGON_SHA256);
+}
+
+fn shard_fixture() -> ecoinfra_governance::aln::AlnShard {
+    use ecoinfra_governance::aln::{AlnDeviceClass, AlnShard, AlnShardHeader};
+    use ecoinfra_governance::audit::{context::AuditContext, sink::InMemoryAuditSink};
+
+    let ctx = AuditContext::new(Uuid::nil());
+    // The fixture's timestamp is fixed, so freshness is not judged here.
+    let config = ValidationConfig::archival();
+    let nanopolygon =
+        validate_with(fixture(), &config, &ctx, &mut InMemoryAuditSink::default()).unwrap();
+    AlnShard::new(
+        AlnShardHeader {
+            shard_id: Uuid::from_u128(0x5eed),
+            source_device_class: AlnDeviceClass::Sensor,
+            target_device_class: AlnDeviceClass::DatacenterNode,
+            segment_label: "eco-infra-routing".to_string(),
+        },
+        nanopolygon,
+    )
+}
+
+#[test]
+fn shard_signing_payload_is_stable() {
+    let payload = String::from_utf8(shard_fixture().signing_payload().unwrap()).unwrap();
+    let expected = format!(
+        "{}{}}}",
+        concat!(
+            r#"{"header":{"segment_label":"eco-infra-routing","#,
+            r#""shard_id":"00000000-0000-0000-0000-000000005eed","#,
+            r#""source_device_class":"Sensor","target_device_class":"DatacenterNode"},"#,
+            r#""nanopolygon":"#,
+        ),
+        GOLDEN_NANOPOLYGON,
+    );
+    assert_eq!(payload, expected);
+}
+
+#[cfg(feature = "ml-dsa")]
+#[test]
+fn shard_signature_is_deterministic_and_verifies() {
+    use ecoinfra_governance::aln_shard::{sign_shard, verify_shard};
+    use ecoinfra_governance::audit::{context::AuditContext, sink::InMemoryAuditSink};
+    use ecoinfra_governance::web5_integration::did::{DidKeyPair, DidKeyVerifier};
+    use sha2::{Digest, Sha256};
+
+    let keypair = DidKeyPair::from_seed(&[0x42; 32]);
+    let shard = sign_shard(shard_fixture(), &keypair).unwrap();
+    let block = shard.signature.as_ref().unwrap();
+    assert_eq!(block.signer_did, keypair.did);
+    assert_eq!(hex::encode(Sha256::digest(keypair.public_key())), GOLDEN_PUBLIC_KEY_SHA256);
+    assert_eq!(hex::encode(Sha256::digest(&block.signature)), GOLDEN_SIGNATURE_SHA256);
+    let ctx = AuditContext::new(Uuid::nil());
+    verify_shard(&shard, &DidKeyVerifier, &ctx, &mut InMemoryAuditSink::new()).unwrap();
+}
+
+const GOLDEN_NANOPOLYGON: &str = concat!(
+    r#"{"biospatial":{"heat_stress":0.3,"pollution_exposure":1},"#,
+    r#""geo":{"hazard_level":"Moderate","infrastructure_criticality":8,"#,
+    r#""location_band":"urban-core","resource_stress":0.7},"#,
+    r#""geometry":{"coordinates":[[[-112.074,33.448],[-112.072,33.448],[-112.072,33.4495],"#,
+    r#"[-112.074,33.448]]],"type":"Polygon"},"intelligence":{"quantified_safety_index":-0.2},"#,
+    r#""learning":{"gradient_weight":0.1},"#,
+    r#""metadata":{"ai_rmf_profile":{"functions":"GOVERN | MANAGE","#,
+    r#""requires_appeal_path":true,"requires_human_in_loop":true},"#,
+    r#""created_by_agent_id":"00000000-0000-0000-0000-000000000001","#,
+    r#""data_owner_did":"did:web:council.example","#,
+    r#""ethics_profile":{"appeal_paths_available":true,"equal_power_thresholds":true,"#,
+    r#""human_primacy":true},"jurisdiction_code":"US-AZ","#,
+    r#""last_modified_by_agent_id":"00000000-0000-0000-0000-000000000002","#,
+    r#""sp80053_profile":{"families":"AC | AU | SC","impact":"High"}},"#,
+    r#""polygon_id":"0f1e2d3c-4b5a-6978-8796-a5b4c3d2e1f0","#,
+    r#""rights":{"neurorights":{"allows_direct_neural_interfaces":false,"#,
+    r#""appeal_path":"EcoInfraCouncil","consent_requirement":"CommunityAndIndividual","#,
+    r#""hitl_trigger_threshold":0,"neurorights_sanctuary":true},"#,
+    r#""species_rights":{"anti_discrimination_hard_floor":true,"primary_species":"Human","#,
+    r#""recognizes_cybernetic_personhood":true,"territory_protection":"SanctuaryCorridor"},"#,
+    r#""version":1},"#,
+    r#""timestamp_utc":"2026-01-02T03:04:05Z"}"#,
+);
+const GOLDEN_NANOPOLYGON_SHA256: &str =
+    "1198c64f8ee03f81f8d0433aeb8f616d7c69156d22ba5bd1e3ce1a1e873ab706";
+#[cfg(feature = "ml-dsa")]
+const GOLDEN_PUBLIC_KEY_SHA256: &str =
+    "2f40048b7202cf1d33e0af88f0695e076d00fea5be3d201d667021afe09c23c1";
+#[cfg(feature = "ml-dsa")]
+const GOLDEN_SIGNATURE_SHA256: &str =
+    "1462c45f4022e9d48bde70106bb6eb48dce5a6c2eca0de69562a87cbf7cc71f2";